@@ -1,40 +1,36 @@
 use criterion::{black_box, Criterion};
 use rand::{distributions::Standard, prelude::Distribution};
 use zama_math::polynomial::Polynomial;
-use zama_math::ring::{Complex, ModInteger32, ModInteger64};
-use zama_math::traits::{Abs, Zero, One};
-use std::ops::{Add, AddAssign, Sub, Mul, Div};
-use std::cmp::{PartialOrd, PartialEq};
-use std::fmt::Debug;
+use zama_math::ring::{Complex, ModInteger32, ModInteger64, Torus32, Torus64};
+use zama_math::traits::{Abs, Ring};
+use std::ops::Div;
+use std::cmp::PartialOrd;
 use zama_math::utilities::{generate_random_float_polynomial, generate_random_complex_polynomial,
-                           generate_random_modint32_polynomial, generate_random_modint64_polynomial};
+                           generate_random_modint32_polynomial, generate_random_modint64_polynomial,
+                           generate_random_torus32_polynomial, generate_random_torus64_polynomial};
 
 pub fn bench_float<T>(c: &mut Criterion, ring_degree: usize)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd  + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     let name = format!("Add polynomials of degree {} with type {}", ring_degree,
                        std::any::type_name::<T>());
     let poly1: Polynomial<T> = generate_random_float_polynomial::<T>(ring_degree);
     let poly2: Polynomial<T> = generate_random_float_polynomial::<T>(ring_degree);
     c.bench_function(name.as_str(), |b| {
         b.iter(|| {
-            black_box(poly1.add(&poly2));
+            black_box(&poly1 + &poly2);
         })
     });
 }
 
 pub fn bench_complex<T>(c: &mut Criterion, ring_degree: usize)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd  + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     let name = format!("Add polynomials of degree {} with type {}", ring_degree,
                        std::any::type_name::<Complex<T>>());
     let poly1: Polynomial<Complex<T>> = generate_random_complex_polynomial::<T>(ring_degree);
     let poly2: Polynomial<Complex<T>> = generate_random_complex_polynomial::<T>(ring_degree);
     c.bench_function(name.as_str(), |b| {
         b.iter(|| {
-            black_box(poly1.add(&poly2));
+            black_box(&poly1 + &poly2);
         })
     });
 }
@@ -46,7 +42,7 @@ pub fn bench_modint32(c: &mut Criterion, ring_degree: usize) {
     let poly2: Polynomial<ModInteger32> = generate_random_modint32_polynomial(ring_degree);
     c.bench_function(name.as_str(), |b| {
         b.iter(|| {
-            black_box(poly1.add(&poly2));
+            black_box(&poly1 + &poly2);
         })
     });
 }
@@ -58,7 +54,31 @@ pub fn bench_modint64(c: &mut Criterion, ring_degree: usize) {
     let poly2: Polynomial<ModInteger64> = generate_random_modint64_polynomial(ring_degree);
     c.bench_function(name.as_str(), |b| {
         b.iter(|| {
-            black_box(poly1.add(&poly2));
+            black_box(&poly1 + &poly2);
+        })
+    });
+}
+
+pub fn bench_torus32(c: &mut Criterion, ring_degree: usize) {
+    let name = format!("Add polynomials of degree {} with type {}", ring_degree,
+                       std::any::type_name::<Torus32>());
+    let poly1: Polynomial<Torus32> = generate_random_torus32_polynomial(ring_degree);
+    let poly2: Polynomial<Torus32> = generate_random_torus32_polynomial(ring_degree);
+    c.bench_function(name.as_str(), |b| {
+        b.iter(|| {
+            black_box(&poly1 + &poly2);
+        })
+    });
+}
+
+pub fn bench_torus64(c: &mut Criterion, ring_degree: usize) {
+    let name = format!("Add polynomials of degree {} with type {}", ring_degree,
+                       std::any::type_name::<Torus64>());
+    let poly1: Polynomial<Torus64> = generate_random_torus64_polynomial(ring_degree);
+    let poly2: Polynomial<Torus64> = generate_random_torus64_polynomial(ring_degree);
+    c.bench_function(name.as_str(), |b| {
+        b.iter(|| {
+            black_box(&poly1 + &poly2);
         })
     });
 }
@@ -110,3 +130,11 @@ pub fn bench_modint64_2048(c: &mut Criterion) {
 pub fn bench_modint64_4096(c: &mut Criterion) {
     bench_modint64(c, 4096);
 }
+
+pub fn bench_torus32_2048(c: &mut Criterion) {
+    bench_torus32(c, 2048);
+}
+
+pub fn bench_torus64_2048(c: &mut Criterion) {
+    bench_torus64(c, 2048);
+}
\ No newline at end of file
@@ -2,19 +2,16 @@ use criterion::{black_box, Criterion};
 use rand::{distributions::Standard, prelude::Distribution};
 use zama_math::vector::{Vector, Matrix};
 use zama_math::ring::{Complex, ModInteger32, ModInteger64};
-use zama_math::traits::{Abs, Zero, One};
-use std::ops::{Add, AddAssign, Sub, Mul, Div};
-use std::cmp::{PartialOrd, PartialEq};
-use std::fmt::Debug;
+use zama_math::traits::{Abs, Ring};
+use std::ops::Div;
+use std::cmp::PartialOrd;
 use zama_math::utilities::{generate_random_float_polynomial_vector, generate_random_complex_polynomial_vector,
                            generate_random_modint32_polynomial_vector, generate_random_modint64_polynomial_vector,
                            generate_random_float_polynomial_matrix, generate_random_complex_polynomial_matrix,
                            generate_random_modint32_polynomial_matrix, generate_random_modint64_polynomial_matrix};
 
 pub fn bench_float<T>(c: &mut Criterion, ring_degree: usize, num_rows: usize, num_cols: usize)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd  + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     let name = format!("Multiply vector by matrix of degree {} with {} rows and {} cols of type {}", ring_degree,
                        num_rows, num_cols, std::any::type_name::<T>());
     let vector: Vector<T> = generate_random_float_polynomial_vector::<T>(num_rows, ring_degree);
@@ -27,9 +24,7 @@ pub fn bench_float<T>(c: &mut Criterion, ring_degree: usize, num_rows: usize, nu
 }
 
 pub fn bench_complex<T>(c: &mut Criterion, ring_degree: usize, num_rows: usize, num_cols: usize)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd  + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     let name = format!("Multiply vector by matrix of degree {} with {} rows and {} cols of type {}", ring_degree,
                        num_rows, num_cols, std::any::type_name::<Complex<T>>());
     let vector: Vector<Complex<T>> = generate_random_complex_polynomial_vector::<T>(num_rows, ring_degree);
@@ -1,18 +1,20 @@
 use criterion::{black_box, Criterion};
 use rand::{distributions::Standard, prelude::Distribution};
 use zama_math::polynomial::Polynomial;
-use zama_math::ring::{Complex, ModInteger32, ModInteger64};
-use zama_math::traits::{Abs, Zero, One};
-use std::ops::{Add, AddAssign, Sub, Mul, Div};
-use std::cmp::{PartialOrd, PartialEq};
-use std::fmt::Debug;
+use zama_math::ntt::Ntt;
+use zama_math::fft::{Fft, FftCoefficient};
+use zama_math::quotient::Negacyclic;
+use zama_math::ring::{Complex, ModInteger32, ModInteger64, Torus32, Torus64, ModInteger};
+use zama_math::traits::{Abs, Ring};
+use std::ops::Div;
+use std::cmp::PartialOrd;
 use zama_math::utilities::{generate_random_float_polynomial, generate_random_complex_polynomial,
-                           generate_random_modint32_polynomial, generate_random_modint64_polynomial};
+                           generate_random_modint32_polynomial, generate_random_modint64_polynomial,
+                           generate_random_torus32_polynomial, generate_random_torus64_polynomial,
+                           generate_random_modint_polynomial};
 
 pub fn bench_float<T>(c: &mut Criterion, ring_degree: usize)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd  + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     let name = format!("Multiply polynomials of degree {} with type {}", ring_degree,
                        std::any::type_name::<T>());
     let poly1: Polynomial<T> = generate_random_float_polynomial::<T>(ring_degree);
@@ -25,9 +27,7 @@ pub fn bench_float<T>(c: &mut Criterion, ring_degree: usize)
 }
 
 pub fn bench_complex<T>(c: &mut Criterion, ring_degree: usize)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd  + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     let name = format!("Multiply polynomials of degree {} with type {}", ring_degree,
                        std::any::type_name::<Complex<T>>());
     let poly1: Polynomial<Complex<T>> = generate_random_complex_polynomial::<T>(ring_degree);
@@ -39,6 +39,34 @@ pub fn bench_complex<T>(c: &mut Criterion, ring_degree: usize)
     });
 }
 
+pub fn bench_float_fft<T>(c: &mut Criterion, ring_degree: usize)
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> + FftCoefficient {
+    let name = format!("Multiply polynomials of degree {} with type {} by FFT", ring_degree,
+                       std::any::type_name::<T>());
+    let fft = Fft::new(ring_degree);
+    let poly1: Polynomial<T> = generate_random_float_polynomial::<T>(ring_degree);
+    let poly2: Polynomial<T> = generate_random_float_polynomial::<T>(ring_degree);
+    c.bench_function(name.as_str(), |b| {
+        b.iter(|| {
+            black_box(poly1.multiply_fft(&poly2, &fft));
+        })
+    });
+}
+
+pub fn bench_complex_fft<T>(c: &mut Criterion, ring_degree: usize)
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T>, Complex<T>: FftCoefficient {
+    let name = format!("Multiply polynomials of degree {} with type {} by FFT", ring_degree,
+                       std::any::type_name::<Complex<T>>());
+    let fft = Fft::new(ring_degree);
+    let poly1: Polynomial<Complex<T>> = generate_random_complex_polynomial::<T>(ring_degree);
+    let poly2: Polynomial<Complex<T>> = generate_random_complex_polynomial::<T>(ring_degree);
+    c.bench_function(name.as_str(), |b| {
+        b.iter(|| {
+            black_box(poly1.multiply_fft(&poly2, &fft));
+        })
+    });
+}
+
 pub fn bench_modint32(c: &mut Criterion, ring_degree: usize) {
     let name = format!("Multiply polynomials of degree {} with type {}", ring_degree,
                        std::any::type_name::<ModInteger32>());
@@ -63,6 +91,69 @@ pub fn bench_modint64(c: &mut Criterion, ring_degree: usize) {
     });
 }
 
+pub fn bench_modint64_karatsuba(c: &mut Criterion, ring_degree: usize) {
+    let name = format!("Multiply polynomials of degree {} with type {} by Karatsuba", ring_degree,
+                       std::any::type_name::<ModInteger64>());
+    let poly1: Polynomial<ModInteger64> = generate_random_modint64_polynomial(ring_degree);
+    let poly2: Polynomial<ModInteger64> = generate_random_modint64_polynomial(ring_degree);
+    c.bench_function(name.as_str(), |b| {
+        b.iter(|| {
+            black_box(poly1.multiply_karatsuba(&poly2));
+        })
+    });
+}
+
+pub fn bench_modint<const Q: u64>(c: &mut Criterion, ring_degree: usize) {
+    let name = format!("Multiply polynomials of degree {} with type {}", ring_degree,
+                       std::any::type_name::<ModInteger<Q>>());
+    let poly1: Polynomial<ModInteger<Q>> = generate_random_modint_polynomial(ring_degree);
+    let poly2: Polynomial<ModInteger<Q>> = generate_random_modint_polynomial(ring_degree);
+    c.bench_function(name.as_str(), |b| {
+        b.iter(|| {
+            black_box(poly1.multiply(&poly2));
+        })
+    });
+}
+
+pub fn bench_modint_ntt<const Q: u64>(c: &mut Criterion, ring_degree: usize) {
+    let name = format!("Multiply negacyclic polynomials of degree {} with type {} by NTT", ring_degree,
+                       std::any::type_name::<ModInteger<Q>>());
+    let ntt: Ntt<Q, Negacyclic> = Ntt::new(ring_degree);
+    let poly1: Polynomial<ModInteger<Q>, Negacyclic> =
+        Polynomial::from_coeffs(generate_random_modint_polynomial::<Q>(ring_degree).coeffs);
+    let poly2: Polynomial<ModInteger<Q>, Negacyclic> =
+        Polynomial::from_coeffs(generate_random_modint_polynomial::<Q>(ring_degree).coeffs);
+    c.bench_function(name.as_str(), |b| {
+        b.iter(|| {
+            black_box(poly1.multiply_ntt(&poly2, &ntt));
+        })
+    });
+}
+
+pub fn bench_torus32(c: &mut Criterion, ring_degree: usize) {
+    let name = format!("Multiply polynomials of degree {} with type {}", ring_degree,
+                       std::any::type_name::<Torus32>());
+    let poly1: Polynomial<Torus32> = generate_random_torus32_polynomial(ring_degree);
+    let poly2: Polynomial<Torus32> = generate_random_torus32_polynomial(ring_degree);
+    c.bench_function(name.as_str(), |b| {
+        b.iter(|| {
+            black_box(poly1.multiply(&poly2));
+        })
+    });
+}
+
+pub fn bench_torus64(c: &mut Criterion, ring_degree: usize) {
+    let name = format!("Multiply polynomials of degree {} with type {}", ring_degree,
+                       std::any::type_name::<Torus64>());
+    let poly1: Polynomial<Torus64> = generate_random_torus64_polynomial(ring_degree);
+    let poly2: Polynomial<Torus64> = generate_random_torus64_polynomial(ring_degree);
+    c.bench_function(name.as_str(), |b| {
+        b.iter(|| {
+            black_box(poly1.multiply(&poly2));
+        })
+    });
+}
+
 pub fn bench_f32_2048(c: &mut Criterion) {
     bench_float::<f32>(c, 2048);
 }
@@ -79,10 +170,42 @@ pub fn bench_complex_f64_2048(c: &mut Criterion) {
     bench_complex::<f64>(c, 2048);
 }
 
+pub fn bench_f64_fft_2048(c: &mut Criterion) {
+    bench_float_fft::<f64>(c, 2048);
+}
+
+pub fn bench_f64_fft_3000(c: &mut Criterion) {
+    bench_float_fft::<f64>(c, 3000);
+}
+
+pub fn bench_complex_f64_fft_2048(c: &mut Criterion) {
+    bench_complex_fft::<f64>(c, 2048);
+}
+
 pub fn bench_modint32_2048(c: &mut Criterion) {
     bench_modint32(c, 2048);
 }
 
 pub fn bench_modint64_2048(c: &mut Criterion) {
     bench_modint64(c, 2048);
+}
+
+pub fn bench_modint64_karatsuba_2048(c: &mut Criterion) {
+    bench_modint64_karatsuba(c, 2048);
+}
+
+pub fn bench_modint_12289_2048(c: &mut Criterion) {
+    bench_modint::<12289>(c, 2048);
+}
+
+pub fn bench_modint_ntt_12289_2048(c: &mut Criterion) {
+    bench_modint_ntt::<12289>(c, 2048);
+}
+
+pub fn bench_torus32_2048(c: &mut Criterion) {
+    bench_torus32(c, 2048);
+}
+
+pub fn bench_torus64_2048(c: &mut Criterion) {
+    bench_torus64(c, 2048);
 }
\ No newline at end of file
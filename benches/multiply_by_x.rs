@@ -1,18 +1,16 @@
 use criterion::{black_box, Criterion};
 use rand::{distributions::Standard, prelude::Distribution};
 use zama_math::polynomial::Polynomial;
+use zama_math::quotient::Negacyclic;
 use zama_math::ring::{Complex, ModInteger32, ModInteger64};
-use zama_math::traits::{Abs, Zero, One};
-use std::ops::{Add, AddAssign, Sub, Mul, Div};
-use std::cmp::{PartialOrd, PartialEq};
-use std::fmt::Debug;
+use zama_math::traits::{Abs, Ring};
+use std::ops::Div;
+use std::cmp::PartialOrd;
 use zama_math::utilities::{generate_random_float_polynomial, generate_random_complex_polynomial,
                            generate_random_modint32_polynomial, generate_random_modint64_polynomial};
 
 pub fn bench_float<T>(c: &mut Criterion, ring_degree: usize)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd  + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     let name = format!("Multiply polynomials of degree {} by x with type {}", ring_degree,
                        std::any::type_name::<T>());
     let poly: Polynomial<T> = generate_random_float_polynomial::<T>(ring_degree);
@@ -24,9 +22,7 @@ pub fn bench_float<T>(c: &mut Criterion, ring_degree: usize)
 }
 
 pub fn bench_complex<T>(c: &mut Criterion, ring_degree: usize)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd  + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     let name = format!("Multiply polynomials of degree {} by x with type {}", ring_degree,
                        std::any::type_name::<Complex<T>>());
     let poly: Polynomial<Complex<T>> = generate_random_complex_polynomial::<T>(ring_degree);
@@ -59,6 +55,19 @@ pub fn bench_modint64(c: &mut Criterion, ring_degree: usize) {
     });
 }
 
+pub fn bench_modint64_monomial(c: &mut Criterion, ring_degree: usize) {
+    let name = format!("Multiply negacyclic polynomials of degree {} by x^k with type {}", ring_degree,
+                       std::any::type_name::<ModInteger64>());
+    let poly: Polynomial<ModInteger64, Negacyclic> =
+        Polynomial::from_coeffs(generate_random_modint64_polynomial(ring_degree).coeffs);
+    let exponent = ring_degree as i64 + 3;
+    c.bench_function(name.as_str(), |b| {
+        b.iter(|| {
+            black_box(poly.multiply_by_monomial(exponent));
+        })
+    });
+}
+
 pub fn bench_f32_2048(c: &mut Criterion) {
     bench_float::<f32>(c, 2048);
 }
@@ -106,3 +115,7 @@ pub fn bench_modint64_2048(c: &mut Criterion) {
 pub fn bench_modint64_4096(c: &mut Criterion) {
     bench_modint64(c, 4096);
 }
+
+pub fn bench_modint64_monomial_2048(c: &mut Criterion) {
+    bench_modint64_monomial(c, 2048);
+}
@@ -0,0 +1,158 @@
+//! automorphism module
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use crate::traits::Ring;
+use crate::polynomial::Polynomial;
+use crate::quotient::Quotient;
+use crate::error::RingMathError;
+
+/// Structure representing the Galois automorphism σ_k: X -> X^k of T[x]/(X^N ± 1)
+///
+/// The term a_i x^i is sent to a_i x^(ik). Since x^(2N) = 1 in both rings, only ik mod 2N matters:
+/// the term lands on x^(ik mod N), and in the negacyclic ring it changes sign when ik mod 2N is at
+/// least N. The tables only depend on N and k, so one instantiation serves every coefficient type
+/// and both quotients.
+///
+/// On the NTT form the automorphism is a pure permutation of the evaluations, since a(X^k) at a root
+/// of unity ζ is a(ζ^k), and ζ^k is another of the roots.
+///
+/// # Attributes
+/// * `ring_degree` - the degree N of the polynomial ring
+/// * `index` - the index k, reduced mod 2N
+/// * `destinations` - the position ik mod N each coefficient i is sent to
+/// * `wraps` - whether ik mod 2N is at least N, so that the coefficient is folded with the quotient
+/// * `cyclic_sources` - the evaluation each evaluation j is taken from, in the cyclic NTT form
+/// * `negacyclic_sources` - the evaluation each evaluation j is taken from, in the negacyclic NTT form
+pub struct Automorphism {
+    pub ring_degree: usize,
+    pub index: usize,
+    destinations: Vec<usize>,
+    wraps: Vec<bool>,
+    cyclic_sources: Vec<usize>,
+    negacyclic_sources: Vec<usize>
+}
+
+thread_local! {
+    /// Tables computed so far, by (N, k mod 2N).
+    static CACHE: RefCell<HashMap<(usize, usize), Rc<Automorphism>>> = RefCell::new(HashMap::new());
+}
+
+impl Automorphism {
+
+    /// Return the automorphism X -> X^k of the rings of degree N, with precomputed tables.
+    ///
+    /// # Arguments
+    /// * `ring_degree` - Degree N of ring.
+    /// * `index` - Index k, coprime to 2N.
+    ///
+    /// # Output
+    /// * a new instantiation of an Automorphism
+    ///
+    /// # Panics if N is zero or k is not coprime to 2N.
+    /// ```
+    pub fn new(ring_degree: usize, index: usize) -> Automorphism {
+        if ring_degree == 0 {
+            panic!("Ring degree should be positive. {}", ring_degree);
+        }
+        let order = 2 * ring_degree;
+        let index = index % order;
+        if gcd(index, order) != 1 {
+            panic!("{}", RingMathError::IndexNotCoprime(index, order));
+        }
+
+        let mut destinations: Vec<usize> = Vec::new();
+        let mut wraps: Vec<bool> = Vec::new();
+        for i in 0..ring_degree {
+            let exponent = i * index % order;
+            destinations.push(exponent % ring_degree);
+            wraps.push(exponent >= ring_degree);
+        }
+
+        // The cyclic NTT evaluates at ω^j = ψ^(2j), and the negacyclic NTT at ψ^(2j + 1).
+        let mut cyclic_sources: Vec<usize> = Vec::new();
+        let mut negacyclic_sources: Vec<usize> = Vec::new();
+        for j in 0..ring_degree {
+            cyclic_sources.push(j * index % ring_degree);
+            negacyclic_sources.push(((2 * j + 1) * index % order - 1) / 2);
+        }
+
+        Automorphism {ring_degree, index, destinations, wraps, cyclic_sources, negacyclic_sources}
+    }
+
+    /// Return the automorphism X -> X^k of the rings of degree N, from a per-thread cache of the
+    /// tables computed so far.
+    ///
+    /// # Arguments
+    /// * `ring_degree` - Degree N of ring.
+    /// * `index` - Index k, coprime to 2N.
+    ///
+    /// # Output
+    /// * a shared instantiation of an Automorphism
+    ///
+    /// # Panics if N is zero or k is not coprime to 2N.
+    /// ```
+    pub fn cached(ring_degree: usize, index: usize) -> Rc<Automorphism> {
+        let key = (ring_degree, index % (2 * ring_degree).max(1));
+        if let Some(automorphism) = CACHE.with(|cache| cache.borrow().get(&key).cloned()) {
+            return automorphism;
+        }
+        let automorphism = Rc::new(Automorphism::new(ring_degree, index));
+        CACHE.with(|cache| cache.borrow_mut().insert(key, automorphism.clone()));
+        automorphism
+    }
+
+    /// Check that a polynomial or list of evaluations has the size of the rings.
+    ///
+    /// # Panics if the size does not match N.
+    /// ```
+    fn check_ring_degree(&self, ring_degree: usize) {
+        if ring_degree != self.ring_degree {
+            panic!("Ring degrees should be equal. {} != {}", ring_degree, self.ring_degree);
+        }
+    }
+
+    /// Apply the automorphism to a polynomial in coefficient form.
+    ///
+    /// # Arguments
+    /// * `poly` - polynomial a(X)
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is a(X^k)
+    /// ```
+    pub fn apply<T: Ring, Q: Quotient<T>>(&self, poly: &Polynomial<T, Q>) -> Polynomial<T, Q> {
+        poly.check_coeff_length();
+        self.check_ring_degree(poly.ring_degree);
+
+        let mut result: Polynomial<T, Q> = Polynomial::new(self.ring_degree);
+        for ((coeff, &destination), &wrap) in poly.coeffs.iter().zip(self.destinations.iter())
+                .zip(self.wraps.iter()) {
+            result.coeffs[destination] = if wrap { Q::wrap(coeff.clone()) } else { coeff.clone() };
+        }
+        result
+    }
+
+    /// Apply the automorphism to a polynomial in NTT form, as a permutation of its evaluations.
+    ///
+    /// # Arguments
+    /// * `values` - the N evaluations of a(X), in the natural order of `Ntt::forward`
+    /// * `negacyclic` - whether the evaluations are at the roots of X^N + 1 rather than X^N - 1
+    ///
+    /// # Output
+    /// * the N evaluations of a(X^k)
+    /// ```
+    pub fn apply_evaluations<T: Clone>(&self, values: &[T], negacyclic: bool) -> Vec<T> {
+        self.check_ring_degree(values.len());
+
+        let sources = if negacyclic { &self.negacyclic_sources } else { &self.cyclic_sources };
+        sources.iter().map(|&source| values[source].clone()).collect()
+    }
+}
+
+/// Compute the greatest common divisor of two integers, by Euclid's algorithm.
+pub(crate) fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,150 @@
+// Tests
+use super::*;
+use rand::Rng;
+use crate::ring::ModInteger;
+use crate::ntt::Ntt;
+use crate::quotient::{Cyclic, Negacyclic};
+use crate::utilities::generate_random_modint_polynomial;
+
+const MAX_TEST_LOG_DEGREE: u32 = 10;
+const FALCON_Q: u64 = 12289;
+
+fn modint_coeffs<const M: u64>(values: &[u64]) -> Vec<ModInteger<M>> {
+    values.iter().map(|&value| ModInteger::new(value)).collect()
+}
+
+fn random_odd_index(ring_degree: usize) -> usize {
+    let mut rng = rand::thread_rng();
+    2 * rng.gen_range(0..ring_degree) + 1
+}
+
+#[test]
+fn test_automorphism_known_answer() {
+    let poly1: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(modint_coeffs(&[1, 2, 3, 4]));
+    let poly2: Polynomial<ModInteger<FALCON_Q>, Negacyclic> = Polynomial::from_coeffs(poly1.coeffs.clone());
+
+    // 1 + 2x^3 + 3x^6 + 4x^9 = 1 + 4x + 3x^2 + 2x^3 when x^4 = 1, and 1 + 4x - 3x^2 + 2x^3 when x^4 = -1
+    assert_eq!(poly1.automorphism(3).coeffs, modint_coeffs(&[1, 4, 3, 2]));
+    assert_eq!(poly2.automorphism(3).coeffs, modint_coeffs(&[1, 4, FALCON_Q - 3, 2]));
+    assert_eq!(poly2.automorphism(1).coeffs, poly2.coeffs);
+}
+
+fn test_automorphism_homomorphism<Q: Quotient<ModInteger<FALCON_Q>>>() {
+    // Generate random polynomials and a random odd index.
+    let mut rng = rand::thread_rng();
+    let ring_degree = 1 << rng.gen_range(0..=MAX_TEST_LOG_DEGREE);
+    let index = random_odd_index(ring_degree);
+    let poly1: Polynomial<ModInteger<FALCON_Q>, Q> =
+        Polynomial::from_coeffs(generate_random_modint_polynomial::<FALCON_Q>(ring_degree).coeffs);
+    let poly2: Polynomial<ModInteger<FALCON_Q>, Q> =
+        Polynomial::from_coeffs(generate_random_modint_polynomial::<FALCON_Q>(ring_degree).coeffs);
+
+    // Test that σ(a + b) = σ(a) + σ(b) and σ(a b) = σ(a) σ(b).
+    let sum = (&poly1 + &poly2).automorphism(index);
+    let prod = (&poly1 * &poly2).automorphism(index);
+
+    assert_eq!(sum.coeffs, (&poly1.automorphism(index) + &poly2.automorphism(index)).coeffs);
+    assert_eq!(prod.coeffs, (&poly1.automorphism(index) * &poly2.automorphism(index)).coeffs);
+}
+
+#[test]
+fn test_automorphism_homomorphism_cyclic() {
+    test_automorphism_homomorphism::<Cyclic>();
+}
+
+#[test]
+fn test_automorphism_homomorphism_negacyclic() {
+    test_automorphism_homomorphism::<Negacyclic>();
+}
+
+#[test]
+fn test_automorphism_compose() {
+    // Generate a random polynomial and two random odd indices.
+    let mut rng = rand::thread_rng();
+    let ring_degree = 1 << rng.gen_range(0..=MAX_TEST_LOG_DEGREE);
+    let index1 = random_odd_index(ring_degree);
+    let index2 = random_odd_index(ring_degree);
+    let poly: Polynomial<ModInteger<FALCON_Q>, Negacyclic> =
+        Polynomial::from_coeffs(generate_random_modint_polynomial::<FALCON_Q>(ring_degree).coeffs);
+
+    // Test that σ_k1(σ_k2(a)) = σ_(k1 k2)(a), and that σ_(2N - 1) is an involution.
+    let composed = poly.automorphism(index2).automorphism(index1);
+
+    assert_eq!(composed.coeffs, poly.automorphism(index1 * index2).coeffs);
+    assert_eq!(poly.automorphism(2 * ring_degree - 1).automorphism(2 * ring_degree - 1).coeffs, poly.coeffs);
+}
+
+#[test]
+fn test_automorphism_any_ring_degree() {
+    // The cyclic ring of degree 6 has automorphisms for k = 1, 5, 7 and 11.
+    let poly: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(modint_coeffs(&[1, 2, 3, 4, 5, 6]));
+
+    assert_eq!(poly.automorphism(5).coeffs, modint_coeffs(&[1, 6, 5, 4, 3, 2]));
+    assert_eq!(poly.automorphism(7).coeffs, poly.coeffs);
+}
+
+fn test_automorphism_ntt<Q: Quotient<ModInteger<FALCON_Q>>>() {
+    // Generate a random polynomial and a random odd index.
+    let mut rng = rand::thread_rng();
+    let ring_degree = 1 << rng.gen_range(0..=MAX_TEST_LOG_DEGREE);
+    let index = random_odd_index(ring_degree);
+    let ntt: Ntt<FALCON_Q, Q> = Ntt::new(ring_degree);
+    let poly: Polynomial<ModInteger<FALCON_Q>, Q> =
+        Polynomial::from_coeffs(generate_random_modint_polynomial::<FALCON_Q>(ring_degree).coeffs);
+
+    // Test that permuting the evaluations matches the automorphism on the coefficients.
+    let values = ntt.automorphism(&ntt.forward(&poly), index);
+
+    assert_eq!(values, ntt.forward(&poly.automorphism(index)));
+}
+
+#[test]
+fn test_automorphism_ntt_cyclic() {
+    test_automorphism_ntt::<Cyclic>();
+}
+
+#[test]
+fn test_automorphism_ntt_negacyclic() {
+    test_automorphism_ntt::<Negacyclic>();
+}
+
+#[test]
+fn test_cached_shares_tables() {
+    let automorphism1 = Automorphism::cached(8, 3);
+    let automorphism2 = Automorphism::cached(8, 19);
+
+    // 19 = 3 mod 16
+    assert!(Rc::ptr_eq(&automorphism1, &automorphism2));
+    assert_eq!(automorphism1.index, 3);
+    assert!(!Rc::ptr_eq(&automorphism1, &Automorphism::cached(16, 3)));
+}
+
+#[test]
+#[should_panic(expected = "Automorphism index should be coprime to 2N. 2 and 8 are not")]
+fn test_automorphism_even_index() {
+    let poly: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(modint_coeffs(&[1, 2, 3, 4]));
+
+    let _ = poly.automorphism(2);
+}
+
+#[test]
+fn test_try_automorphism() {
+    let mut poly: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(modint_coeffs(&[1, 2, 3, 4]));
+    let empty: Polynomial<ModInteger<FALCON_Q>> = Polynomial::new(0);
+
+    assert_eq!(poly.try_automorphism(3).unwrap().coeffs, poly.automorphism(3).coeffs);
+    assert_eq!(poly.try_automorphism(2).err(), Some(RingMathError::IndexNotCoprime(2, 8)));
+    assert_eq!(poly.try_automorphism(12).err(), Some(RingMathError::IndexNotCoprime(12, 8)));
+    assert!(empty.try_automorphism(3).unwrap().coeffs.is_empty());
+    poly.coeffs.pop();
+    assert_eq!(poly.try_automorphism(3).err(), Some(RingMathError::CoeffLengthInvalid(4, 3)));
+}
+
+#[test]
+#[should_panic(expected = "Ring degrees should be equal. 4 != 8")]
+fn test_apply_ring_degree_mismatch() {
+    let automorphism = Automorphism::new(8, 3);
+    let poly: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(modint_coeffs(&[1, 2, 3, 4]));
+
+    let _ = automorphism.apply(&poly);
+}
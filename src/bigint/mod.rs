@@ -0,0 +1,395 @@
+//! bigint module
+//!
+//! Integers mod a modulus q of hundreds of bits, as needed by CKKS and BGV at high levels. The
+//! modulus is held in L limbs of 64 bits, least significant first, where the number of limbs is
+//! fixed at compile time and the modulus itself is chosen at runtime.
+use std::ops::{Add, Sub, Mul, Neg, AddAssign, SubAssign, MulAssign};
+use std::cmp::{PartialEq, Eq, Ordering};
+use std::fmt;
+use crate::traits::{Zero, One, Norm, Ring, CommutativeRing};
+use crate::polynomial::Polynomial;
+use crate::quotient::Quotient;
+use crate::ring::{montgomery_inverse, MontgomeryContext, Residue};
+
+/// Add two numbers of L limbs, returning the sum mod 2^64L and the carry out.
+fn add_limbs<const L: usize>(a: &[u64; L], b: &[u64; L]) -> ([u64; L], bool) {
+    let mut sum = [0; L];
+    let mut carry = false;
+    for ((limb, &a_limb), &b_limb) in sum.iter_mut().zip(a.iter()).zip(b.iter()) {
+        let (partial, carry1) = a_limb.overflowing_add(b_limb);
+        let (total, carry2) = partial.overflowing_add(carry as u64);
+        *limb = total;
+        carry = carry1 || carry2;
+    }
+    (sum, carry)
+}
+
+/// Subtract two numbers of L limbs, returning the difference mod 2^64L and the borrow out.
+fn sub_limbs<const L: usize>(a: &[u64; L], b: &[u64; L]) -> ([u64; L], bool) {
+    let mut diff = [0; L];
+    let mut borrow = false;
+    for ((limb, &a_limb), &b_limb) in diff.iter_mut().zip(a.iter()).zip(b.iter()) {
+        let (partial, borrow1) = a_limb.overflowing_sub(b_limb);
+        let (total, borrow2) = partial.overflowing_sub(borrow as u64);
+        *limb = total;
+        borrow = borrow1 || borrow2;
+    }
+    (diff, borrow)
+}
+
+/// Compare two numbers of L limbs, from the most significant limb down.
+fn compare_limbs<const L: usize>(a: &[u64; L], b: &[u64; L]) -> Ordering {
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+/// Add two residues in [0, q), with L limbs.
+fn add_mod_limbs<const L: usize>(a: &[u64; L], b: &[u64; L], modulus: &[u64; L]) -> [u64; L] {
+    let (sum, carry) = add_limbs(a, b);
+    if carry || compare_limbs(&sum, modulus) != Ordering::Less { sub_limbs(&sum, modulus).0 } else { sum }
+}
+
+/// Subtract two residues in [0, q), with L limbs.
+fn sub_mod_limbs<const L: usize>(a: &[u64; L], b: &[u64; L], modulus: &[u64; L]) -> [u64; L] {
+    let (diff, borrow) = sub_limbs(a, b);
+    if borrow { add_limbs(&diff, modulus).0 } else { diff }
+}
+
+/// Montgomery multiplication of two numbers of L limbs, interleaving the product with the
+/// reduction one limb at a time (coarsely integrated operand scanning).
+///
+/// # Arguments
+/// * `a` - First factor, less than R = 2^64L.
+/// * `b` - Second factor, less than q.
+/// * `modulus` - Odd modulus q.
+/// * `modulus_neg_inv` - -q^-1 mod 2^64.
+///
+/// # Output
+/// * a b / R mod q, in [0, q)
+/// ```
+fn montgomery_multiply<const L: usize>(a: &[u64; L], b: &[u64; L], modulus: &[u64; L],
+                                       modulus_neg_inv: u64) -> [u64; L] {
+    // The running value t has L + 1 limbs, with the top limb held apart, and stays below 2R.
+    let mut t = [0; L];
+    let mut t_high: u64 = 0;
+    for &b_limb in b.iter() {
+        // Add a b_i to t, which may carry into one more limb above t_high.
+        let mut carry: u64 = 0;
+        for (t_limb, &a_limb) in t.iter_mut().zip(a.iter()) {
+            let wide = *t_limb as u128 + a_limb as u128 * b_limb as u128 + carry as u128;
+            *t_limb = wide as u64;
+            carry = (wide >> 64) as u64;
+        }
+        let wide = t_high as u128 + carry as u128;
+        t_high = wide as u64;
+        let t_top = (wide >> 64) as u64;
+
+        // Add m q, where m makes the lowest limb vanish, then shift t down by one limb.
+        let m = t[0].wrapping_mul(modulus_neg_inv);
+        let mut carry = ((t[0] as u128 + m as u128 * modulus[0] as u128) >> 64) as u64;
+        for j in 1..L {
+            let wide = t[j] as u128 + m as u128 * modulus[j] as u128 + carry as u128;
+            t[j - 1] = wide as u64;
+            carry = (wide >> 64) as u64;
+        }
+        let wide = t_high as u128 + carry as u128;
+        t[L - 1] = wide as u64;
+        t_high = t_top + (wide >> 64) as u64;
+    }
+
+    // The result is below 2q, so one subtraction brings it into [0, q).
+    if t_high != 0 || compare_limbs(&t, modulus) != Ordering::Less { sub_limbs(&t, modulus).0 } else { t }
+}
+
+/// Return the number of L limbs equal to a u64.
+fn limbs_from_u64<const L: usize>(value: u64) -> [u64; L] {
+    let mut limbs = [0; L];
+    limbs[0] = value;
+    limbs
+}
+
+/// Format a number of L limbs in hexadecimal.
+pub(crate) fn limbs_to_hex(limbs: &[u64]) -> String {
+    let mut hex = String::from("0x");
+    match limbs.iter().rposition(|&limb| limb != 0) {
+        Some(top) => {
+            hex.push_str(&format!("{:x}", limbs[top]));
+            for limb in limbs[..top].iter().rev() {
+                hex.push_str(&format!("{:016x}", limb));
+            }
+        }
+        None => hex.push('0')
+    }
+    hex
+}
+
+/// Return the nearest f64 to a number of L limbs.
+fn limbs_to_f64(limbs: &[u64]) -> f64 {
+    limbs.iter().rev().fold(0.0, |value, &limb| value * 2f64.powi(64) + limb as f64)
+}
+
+/// Structure holding a multi-limb modulus q chosen at runtime, with its precomputed Montgomery
+/// constants for R = 2^64L
+///
+/// Integers and polynomials created from a context borrow it, so they cannot outlive it, and
+/// arithmetic between integers of two contexts with different moduli panics.
+///
+/// # Attributes
+/// * `modulus` - the odd modulus q, as L limbs from the least significant
+/// * `modulus_neg_inv` - -q^-1 mod 2^64
+/// * `r2` - R^2 mod q
+#[derive(Debug)]
+pub struct BigModulusContext<const L: usize> {
+    pub modulus: [u64; L],
+    modulus_neg_inv: u64,
+    r2: [u64; L]
+}
+
+impl<const L: usize> BigModulusContext<L> {
+
+    /// Return a new modulus context.
+    ///
+    /// # Arguments
+    /// * `modulus` - Odd modulus q greater than 1, as L limbs from the least significant.
+    ///
+    /// # Output
+    /// * a new instantiation of a BigModulusContext
+    ///
+    /// # Panics if L is zero, or if q is even or less than 2.
+    /// ```
+    pub fn new(modulus: [u64; L]) -> BigModulusContext<L> {
+        if L == 0 || modulus[0] & 1 == 0 || modulus == limbs_from_u64(1) {
+            panic!("Modulus should be an odd number greater than 1. {}", limbs_to_hex(&modulus));
+        }
+
+        // Double 1 up to R^2 = 2^128L, reducing mod q at each step.
+        let mut r2: [u64; L] = limbs_from_u64(1);
+        for _ in 0..128 * L {
+            r2 = add_mod_limbs(&r2, &r2, &modulus);
+        }
+        BigModulusContext {modulus,
+                           modulus_neg_inv: montgomery_inverse(modulus[0]).wrapping_neg(),
+                           r2}
+    }
+
+    /// Return a new integer mod q tied to this context.
+    ///
+    /// # Arguments
+    /// * `value` - Value of the integer, which is reduced mod q.
+    ///
+    /// # Output
+    /// * a new instantiation of a BigModInteger
+    /// ```
+    pub fn integer(&self, value: u64) -> BigModInteger<'_, L> {
+        self.integer_from_limbs(limbs_from_u64(value))
+    }
+
+    /// Return a new integer mod q tied to this context, from its limbs.
+    ///
+    /// # Arguments
+    /// * `limbs` - Value of the integer as L limbs from the least significant, which is reduced
+    ///   mod q.
+    ///
+    /// # Output
+    /// * a new instantiation of a BigModInteger
+    /// ```
+    pub fn integer_from_limbs(&self, limbs: [u64; L]) -> BigModInteger<'_, L> {
+        BigModInteger{residue: Residue::Bound(self.value_to_montgomery(&limbs), self)}
+    }
+
+    /// Return a new polynomial with coefficients mod q tied to this context.
+    ///
+    /// # Arguments
+    /// * `values` - Values of the coefficients as L limbs each, which are reduced mod q.
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial
+    /// ```
+    pub fn polynomial<'a, Q: Quotient<BigModInteger<'a, L>>>(&'a self, values: &[[u64; L]])
+            -> Polynomial<BigModInteger<'a, L>, Q> {
+        Polynomial::from_coeffs(values.iter().map(|&limbs| self.integer_from_limbs(limbs)).collect())
+    }
+
+    /// Move a value below R into Montgomery form, reducing it mod q.
+    fn value_to_montgomery(&self, value: &[u64; L]) -> [u64; L] {
+        montgomery_multiply(value, &self.r2, &self.modulus, self.modulus_neg_inv)
+    }
+
+    fn montgomery_to_value(&self, montgomery: &[u64; L]) -> [u64; L] {
+        montgomery_multiply(montgomery, &limbs_from_u64(1), &self.modulus, self.modulus_neg_inv)
+    }
+
+    fn multiply(&self, a: &[u64; L], b: &[u64; L]) -> [u64; L] {
+        montgomery_multiply(a, b, &self.modulus, self.modulus_neg_inv)
+    }
+}
+
+impl<const L: usize> MontgomeryContext for BigModulusContext<L> {
+    type Montgomery = [u64; L];
+
+    fn constant_to_montgomery(&self, constant: i64) -> [u64; L] {
+        let magnitude = self.value_to_montgomery(&limbs_from_u64(constant.unsigned_abs()));
+        if constant < 0 {
+            sub_mod_limbs(&[0; L], &magnitude, &self.modulus)
+        } else {
+            magnitude
+        }
+    }
+
+    fn same_modulus(&self, other: &BigModulusContext<L>) -> bool {
+        self.modulus == other.modulus
+    }
+
+    fn modulus_string(&self) -> String {
+        limbs_to_hex(&self.modulus)
+    }
+
+    fn centered_magnitude(&self, montgomery: [u64; L]) -> f64 {
+        // The centered representative of v lies in (-q/2, q/2], so its magnitude is min(v, q - v).
+        let value = self.montgomery_to_value(&montgomery);
+        let complement = sub_limbs(&self.modulus, &value).0;
+        limbs_to_f64(&value).min(limbs_to_f64(&complement))
+    }
+}
+
+/// Structure representing an integer mod a multi-limb runtime modulus q held by a
+/// `BigModulusContext`
+///
+/// # Attributes
+/// * `residue` - Montgomery form of the integer with its context, or a context-free constant
+#[derive(Copy, Clone)]
+pub struct BigModInteger<'a, const L: usize> {
+    residue: Residue<'a, BigModulusContext<L>>
+}
+
+impl<'a, const L: usize> BigModInteger<'a, L> {
+
+    /// Return the context of the integer, or None for a context-free constant.
+    pub fn context(self) -> Option<&'a BigModulusContext<L>> {
+        self.residue.context()
+    }
+
+    /// Return the value of the integer, as L limbs from the least significant.
+    ///
+    /// # Output
+    /// * the limbs of the value in [0, q)
+    ///
+    /// # Panics if the integer is a context-free constant.
+    /// ```
+    pub fn to_limbs(self) -> [u64; L] {
+        match self.residue {
+            Residue::Constant(constant) => {
+                panic!("Integer should be tied to a modulus context. {} has none", constant)
+            }
+            Residue::Bound(montgomery, context) => context.montgomery_to_value(&montgomery)
+        }
+    }
+}
+
+impl<'a, const L: usize> Add for BigModInteger<'a, L> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        let residue = self.residue.combine(other.residue, |a, b, context| add_mod_limbs(&a, &b, &context.modulus),
+                                           i64::checked_add, '+');
+        BigModInteger{residue}
+    }
+}
+
+impl<'a, const L: usize> AddAssign for BigModInteger<'a, L> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<'a, const L: usize> Sub for BigModInteger<'a, L> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        let residue = self.residue.combine(other.residue, |a, b, context| sub_mod_limbs(&a, &b, &context.modulus),
+                                           i64::checked_sub, '-');
+        BigModInteger{residue}
+    }
+}
+
+impl<'a, const L: usize> SubAssign for BigModInteger<'a, L> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<'a, const L: usize> Neg for BigModInteger<'a, L> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::zero() - self
+    }
+}
+
+impl<'a, const L: usize> Mul for BigModInteger<'a, L> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        let residue = self.residue.combine(other.residue, |a, b, context| context.multiply(&a, &b),
+                                           i64::checked_mul, '*');
+        BigModInteger{residue}
+    }
+}
+
+ref_mul_copy!(impl['a, const L: usize] for BigModInteger<'a, L>);
+
+impl<'a, const L: usize> MulAssign for BigModInteger<'a, L> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<'a, const L: usize> Zero for BigModInteger<'a, L> {
+    fn zero() -> BigModInteger<'a, L> {
+        BigModInteger{residue: Residue::Constant(0)}
+    }
+}
+
+impl<'a, const L: usize> One for BigModInteger<'a, L> {
+    fn one() -> BigModInteger<'a, L> {
+        BigModInteger{residue: Residue::Constant(1)}
+    }
+}
+
+impl<'a, const L: usize> fmt::Debug for BigModInteger<'a, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.residue {
+            Residue::Constant(constant) => {
+                f.debug_struct("BigModInteger")
+                 .field("constant", &constant)
+                 .finish()
+            }
+            Residue::Bound(montgomery, context) => {
+                f.debug_struct("BigModInteger")
+                 .field("value", &limbs_to_hex(&context.montgomery_to_value(&montgomery)))
+                 .field("modulus", &limbs_to_hex(&context.modulus))
+                 .finish()
+            }
+        }
+    }
+}
+
+impl<'a, const L: usize> PartialEq for BigModInteger<'a, L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.residue == other.residue
+    }
+}
+
+impl<'a, const L: usize> Eq for BigModInteger<'a, L> {}
+
+impl<'a, const L: usize> Ring for BigModInteger<'a, L> {}
+
+impl<'a, const L: usize> CommutativeRing for BigModInteger<'a, L> {}
+
+impl<'a, const L: usize> Norm for BigModInteger<'a, L> {
+    fn norm(self) -> f64 {
+        self.residue.norm()
+    }
+}
+
+#[cfg(test)]
+mod tests;
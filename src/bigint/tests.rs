@@ -0,0 +1,198 @@
+// Tests
+use super::*;
+use rand::Rng;
+use crate::polynomial::Polynomial;
+use crate::quotient::Negacyclic;
+use crate::ring::ModulusContext;
+use crate::utilities::generate_random_big_modint_polynomial;
+
+const NUM_RANDOM_TESTS: usize = 1000;
+const MAX_TEST_DEGREE: usize = 256;
+const GOLDILOCKS_Q: u64 = 0xffff_ffff_0000_0001;
+// 2^127 - 1
+const MERSENNE_127_Q: [u64; 2] = [u64::MAX, u64::MAX >> 1];
+
+/// Return a random odd modulus of exactly 64 L bits.
+fn random_modulus<const L: usize>() -> [u64; L] {
+    let mut rng = rand::thread_rng();
+    let mut modulus = [0; L];
+    rng.fill(&mut modulus[..]);
+    modulus[0] |= 1;
+    modulus[L - 1] |= 1 << 63;
+    modulus
+}
+
+fn limbs_to_u128(limbs: [u64; 2]) -> u128 {
+    limbs[0] as u128 | (limbs[1] as u128) << 64
+}
+
+fn u128_to_limbs(value: u128) -> [u64; 2] {
+    [value as u64, (value >> 64) as u64]
+}
+
+/// Multiply mod q < 2^127 by doubling and adding, so that no sum overflows a u128.
+fn multiply_mod_u128(a: u128, b: u128, modulus: u128) -> u128 {
+    let mut result = 0;
+    for bit in (0..128).rev() {
+        result = (result + result) % modulus;
+        if (b >> bit) & 1 == 1 {
+            result = (result + a) % modulus;
+        }
+    }
+    result
+}
+
+#[test]
+#[should_panic(expected = "Modulus should be an odd number greater than 1. 0x10000000000000000")]
+fn test_big_modulus_context_even_fail() {
+    BigModulusContext::new([0, 1]);
+}
+
+#[test]
+fn test_big_modint_known_answer() {
+    let context = BigModulusContext::new(MERSENNE_127_Q);
+    let minus_one = context.integer_from_limbs([u64::MAX - 1, u64::MAX >> 1]);
+    let two = context.integer_from_limbs([1, 1 << 63]);
+
+    assert_eq!(two.to_limbs(), [2, 0]);
+    assert_eq!((minus_one * minus_one).to_limbs(), [1, 0]);
+    assert_eq!((minus_one + two).to_limbs(), [1, 0]);
+    assert_eq!((two - minus_one).to_limbs(), [3, 0]);
+    assert_eq!((-two).to_limbs(), [u64::MAX - 2, u64::MAX >> 1]);
+    assert_eq!(context.integer(1 << 63) * context.integer(4), context.integer_from_limbs([0, 2]));
+    assert_eq!(two.context().unwrap().modulus, MERSENNE_127_Q);
+}
+
+#[test]
+fn test_big_modint_constants() {
+    let context = BigModulusContext::new(MERSENNE_127_Q);
+    let five = context.integer(5);
+
+    // Constants pick up the context of the integer they are combined with.
+    assert_eq!((five + BigModInteger::one()).to_limbs(), [6, 0]);
+    assert_eq!((BigModInteger::zero() - five).to_limbs(), [u64::MAX - 5, u64::MAX >> 1]);
+    assert_eq!((five * BigModInteger::zero()).to_limbs(), [0, 0]);
+    assert_eq!(context.integer(1), BigModInteger::one());
+    assert_eq!(context.integer_from_limbs([u64::MAX - 1, u64::MAX >> 1]), BigModInteger::zero() - BigModInteger::one());
+    assert!(BigModInteger::<2>::one().context().is_none());
+}
+
+#[test]
+#[should_panic(expected = "Integer should be tied to a modulus context. 1 has none")]
+fn test_big_modint_constant_to_limbs_fail() {
+    BigModInteger::<2>::one().to_limbs();
+}
+
+#[test]
+#[should_panic(expected = "Modulus contexts should be equal. 0x7fffffffffffffffffffffffffffffff != 0x3")]
+fn test_big_modint_mixed_contexts_fail() {
+    let context1 = BigModulusContext::new(MERSENNE_127_Q);
+    let context2 = BigModulusContext::new([3, 0]);
+
+    // Should panic because the integers belong to different contexts.
+    let _ = context1.integer(1) + context2.integer(1);
+}
+
+#[test]
+#[should_panic(expected = "Integer arithmetic should not overflow. 4611686018427387904 * 2")]
+fn test_big_modint_constant_overflow_fail() {
+    // Doubling one 63 times overflows an i64, which would not be congruent mod q once wrapped.
+    let two = BigModInteger::<2>::one() + BigModInteger::one();
+    let mut constant = BigModInteger::<2>::one();
+    for _ in 0..63 {
+        constant *= two;
+    }
+}
+
+#[test]
+fn test_big_modint_compare_context_modint() {
+    let mut rng = rand::thread_rng();
+    let context = ModulusContext::new(GOLDILOCKS_Q);
+    let big_context = BigModulusContext::new([GOLDILOCKS_Q]);
+    for _ in 0..NUM_RANDOM_TESTS {
+        let (a, b): (u64, u64) = (rng.gen(), rng.gen());
+        let (context_a, context_b) = (context.integer(a), context.integer(b));
+        let (big_a, big_b) = (big_context.integer(a), big_context.integer(b));
+
+        assert_eq!((big_a + big_b).to_limbs(), [(context_a + context_b).value()]);
+        assert_eq!((big_a - big_b).to_limbs(), [(context_a - context_b).value()]);
+        assert_eq!((big_a * big_b).to_limbs(), [(context_a * context_b).value()]);
+        assert_eq!((-big_a).to_limbs(), [(-context_a).value()]);
+    }
+}
+
+#[test]
+fn test_big_modint_compare_u128() {
+    let mut rng = rand::thread_rng();
+    let mut modulus = random_modulus::<2>();
+    modulus[1] >>= 1;
+    let context = BigModulusContext::new(modulus);
+    let q = limbs_to_u128(modulus);
+    for _ in 0..NUM_RANDOM_TESTS {
+        let (a, b): (u128, u128) = (rng.gen_range(0..q), rng.gen_range(0..q));
+        let (big_a, big_b) = (context.integer_from_limbs(u128_to_limbs(a)), context.integer_from_limbs(u128_to_limbs(b)));
+
+        assert_eq!(limbs_to_u128((big_a + big_b).to_limbs()), (a + b) % q);
+        assert_eq!(limbs_to_u128((big_a - big_b).to_limbs()), (a + q - b) % q);
+        assert_eq!(limbs_to_u128((big_a * big_b).to_limbs()), multiply_mod_u128(a, b, q));
+    }
+}
+
+fn test_big_modint_ring_laws<const L: usize>() {
+    let mut rng = rand::thread_rng();
+    let modulus = random_modulus::<L>();
+    let context = BigModulusContext::new(modulus);
+    for _ in 0..NUM_RANDOM_TESTS {
+        let (mut a, mut b, mut c) = ([0; L], [0; L], [0; L]);
+        rng.fill(&mut a[..]);
+        rng.fill(&mut b[..]);
+        rng.fill(&mut c[..]);
+        let (big_a, big_b, big_c) = (context.integer_from_limbs(a), context.integer_from_limbs(b),
+                                     context.integer_from_limbs(c));
+
+        // Test that limbs are reduced into [0, q), and that reduced limbs convert back unchanged.
+        let reduced = big_a.to_limbs();
+        assert_eq!(compare_limbs(&reduced, &modulus), Ordering::Less);
+        assert_eq!(context.integer_from_limbs(reduced).to_limbs(), reduced);
+
+        assert_eq!((big_a * big_b) * big_c, big_a * (big_b * big_c));
+        assert_eq!((big_a + big_b) * big_c, big_a * big_c + big_b * big_c);
+        assert_eq!(big_a - big_b + big_b, big_a);
+        assert_eq!(big_a * BigModInteger::one(), big_a);
+    }
+
+    // q - 1 = -1 squares to 1, even with every bit of the top limb in use.
+    let mut minus_one = modulus;
+    minus_one[0] -= 1;
+    let minus_one = context.integer_from_limbs(minus_one);
+    assert_eq!(minus_one * minus_one, BigModInteger::one());
+    assert_eq!(minus_one.norm(), 1.0);
+}
+
+#[test]
+fn test_big_modint_ring_laws_256() {
+    test_big_modint_ring_laws::<4>();
+}
+
+#[test]
+fn test_big_modint_ring_laws_448() {
+    test_big_modint_ring_laws::<7>();
+}
+
+#[test]
+fn test_big_modint_polynomial_multiply() {
+    // Generate random polynomials over a 384-bit modulus.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let context = BigModulusContext::new(random_modulus::<6>());
+    let poly1: Polynomial<BigModInteger<6>, Negacyclic> = Polynomial::from_coeffs(generate_random_big_modint_polynomial(&context, ring_degree).coeffs);
+    let poly2: Polynomial<BigModInteger<6>, Negacyclic> = Polynomial::from_coeffs(generate_random_big_modint_polynomial(&context, ring_degree).coeffs);
+
+    assert_eq!(poly1.multiply_karatsuba(&poly2).coeffs, poly1.multiply(&poly2).coeffs);
+
+    // x^(N - 1) x = -1 in the negacyclic ring.
+    let mut monomial = [[0; 6]; MAX_TEST_DEGREE];
+    monomial[ring_degree - 1][0] = 1;
+    let monomial: Polynomial<BigModInteger<6>, Negacyclic> = context.polynomial(&monomial[..ring_degree]);
+    assert_eq!(monomial.multiply_by_x().coeffs[0], context.integer(0) - context.integer(1));
+}
@@ -0,0 +1,71 @@
+//! error module
+use std::error::Error;
+use std::fmt;
+
+/// Enum representing the ways an operation on polynomials, vectors or matrices can fail
+///
+/// Variants with fields carry the two sizes or indices which were compared, in the order they
+/// were compared.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RingMathError {
+    /// The ring degrees of two polynomials differ.
+    RingDegreeMismatch(usize, usize),
+    /// The number of coefficients of a polynomial differs from its ring degree.
+    CoeffLengthInvalid(usize, usize),
+    /// The lengths of two vectors differ.
+    LengthMismatch(usize, usize),
+    /// The number of polynomials of a vector differs from its length.
+    PolyCountInvalid(usize, usize),
+    /// The number of rows of two matrices differ.
+    RowLengthMismatch(usize, usize),
+    /// The number of columns of two matrices differ.
+    ColumnLengthMismatch(usize, usize),
+    /// The number of column vectors of a matrix differs from its number of columns.
+    ColumnCountInvalid(usize, usize),
+    /// The number of rows of a matrix differs from the length of the vector multiplying it.
+    MatrixVectorMismatch(usize, usize),
+    /// More interpolation points were given than the ring degree can hold.
+    TooManyPoints(usize, usize),
+    /// Two interpolation points, given by their indices, are equal.
+    RepeatedPoint(usize, usize),
+    /// An automorphism index k is not coprime to 2N, so X -> X^k is not invertible.
+    IndexNotCoprime(usize, usize),
+    /// A polynomial was divided by the zero polynomial.
+    DivisionByZero,
+    /// A complex number with a nonzero imaginary part was converted to a real type.
+    NonzeroImaginaryPart
+}
+
+impl fmt::Display for RingMathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RingMathError::RingDegreeMismatch(a, b) => write!(f, "Ring degrees should be equal. {} != {}", a, b),
+            RingMathError::CoeffLengthInvalid(a, b) =>
+                write!(f, "Ring degree should be equal to vector length. {} != {}", a, b),
+            RingMathError::LengthMismatch(a, b) => write!(f, "Vector lengths should be equal. {} != {}", a, b),
+            RingMathError::PolyCountInvalid(a, b) =>
+                write!(f, "Vector length should be equal to number of polynomials. {} != {}", a, b),
+            RingMathError::RowLengthMismatch(a, b) => write!(f, "Row lengths should be equal. {} != {}", a, b),
+            RingMathError::ColumnLengthMismatch(a, b) =>
+                write!(f, "Column lengths should be equal. {} != {}", a, b),
+            RingMathError::ColumnCountInvalid(a, b) =>
+                write!(f, "Number of columns should be equal to number of column vectors. {} != {}", a, b),
+            RingMathError::MatrixVectorMismatch(a, b) =>
+                write!(f, "Matrix row length should equal size of vector. {} != {}", a, b),
+            RingMathError::TooManyPoints(a, b) =>
+                write!(f, "Number of points should be at most the ring degree. {} > {}", a, b),
+            RingMathError::RepeatedPoint(a, b) =>
+                write!(f, "Interpolation points should be distinct. Points {} and {} are equal", a, b),
+            RingMathError::IndexNotCoprime(a, b) =>
+                write!(f, "Automorphism index should be coprime to 2N. {} and {} are not", a, b),
+            RingMathError::DivisionByZero => write!(f, "Divisor should be a nonzero polynomial."),
+            RingMathError::NonzeroImaginaryPart =>
+                write!(f, "Imaginary part should be zero to convert to a real number.")
+        }
+    }
+}
+
+impl Error for RingMathError {}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,34 @@
+// Tests
+use super::*;
+
+#[test]
+fn test_display_matches_panic_messages() {
+    assert_eq!(RingMathError::RingDegreeMismatch(10, 14).to_string(), "Ring degrees should be equal. 10 != 14");
+    assert_eq!(RingMathError::CoeffLengthInvalid(10, 9).to_string(),
+               "Ring degree should be equal to vector length. 10 != 9");
+    assert_eq!(RingMathError::LengthMismatch(2, 3).to_string(), "Vector lengths should be equal. 2 != 3");
+    assert_eq!(RingMathError::PolyCountInvalid(3, 2).to_string(),
+               "Vector length should be equal to number of polynomials. 3 != 2");
+    assert_eq!(RingMathError::RowLengthMismatch(2, 3).to_string(), "Row lengths should be equal. 2 != 3");
+    assert_eq!(RingMathError::ColumnLengthMismatch(2, 3).to_string(), "Column lengths should be equal. 2 != 3");
+    assert_eq!(RingMathError::ColumnCountInvalid(3, 2).to_string(),
+               "Number of columns should be equal to number of column vectors. 3 != 2");
+    assert_eq!(RingMathError::MatrixVectorMismatch(3, 2).to_string(),
+               "Matrix row length should equal size of vector. 3 != 2");
+    assert_eq!(RingMathError::TooManyPoints(5, 4).to_string(),
+               "Number of points should be at most the ring degree. 5 > 4");
+    assert_eq!(RingMathError::RepeatedPoint(0, 3).to_string(),
+               "Interpolation points should be distinct. Points 0 and 3 are equal");
+    assert_eq!(RingMathError::IndexNotCoprime(4, 16).to_string(),
+               "Automorphism index should be coprime to 2N. 4 and 16 are not");
+    assert_eq!(RingMathError::DivisionByZero.to_string(), "Divisor should be a nonzero polynomial.");
+    assert_eq!(RingMathError::NonzeroImaginaryPart.to_string(),
+               "Imaginary part should be zero to convert to a real number.");
+}
+
+#[test]
+fn test_is_std_error() {
+    let err: Box<dyn Error> = Box::new(RingMathError::LengthMismatch(2, 3));
+
+    assert_eq!(err.to_string(), "Vector lengths should be equal. 2 != 3");
+}
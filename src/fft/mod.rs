@@ -0,0 +1,222 @@
+//! fft module
+use std::f64::consts::PI;
+use crate::traits::Ring;
+use crate::ring::Complex;
+use crate::polynomial::Polynomial;
+use crate::quotient::Quotient;
+use crate::error::RingMathError;
+
+/// Trait describing a coefficient type which can be multiplied with the fast Fourier transform
+///
+/// The transform itself always runs on `Complex<f64>`, so `f32` coefficients are only rounded
+/// once, when the product is converted back.
+pub trait FftCoefficient: Copy {
+    /// Convert the coefficient to a complex number.
+    fn to_complex(self) -> Complex<f64>;
+
+    /// Convert a complex number back to a coefficient, dropping the imaginary part of real types.
+    fn from_complex(value: Complex<f64>) -> Self;
+}
+
+impl FftCoefficient for f32 {
+    fn to_complex(self) -> Complex<f64> {
+        Complex{real: self as f64, imag: 0.0}
+    }
+
+    fn from_complex(value: Complex<f64>) -> f32 {
+        value.real as f32
+    }
+}
+
+impl FftCoefficient for f64 {
+    fn to_complex(self) -> Complex<f64> {
+        Complex{real: self, imag: 0.0}
+    }
+
+    fn from_complex(value: Complex<f64>) -> f64 {
+        value.real
+    }
+}
+
+impl FftCoefficient for Complex<f32> {
+    fn to_complex(self) -> Complex<f64> {
+        Complex{real: self.real as f64, imag: self.imag as f64}
+    }
+
+    fn from_complex(value: Complex<f64>) -> Complex<f32> {
+        Complex{real: value.real as f32, imag: value.imag as f32}
+    }
+}
+
+impl FftCoefficient for Complex<f64> {
+    fn to_complex(self) -> Complex<f64> {
+        self
+    }
+
+    fn from_complex(value: Complex<f64>) -> Complex<f64> {
+        value
+    }
+}
+
+/// Structure representing a fast Fourier transform for multiplying polynomials of degree N
+///
+/// N can be any positive integer. The polynomials are zero-padded to the power of two `size` which
+/// holds their full product of degree 2N - 2, and the terms from x^N up are folded back with the
+/// quotient of the ring, so the same instantiation serves both the cyclic and negacyclic rings.
+///
+/// The product is computed in double precision. Each output coefficient has an absolute error of
+/// roughly log2(size) * 2^-52 * N * max|a_i| * max|b_j|, so products of coefficients in [0, 1)
+/// agree with the schoolbook product to the `F64_ERROR` relative tolerance of the tests.
+///
+/// # Attributes
+/// * `ring_degree` - the degree N of the polynomial ring
+/// * `size` - the size of the transform, the smallest power of two at least 2N - 1
+/// * `twiddles` - the roots of unity e^(-2πik/size) for k < size/2
+pub struct Fft {
+    pub ring_degree: usize,
+    size: usize,
+    twiddles: Vec<Complex<f64>>
+}
+
+impl Fft {
+
+    /// Return a new transform for polynomials of degree N, with precomputed twiddle factors.
+    ///
+    /// # Arguments
+    /// * `ring_degree` - Degree N of ring.
+    ///
+    /// # Output
+    /// * a new instantiation of an Fft
+    ///
+    /// # Panics if N is 0.
+    /// ```
+    pub fn new(ring_degree: usize) -> Fft {
+        if ring_degree == 0 {
+            panic!("Ring degree should be positive. {}", ring_degree);
+        }
+
+        let size = (2 * ring_degree - 1).next_power_of_two();
+        let mut twiddles: Vec<Complex<f64>> = Vec::new();
+        for k in 0..size / 2 {
+            // Compute each root directly, since repeated multiplication accumulates error.
+            let angle = -2.0 * PI * k as f64 / size as f64;
+            twiddles.push(Complex{real: angle.cos(), imag: angle.sin()});
+        }
+        Fft {ring_degree, size, twiddles}
+    }
+
+    /// Compute the transform in place, with the Cooley-Tukey butterfly.
+    ///
+    /// # Arguments
+    /// * `values` - the values to transform, replaced by the transform in natural order
+    /// * `inverse` - whether to compute the inverse transform, including the division by `size`
+    /// ```
+    fn transform(&self, values: &mut [Complex<f64>], inverse: bool) {
+        let n = self.size;
+
+        // Permute the values into bit-reversed order.
+        let mut j = 0;
+        for i in 1..n {
+            let mut bit = n >> 1;
+            while j & bit != 0 {
+                j ^= bit;
+                bit >>= 1;
+            }
+            j |= bit;
+            if i < j {
+                values.swap(i, j);
+            }
+        }
+
+        // Combine transforms of size len / 2 into transforms of size len.
+        let mut len = 2;
+        while len <= n {
+            let step = n / len;
+            for start in (0..n).step_by(len) {
+                for k in 0..len / 2 {
+                    let mut twiddle = self.twiddles[k * step];
+                    if inverse {
+                        twiddle.imag = -twiddle.imag;
+                    }
+                    let u = values[start + k];
+                    let v = values[start + k + len / 2] * twiddle;
+                    values[start + k] = u + v;
+                    values[start + k + len / 2] = u - v;
+                }
+            }
+            len <<= 1;
+        }
+
+        if inverse {
+            let scale = 1.0 / n as f64;
+            for value in values.iter_mut() {
+                value.real *= scale;
+                value.imag *= scale;
+            }
+        }
+    }
+
+    /// Multiply two polynomials in the ring, by pointwise multiplication of their transforms.
+    ///
+    /// # Arguments
+    /// * `poly1` - first polynomial to multiply
+    /// * `poly2` - second polynomial to multiply
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the product of the two polynomials, or the
+    ///   `RingMathError` describing why the polynomials cannot be multiplied
+    /// ```
+    pub fn try_multiply<T, Q>(&self, poly1: &Polynomial<T, Q>, poly2: &Polynomial<T, Q>)
+            -> Result<Polynomial<T, Q>, RingMathError>
+            where T: Ring + FftCoefficient,
+            Q: Quotient<T> {
+        for poly in [poly1, poly2].iter() {
+            poly.try_check_coeff_length()?;
+            if poly.ring_degree != self.ring_degree {
+                return Err(RingMathError::RingDegreeMismatch(poly.ring_degree, self.ring_degree));
+            }
+        }
+
+        let zero = Complex{real: 0.0, imag: 0.0};
+        let mut values1: Vec<Complex<f64>> = vec![zero; self.size];
+        let mut values2: Vec<Complex<f64>> = vec![zero; self.size];
+        for i in 0..self.ring_degree {
+            values1[i] = poly1.coeffs[i].to_complex();
+            values2[i] = poly2.coeffs[i].to_complex();
+        }
+        self.transform(&mut values1, false);
+        self.transform(&mut values2, false);
+        for i in 0..self.size {
+            values1[i] *= values2[i];
+        }
+        self.transform(&mut values1, true);
+
+        // Fold the x^(N + i) terms of the full product onto the x^i terms.
+        let mut prod: Polynomial<T, Q> = Polynomial::new(self.ring_degree);
+        for i in 0..self.ring_degree {
+            prod.coeffs[i] = T::from_complex(values1[i]);
+            if i + self.ring_degree < 2 * self.ring_degree - 1 {
+                prod.coeffs[i] += Q::wrap(T::from_complex(values1[i + self.ring_degree]));
+            }
+        }
+        Ok(prod)
+    }
+
+    /// Multiply two polynomials in the ring, by pointwise multiplication of their transforms.
+    ///
+    /// # Arguments
+    /// * `poly1` - first polynomial to multiply
+    /// * `poly2` - second polynomial to multiply
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the product of the two polynomials
+    /// ```
+    pub fn multiply<T, Q>(&self, poly1: &Polynomial<T, Q>, poly2: &Polynomial<T, Q>) -> Polynomial<T, Q>
+            where T: Ring + FftCoefficient,
+            Q: Quotient<T> {
+        self.try_multiply(poly1, poly2).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,125 @@
+// Tests
+use super::*;
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+use crate::utilities::{check_vecs_almost_equal, generate_random_float_polynomial,
+                       generate_random_complex_polynomial};
+use crate::quotient::{Cyclic, Negacyclic};
+use crate::traits::Abs;
+
+const MAX_TEST_DEGREE: usize = 2048;
+const F32_MULTIPLY_ERROR: f64 = 0.01;
+const F64_ERROR: f64 = 0.0000000001;
+const F64_ABSOLUTE_ERROR: f64 = 0.000001;
+
+#[test]
+fn test_multiply_known_answer() {
+    let fft = Fft::new(3);
+    let poly1: Polynomial<f64> = Polynomial::from_coeffs(vec![1.0, 2.0, 3.0]);
+    let poly2: Polynomial<f64> = Polynomial::from_coeffs(vec![4.0, 5.0, 6.0]);
+    let poly3: Polynomial<f64, Negacyclic> = Polynomial::from_coeffs(vec![1.0, 2.0, 3.0]);
+    let poly4: Polynomial<f64, Negacyclic> = Polynomial::from_coeffs(vec![4.0, 5.0, 6.0]);
+
+    // (1 + 2x + 3x^2)(4 + 5x + 6x^2) = 4 + 13x + 28x^2 + 27x^3 + 18x^4
+    assert!(check_vecs_almost_equal(&fft.multiply(&poly1, &poly2).coeffs, &[31.0, 31.0, 28.0], F64_ERROR));
+    assert!(check_vecs_almost_equal(&fft.multiply(&poly3, &poly4).coeffs, &[-23.0, -5.0, 28.0], F64_ERROR));
+}
+
+fn test_multiply_matches_schoolbook<T>(generate_random_polynomial: fn(usize) -> Polynomial<T>, error: f64)
+        where T: Ring + FftCoefficient + Abs {
+    // Generate random polynomials of any degree, not only powers of two.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let fft = Fft::new(ring_degree);
+    let poly1: Polynomial<T> = generate_random_polynomial(ring_degree);
+    let poly2: Polynomial<T> = generate_random_polynomial(ring_degree);
+
+    let prod1 = poly1.multiply(&poly2);
+    let prod2 = poly1.multiply_fft(&poly2, &fft);
+
+    assert_eq!(prod1.ring_degree, prod2.ring_degree);
+    assert!(check_vecs_almost_equal(&prod1.coeffs, &prod2.coeffs, error));
+}
+
+#[test]
+fn test_multiply_matches_schoolbook_f32() {
+    test_multiply_matches_schoolbook(generate_random_float_polynomial::<f32>, F32_MULTIPLY_ERROR);
+}
+
+#[test]
+fn test_multiply_matches_schoolbook_f64() {
+    test_multiply_matches_schoolbook(generate_random_float_polynomial::<f64>, F64_ERROR);
+}
+
+#[test]
+fn test_multiply_matches_schoolbook_complex_f32() {
+    test_multiply_matches_schoolbook(generate_random_complex_polynomial::<f32>, F32_MULTIPLY_ERROR);
+}
+
+#[test]
+fn test_multiply_matches_schoolbook_complex_f64() {
+    test_multiply_matches_schoolbook(generate_random_complex_polynomial::<f64>, F64_ERROR);
+}
+
+fn test_multiply_negacyclic_integer_coeffs<T>()
+        where Standard: Distribution<T>, T: Ring + FftCoefficient + Abs {
+    // Coefficients in {0, 1} make the schoolbook product exact, and the negacyclic product can
+    // cancel to 0, so the error is measured in absolute terms.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let fft = Fft::new(ring_degree);
+    let mut coeffs1: Vec<T> = Vec::new();
+    let mut coeffs2: Vec<T> = Vec::new();
+    for _ in 0..ring_degree {
+        coeffs1.push(if rng.gen::<bool>() { T::one() } else { T::zero() });
+        coeffs2.push(if rng.gen::<bool>() { T::one() } else { T::zero() });
+    }
+    let poly1: Polynomial<T, Negacyclic> = Polynomial::from_coeffs(coeffs1);
+    let poly2: Polynomial<T, Negacyclic> = Polynomial::from_coeffs(coeffs2);
+
+    let prod1 = poly1.multiply(&poly2);
+    let prod2 = poly1.multiply_fft(&poly2, &fft);
+
+    for i in 0..ring_degree {
+        assert!((prod1.coeffs[i] - prod2.coeffs[i]).abs() < F64_ABSOLUTE_ERROR,
+                "{:?} != {:?}", prod1.coeffs[i], prod2.coeffs[i]);
+    }
+}
+
+#[test]
+fn test_multiply_negacyclic_integer_coeffs_f32() {
+    test_multiply_negacyclic_integer_coeffs::<f32>();
+}
+
+#[test]
+fn test_multiply_negacyclic_integer_coeffs_f64() {
+    test_multiply_negacyclic_integer_coeffs::<f64>();
+}
+
+#[test]
+fn test_multiply_small_degrees() {
+    for ring_degree in 1..=8 {
+        let fft = Fft::new(ring_degree);
+        let poly1: Polynomial<f64, Cyclic> = generate_random_float_polynomial(ring_degree);
+        let poly2: Polynomial<f64, Cyclic> = generate_random_float_polynomial(ring_degree);
+
+        assert!(check_vecs_almost_equal(&poly1.multiply(&poly2).coeffs, &fft.multiply(&poly1, &poly2).coeffs,
+                                        F64_ERROR));
+    }
+}
+
+#[test]
+#[should_panic(expected = "Ring degree should be positive. 0")]
+fn test_new_zero_degree_fail() {
+    // Should panic because there are no polynomials of degree 0 to multiply.
+    Fft::new(0);
+}
+
+#[test]
+fn test_try_multiply_different_ring_degrees() {
+    let fft = Fft::new(10);
+    let poly1: Polynomial<f64> = generate_random_float_polynomial(10);
+    let poly2: Polynomial<f64> = generate_random_float_polynomial(14);
+
+    assert_eq!(fft.try_multiply(&poly1, &poly2).err(), Some(RingMathError::RingDegreeMismatch(14, 10)));
+    assert_eq!(fft.try_multiply(&poly2, &poly1).err(), Some(RingMathError::RingDegreeMismatch(14, 10)));
+}
@@ -0,0 +1,193 @@
+//! gadget module
+use crate::traits::ModularRing;
+use crate::polynomial::Polynomial;
+use crate::vector::Vector;
+use crate::quotient::Quotient;
+
+/// Structure representing the gadget vector g = (q/B, q/B^2, ..., q/B^ℓ) for a base B = 2^b
+///
+/// A coefficient x is first rounded to the nearest multiple of q/B^ℓ, then written with ℓ balanced
+/// digits d_i in [-B/2, B/2) so that x ≈ d_1 q/B + ... + d_ℓ q/B^ℓ. When q is not a power of two
+/// the gadget entries are the rounded values of q/B^i, so the recomposition is only approximate.
+///
+/// # Attributes
+/// * `base_log` - the number of bits b of the base B
+/// * `levels` - the number of digits ℓ
+pub struct Gadget {
+    pub base_log: u32,
+    pub levels: usize
+}
+
+impl Gadget {
+
+    /// Return a new gadget with base 2^b and ℓ levels.
+    ///
+    /// # Arguments
+    /// * `base_log` - Number of bits b of the base.
+    /// * `levels` - Number of digits ℓ.
+    ///
+    /// # Output
+    /// * a new instantiation of a Gadget
+    ///
+    /// # Panics if b or ℓ is zero, or if b ℓ is more than 64 bits.
+    /// ```
+    pub fn new(base_log: u32, levels: usize) -> Gadget {
+        if base_log == 0 || levels == 0 {
+            panic!("Gadget base and levels should be positive. {} and {}", base_log, levels);
+        }
+        if base_log as usize * levels > 64 {
+            panic!("Gadget precision should be at most 64 bits. {} * {} > 64", base_log, levels);
+        }
+        Gadget {base_log, levels}
+    }
+
+    /// Return the gadget vector g.
+    ///
+    /// # Output
+    /// * the ℓ entries round(q/B^i), for i from 1 to ℓ
+    /// ```
+    pub fn gadget_vector<T: ModularRing>(&self) -> Vec<T> {
+        (1..=self.levels).map(|level| {
+            let shift = self.base_log * level as u32;
+            T::from_residue((T::modulus() + (1 << (shift - 1))) >> shift)
+        }).collect()
+    }
+
+    /// Split a coefficient into ℓ balanced digits.
+    ///
+    /// # Arguments
+    /// * `coeff` - the coefficient x to decompose
+    ///
+    /// # Output
+    /// * the digits d_1, ..., d_ℓ in [-B/2, B/2), from the most significant
+    /// ```
+    pub fn decompose_coeff<T: ModularRing>(&self, coeff: T) -> Vec<i64> {
+        let precision = self.base_log * self.levels as u32;
+        // With 128 bits, B = 2^64 does not overflow, and B/2 = 2^63 is positive for B = 2^64.
+        let base = 1_i128 << self.base_log;
+
+        // Round x B^ℓ / q to an integer, with 128 bits since x < 2^64 and B^ℓ <= 2^64.
+        let modulus = T::modulus();
+        let scaled = ((coeff.residue() << precision) + modulus / 2) / modulus;
+        let mut rest = scaled;
+
+        // Extract the digits from the least significant, carrying into the next one whenever a
+        // digit is moved from [B/2, B) down to [-B/2, 0).
+        let mut digits: Vec<i64> = vec![0; self.levels];
+        for digit in digits.iter_mut().rev() {
+            let mut value = (rest & (base as u128 - 1)) as i128;
+            rest >>= self.base_log;
+            if value >= base / 2 {
+                value -= base;
+                rest += 1;
+            }
+            // A digit in [-B/2, B/2) fits an i64 even for B = 2^64.
+            *digit = value as i64;
+        }
+        digits
+    }
+
+    /// Return the coefficient mod q equal to a signed digit.
+    fn digit_to_coeff<T: ModularRing>(digit: i64) -> T {
+        let modulus = T::modulus();
+        if digit < 0 {
+            // Reduce once more, since q - 0 = q when q divides the digit.
+            T::from_residue((modulus - (digit.unsigned_abs() as u128 % modulus)) % modulus)
+        } else {
+            T::from_residue(digit as u128 % modulus)
+        }
+    }
+
+    /// Decompose a polynomial into ℓ digit polynomials.
+    ///
+    /// # Arguments
+    /// * `poly` - polynomial to decompose
+    ///
+    /// # Output
+    /// * a new instantiation of a Vector of ℓ polynomials with balanced digit coefficients, the
+    ///   most significant first
+    /// ```
+    pub fn decompose<T: ModularRing, Q: Quotient<T>>(&self, poly: &Polynomial<T, Q>) -> Vector<T, Q> {
+        poly.check_coeff_length();
+
+        let mut polys: Vec<Polynomial<T, Q>> = (0..self.levels).map(|_| Polynomial::new(poly.ring_degree)).collect();
+        for (i, coeff) in poly.coeffs.iter().enumerate() {
+            for (digit_poly, &digit) in polys.iter_mut().zip(self.decompose_coeff(coeff.clone()).iter()) {
+                digit_poly.coeffs[i] = Self::digit_to_coeff(digit);
+            }
+        }
+        Vector{ring_degree: poly.ring_degree, length: self.levels, polys}
+    }
+
+    /// Decompose each polynomial of a vector into ℓ digit polynomials.
+    ///
+    /// # Arguments
+    /// * `vector` - vector of length n to decompose
+    ///
+    /// # Output
+    /// * a new instantiation of a Vector of length n ℓ, holding the ℓ digit polynomials of each
+    ///   polynomial in turn
+    /// ```
+    pub fn decompose_vector<T: ModularRing, Q: Quotient<T>>(&self, vector: &Vector<T, Q>) -> Vector<T, Q> {
+        let mut polys: Vec<Polynomial<T, Q>> = Vec::new();
+        for poly in vector.polys.iter() {
+            polys.extend(self.decompose(poly).polys);
+        }
+        Vector{ring_degree: vector.ring_degree, length: vector.length * self.levels, polys}
+    }
+
+    /// Recompose a polynomial from its ℓ digit polynomials, as the inner product with g.
+    ///
+    /// # Arguments
+    /// * `digits` - vector of ℓ digit polynomials, the most significant first
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is d_1 q/B + ... + d_ℓ q/B^ℓ
+    ///
+    /// # Panics if the vector length is not ℓ.
+    /// ```
+    pub fn recompose<T: ModularRing, Q: Quotient<T>>(&self, digits: &Vector<T, Q>) -> Polynomial<T, Q> {
+        if digits.length != self.levels {
+            panic!("Vector length should be equal to the number of levels. {} != {}", digits.length,
+                   self.levels);
+        }
+
+        self.recompose_polys(digits.ring_degree, &digits.polys)
+    }
+
+    /// Compute the inner product of ℓ digit polynomials with g.
+    fn recompose_polys<T: ModularRing, Q: Quotient<T>>(&self, ring_degree: usize,
+                                                        digit_polys: &[Polynomial<T, Q>]) -> Polynomial<T, Q> {
+        let mut poly: Polynomial<T, Q> = Polynomial::new(ring_degree);
+        for (digit_poly, entry) in digit_polys.iter().zip(self.gadget_vector::<T>()) {
+            poly.add_assign(&digit_poly.multiply_by_scalar(entry));
+        }
+        poly
+    }
+
+    /// Recompose a vector from the ℓ digit polynomials of each of its polynomials.
+    ///
+    /// # Arguments
+    /// * `digits` - vector of length n ℓ, as returned by `decompose_vector`
+    ///
+    /// # Output
+    /// * a new instantiation of a Vector of length n
+    ///
+    /// # Panics if the vector length is not a multiple of ℓ.
+    /// ```
+    pub fn recompose_vector<T: ModularRing, Q: Quotient<T>>(&self, digits: &Vector<T, Q>) -> Vector<T, Q> {
+        if !digits.length.is_multiple_of(self.levels) {
+            panic!("Vector length should be a multiple of the number of levels. {} % {} != 0", digits.length,
+                   self.levels);
+        }
+
+        let mut polys: Vec<Polynomial<T, Q>> = Vec::new();
+        for chunk in digits.polys.chunks(self.levels) {
+            polys.push(self.recompose_polys(digits.ring_degree, chunk));
+        }
+        Vector{ring_degree: digits.ring_degree, length: digits.length / self.levels, polys}
+    }
+}
+
+#[cfg(test)]
+mod tests;
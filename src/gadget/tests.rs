@@ -0,0 +1,161 @@
+// Tests
+use super::*;
+use rand::Rng;
+use crate::ring::{ModInteger, ModInteger32, Torus32, Torus64};
+use crate::utilities::{generate_random_modint32_polynomial, generate_random_modint64_polynomial,
+                       generate_random_modint_polynomial, generate_random_torus32_polynomial,
+                       generate_random_torus64_polynomial, generate_random_torus32_polynomial_vector};
+
+const MAX_TEST_DEGREE: usize = 2048;
+const MAX_TEST_LENGTH: usize = 16;
+const FALCON_Q: u64 = 12289;
+
+#[test]
+fn test_decompose_known_answer() {
+    let gadget = Gadget::new(8, 2);
+
+    // 0x12800000 = 0x13 * 2^24 - 0x80 * 2^16
+    assert_eq!(gadget.decompose_coeff(Torus32{value: 0x1280_0000}), vec![19, -128]);
+    assert_eq!(gadget.decompose_coeff(Torus32{value: 0x1280_7fff}), vec![19, -128]);
+    assert_eq!(gadget.decompose_coeff(Torus32{value: 0x1280_8000}), vec![19, -127]);
+    assert_eq!(gadget.gadget_vector::<Torus32>(), vec![Torus32{value: 1 << 24}, Torus32{value: 1 << 16}]);
+}
+
+#[test]
+fn test_decompose_largest_base_known_answer() {
+    let gadget = Gadget::new(64, 1);
+
+    assert_eq!(gadget.decompose_coeff(Torus64{value: 5}), vec![5]);
+    assert_eq!(gadget.decompose_coeff(Torus64{value: u64::MAX}), vec![-1]);
+    assert_eq!(gadget.decompose_coeff(Torus64{value: 1 << 63}), vec![i64::MIN]);
+    assert_eq!(gadget.gadget_vector::<Torus64>(), vec![Torus64{value: 1}]);
+
+    let poly: Polynomial<Torus64> = Polynomial::from_coeffs(vec![Torus64{value: 1 << 63}, Torus64{value: 7}]);
+    assert_eq!(gadget.recompose(&gadget.decompose(&poly)).coeffs, poly.coeffs);
+}
+
+#[test]
+fn test_decompose_base_2_63_known_answer() {
+    let gadget = Gadget::new(63, 1);
+
+    // 0xc000000000000000 = -2^61 * 2 mod 2^64, and 2^64 - 1 rounds up to 2^64 = 0.
+    assert_eq!(gadget.decompose_coeff(Torus64{value: 0xc000_0000_0000_0000}), vec![-(1 << 61)]);
+    assert_eq!(gadget.decompose_coeff(Torus64{value: u64::MAX}), vec![0]);
+    assert_eq!(gadget.decompose_coeff(Torus64{value: 6}), vec![3]);
+    assert_eq!(gadget.gadget_vector::<Torus64>(), vec![Torus64{value: 2}]);
+}
+
+#[test]
+fn test_digit_to_coeff_multiple_of_modulus() {
+    // -2^32 is 0 mod 2^32, and should map to the residue 0 rather than 2^32.
+    assert_eq!(Gadget::digit_to_coeff::<ModInteger32>(-(1 << 32)), ModInteger32::from_residue(0));
+    assert_eq!(Gadget::digit_to_coeff::<ModInteger<FALCON_Q>>(-(FALCON_Q as i64)), ModInteger::new(0));
+    assert_eq!(Gadget::digit_to_coeff::<ModInteger<FALCON_Q>>(-1), ModInteger::new(FALCON_Q - 1));
+}
+
+fn test_decompose_roundtrip_exact<T>(generate_random_polynomial: fn(usize) -> Polynomial<T>, base_log: u32,
+                                     levels: usize)
+        where T: ModularRing {
+    // Generate a random polynomial.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly: Polynomial<T> = generate_random_polynomial(ring_degree);
+    let gadget = Gadget::new(base_log, levels);
+
+    // Test that the digits are balanced, and that b ℓ = log q bits recompose exactly.
+    let digits = gadget.decompose(&poly);
+    for coeff in poly.coeffs.iter() {
+        for digit in gadget.decompose_coeff(coeff.clone()) {
+            assert!(-(1 << (base_log - 1)) <= digit && digit < 1 << (base_log - 1));
+        }
+    }
+
+    assert_eq!(digits.length, levels);
+    assert_eq!(gadget.recompose(&digits).coeffs, poly.coeffs);
+}
+
+#[test]
+fn test_decompose_roundtrip_modint32() {
+    test_decompose_roundtrip_exact(generate_random_modint32_polynomial, 4, 8);
+}
+
+#[test]
+fn test_decompose_roundtrip_modint64() {
+    test_decompose_roundtrip_exact(generate_random_modint64_polynomial, 16, 4);
+}
+
+#[test]
+fn test_decompose_roundtrip_torus32() {
+    test_decompose_roundtrip_exact(generate_random_torus32_polynomial, 1, 32);
+}
+
+#[test]
+fn test_decompose_roundtrip_torus64() {
+    test_decompose_roundtrip_exact(generate_random_torus64_polynomial, 8, 8);
+}
+
+fn check_recompose_error<T>(poly: &Polynomial<T>, gadget: &Gadget, max_error: u128)
+        where T: ModularRing {
+    let recomposed = gadget.recompose(&gadget.decompose(poly));
+
+    for (coeff, approx) in poly.coeffs.iter().zip(recomposed.coeffs.iter()) {
+        let diff = (coeff.clone().residue() + T::modulus() - approx.clone().residue()) % T::modulus();
+        assert!(diff.min(T::modulus() - diff) <= max_error);
+    }
+}
+
+#[test]
+fn test_decompose_approximate_torus32() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly: Polynomial<Torus32> = generate_random_torus32_polynomial(ring_degree);
+
+    // Rounding to a multiple of 2^(32 - 18) moves a coefficient by at most 2^13.
+    check_recompose_error(&poly, &Gadget::new(6, 3), 1 << 13);
+}
+
+#[test]
+fn test_decompose_approximate_modint() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly: Polynomial<ModInteger<FALCON_Q>> = generate_random_modint_polynomial(ring_degree);
+
+    // Rounding x 2^14 / q moves x by less than 1, and each rounded entry of g adds at most |d_i| / 2 <= 1.
+    check_recompose_error(&poly, &Gadget::new(2, 7), 8);
+}
+
+#[test]
+fn test_decompose_vector_roundtrip() {
+    // Generate a random vector.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let length = rng.gen_range(1..=MAX_TEST_LENGTH);
+    let vector: Vector<Torus32> = generate_random_torus32_polynomial_vector(length, ring_degree);
+    let gadget = Gadget::new(8, 4);
+
+    let digits = gadget.decompose_vector(&vector);
+    let recomposed = gadget.recompose_vector(&digits);
+
+    assert_eq!(digits.length, 4 * length);
+    assert_eq!(digits.polys.len(), 4 * length);
+    assert_eq!(recomposed.length, length);
+    for i in 0..length {
+        assert_eq!(digits.polys[4 * i].coeffs, gadget.decompose(&vector.polys[i]).polys[0].coeffs);
+        assert_eq!(recomposed.polys[i].coeffs, vector.polys[i].coeffs);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Gadget precision should be at most 64 bits. 8 * 9 > 64")]
+fn test_gadget_too_precise() {
+    let _ = Gadget::new(8, 9);
+}
+
+#[test]
+#[should_panic(expected = "Vector length should be equal to the number of levels. 3 != 2")]
+fn test_recompose_wrong_length() {
+    let gadget = Gadget::new(8, 2);
+    let digits: Vector<Torus32> = generate_random_torus32_polynomial_vector(3, 4);
+
+    let _ = gadget.recompose(&digits);
+}
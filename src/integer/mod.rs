@@ -0,0 +1,216 @@
+//! integer module
+//!
+//! The primitive integers i8, ..., i128 and u8, ..., u128 are coefficients over Z, with the
+//! overflow policy selected by the type:
+//!
+//! * the primitive itself follows Rust's default, so overflow panics in debug builds and wraps
+//!   in release builds
+//! * `Checked<T>` panics on overflow in every build
+//! * `std::num::Wrapping<T>` wraps, so that `Wrapping<u32>` is the ring Z/2^32
+//! * `std::num::Saturating<T>` clamps to the range of T, as a scalar only. Clamping is not
+//!   associative, e.g. (100 + 100) - 100 = 27 but 100 + (100 - 100) = 100 in i8, so a polynomial
+//!   product would depend on the algorithm, such as Karatsuba versus schoolbook multiplication.
+//!   `Saturating<T>` is therefore not a `Ring`, and cannot be a coefficient.
+//!
+//! Negation is subtraction from zero, so unsigned coefficients should wrap to be negated, as in
+//! the negacyclic ring.
+use std::ops::{Add, Sub, Mul, AddAssign};
+use std::num::{Wrapping, Saturating};
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+use crate::traits::{Zero, One, Abs, Norm, Ring, CommutativeRing, ModularRing};
+
+/// Structure representing an integer whose arithmetic panics on overflow, in every build
+///
+/// # Attributes
+/// * `0` - Value of the integer
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Checked<T>(pub T);
+
+/// Implement the ring traits for a primitive integer type under each overflow policy.
+macro_rules! integer_ring {
+    ($($int:ty),*) => {$(
+        impl Zero for $int {
+            fn zero() -> $int {
+                0
+            }
+        }
+
+        impl One for $int {
+            fn one() -> $int {
+                1
+            }
+        }
+
+        impl Abs for $int {
+            fn abs(self) -> f64 {
+                (self as f64).abs()
+            }
+        }
+
+        impl Norm for $int {
+            fn norm(self) -> f64 {
+                (self as f64).abs()
+            }
+        }
+
+        impl Ring for $int {}
+
+        impl CommutativeRing for $int {}
+
+        impl Zero for Wrapping<$int> {
+            fn zero() -> Wrapping<$int> {
+                Wrapping(0)
+            }
+        }
+
+        impl One for Wrapping<$int> {
+            fn one() -> Wrapping<$int> {
+                Wrapping(1)
+            }
+        }
+
+        impl Abs for Wrapping<$int> {
+            fn abs(self) -> f64 {
+                (self.0 as f64).abs()
+            }
+        }
+
+        impl Norm for Wrapping<$int> {
+            // The centered representative in Z/2^k is min(v, 2^k - v) in magnitude, and the
+            // two's complement value is already centered for the signed types.
+            fn norm(self) -> f64 {
+                (self.0 as f64).abs().min((self.0.wrapping_neg() as f64).abs())
+            }
+        }
+
+        impl Ring for Wrapping<$int> {}
+
+        impl CommutativeRing for Wrapping<$int> {}
+
+        impl Zero for Saturating<$int> {
+            fn zero() -> Saturating<$int> {
+                Saturating(0)
+            }
+        }
+
+        impl One for Saturating<$int> {
+            fn one() -> Saturating<$int> {
+                Saturating(1)
+            }
+        }
+
+        impl Abs for Saturating<$int> {
+            fn abs(self) -> f64 {
+                (self.0 as f64).abs()
+            }
+        }
+
+        impl Norm for Saturating<$int> {
+            fn norm(self) -> f64 {
+                (self.0 as f64).abs()
+            }
+        }
+
+        impl Add for Checked<$int> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self::Output {
+                match self.0.checked_add(other.0) {
+                    Some(value) => Checked(value),
+                    None => panic!("Integer arithmetic should not overflow. {} + {}", self.0, other.0)
+                }
+            }
+        }
+
+        impl AddAssign for Checked<$int> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl Sub for Checked<$int> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self::Output {
+                match self.0.checked_sub(other.0) {
+                    Some(value) => Checked(value),
+                    None => panic!("Integer arithmetic should not overflow. {} - {}", self.0, other.0)
+                }
+            }
+        }
+
+        impl Mul for Checked<$int> {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self::Output {
+                match self.0.checked_mul(other.0) {
+                    Some(value) => Checked(value),
+                    None => panic!("Integer arithmetic should not overflow. {} * {}", self.0, other.0)
+                }
+            }
+        }
+
+        ref_mul_copy!(impl[] for Checked<$int>);
+
+        impl Zero for Checked<$int> {
+            fn zero() -> Checked<$int> {
+                Checked(0)
+            }
+        }
+
+        impl One for Checked<$int> {
+            fn one() -> Checked<$int> {
+                Checked(1)
+            }
+        }
+
+        impl Abs for Checked<$int> {
+            fn abs(self) -> f64 {
+                (self.0 as f64).abs()
+            }
+        }
+
+        impl Norm for Checked<$int> {
+            fn norm(self) -> f64 {
+                (self.0 as f64).abs()
+            }
+        }
+
+        impl Ring for Checked<$int> {}
+
+        impl CommutativeRing for Checked<$int> {}
+
+        impl Distribution<Checked<$int>> for Standard {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Checked<$int> {
+                Checked(rng.gen())
+            }
+        }
+    )*};
+}
+
+integer_ring!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+/// Implement `ModularRing` for wrapping unsigned integers, which are the rings Z/2^k. There is no
+/// implementation for u128, since the modulus 2^128 does not fit in a u128.
+macro_rules! wrapping_modular_ring {
+    ($($uint:ty),*) => {$(
+        impl ModularRing for Wrapping<$uint> {
+            fn modulus() -> u128 {
+                1 << <$uint>::BITS
+            }
+
+            fn residue(self) -> u128 {
+                self.0 as u128
+            }
+
+            fn from_residue(residue: u128) -> Wrapping<$uint> {
+                Wrapping(residue as $uint)
+            }
+        }
+    )*};
+}
+
+wrapping_modular_ring!(u8, u16, u32, u64);
+
+#[cfg(test)]
+mod tests;
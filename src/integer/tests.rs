@@ -0,0 +1,169 @@
+// Tests
+use super::*;
+use rand::{distributions::uniform::SampleUniform, Rng};
+use crate::polynomial::{Polynomial, Rounding};
+use crate::vector::Vector;
+use crate::quotient::{Quotient, Cyclic, Negacyclic};
+use crate::ring::ModInteger32;
+use crate::utilities::{generate_random_integer_polynomial, generate_random_integer_polynomial_vector,
+                       generate_random_integer_polynomial_matrix, generate_random_modint32_polynomial};
+
+const MAX_TEST_DEGREE: usize = 256;
+const MAX_TEST_LENGTH: usize = 8;
+
+#[test]
+fn test_integer_multiply_known_answer() {
+    let poly1: Polynomial<i64, Negacyclic> = Polynomial::from_coeffs(vec![1, -2, 3]);
+    let poly2: Polynomial<i64, Negacyclic> = Polynomial::from_coeffs(vec![0, 1, 0]);
+
+    // (1 - 2x + 3x^2) x = -3 + x - 2x^2 when x^3 = -1
+    assert_eq!((&poly1 * &poly2).coeffs, vec![-3, 1, -2]);
+    assert_eq!((&poly1 - &poly2).coeffs, vec![1, -3, 3]);
+    assert_eq!((3 * &poly1).coeffs, vec![3, -6, 9]);
+    assert_eq!(poly1.norm_l1(), 6.0);
+    assert_eq!(poly1.norm_infinity(), 3.0);
+}
+
+fn test_integer_multiply_matches_float<T, Q>(low: T, high: T, to_real: fn(T) -> f64)
+        where T: Ring + SampleUniform + PartialOrd,
+              Q: Quotient<T> + Quotient<f64> {
+    // Generate random small polynomials, whose products are exact in an f64.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_integer_polynomial(ring_degree, low.clone(), high.clone()).coeffs);
+    let poly2: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_integer_polynomial(ring_degree, low.clone(), high.clone()).coeffs);
+    let real1: Polynomial<f64, Q> = Polynomial::from_coeffs(poly1.coeffs.iter().map(|coeff| to_real(coeff.clone())).collect());
+    let real2: Polynomial<f64, Q> = Polynomial::from_coeffs(poly2.coeffs.iter().map(|coeff| to_real(coeff.clone())).collect());
+
+    let prod: Vec<f64> = (&poly1 * &poly2).coeffs.into_iter().map(to_real).collect();
+    let sum: Vec<f64> = (&poly1 + &poly2).coeffs.into_iter().map(to_real).collect();
+
+    assert_eq!(prod, (&real1 * &real2).coeffs);
+    assert_eq!(sum, (&real1 + &real2).coeffs);
+}
+
+#[test]
+fn test_integer_multiply_matches_float_i32() {
+    test_integer_multiply_matches_float::<i32, Negacyclic>(-2, 2, |coeff| coeff as f64);
+}
+
+#[test]
+fn test_integer_multiply_matches_float_i64() {
+    test_integer_multiply_matches_float::<i64, Cyclic>(-1000, 1000, |coeff| coeff as f64);
+}
+
+#[test]
+fn test_integer_multiply_matches_float_u32() {
+    test_integer_multiply_matches_float::<u32, Cyclic>(0, 100, |coeff| coeff as f64);
+}
+
+#[test]
+fn test_integer_multiply_known_answer_i8() {
+    // (2 - 2x)(-2 - 2x) = -4 + 4x^2 = -8 when x^2 = -1
+    let poly1: Polynomial<i8, Negacyclic> = Polynomial::from_coeffs(vec![2, -2]);
+    let poly2: Polynomial<i8, Negacyclic> = Polynomial::from_coeffs(vec![-2, -2]);
+
+    assert_eq!((&poly1 * &poly2).coeffs, vec![-8, 0]);
+}
+
+#[test]
+fn test_wrapping_matches_modint32() {
+    // Generate a random polynomial mod 2^32.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly: Polynomial<ModInteger32, Negacyclic> =
+        Polynomial::from_coeffs(generate_random_modint32_polynomial(ring_degree).coeffs);
+    let wrapping: Polynomial<Wrapping<u32>, Negacyclic> =
+        Polynomial::from_coeffs(poly.coeffs.iter().map(|coeff| Wrapping(coeff.value as u32)).collect());
+
+    // Test that Wrapping<u32> is the ring Z/2^32.
+    let square: Vec<u64> = (&wrapping * &wrapping).coeffs.iter().map(|coeff| coeff.0 as u64).collect();
+    let switched: Polynomial<ModInteger32, Negacyclic> = wrapping.switch_modulus(Rounding::Nearest);
+
+    assert_eq!(square, (&poly * &poly).coeffs.iter().map(|coeff| coeff.value).collect::<Vec<u64>>());
+    assert_eq!(switched.coeffs, poly.coeffs);
+    assert_eq!(Wrapping::<u8>::modulus(), 256);
+}
+
+#[test]
+fn test_saturating_clamps() {
+    let value: Saturating<i8> = Saturating(100);
+
+    assert_eq!(value + value, Saturating(127));
+    assert_eq!(Saturating::<i8>::zero() - value * Saturating(2), Saturating(-127));
+    assert_eq!(Saturating(-100) - value, Saturating(-128));
+    assert_eq!((Saturating::<i8>::one() - Saturating(i8::MAX)).norm(), 126.0);
+}
+
+#[test]
+fn test_saturating_not_associative() {
+    let value: Saturating<i8> = Saturating(100);
+
+    assert_eq!((value + value) - value, Saturating(27));
+    assert_eq!(value + (value - value), Saturating(100));
+}
+
+#[test]
+fn test_wrapping_norm_centered() {
+    let poly: Polynomial<Wrapping<u8>, Negacyclic> =
+        Polynomial::from_coeffs(vec![Wrapping(1), Wrapping(255), Wrapping(128), Wrapping(130)]);
+    let signed: Polynomial<Wrapping<i8>> = Polynomial::from_coeffs(vec![Wrapping(-128), Wrapping(-3), Wrapping(5)]);
+
+    assert_eq!(Wrapping(255_u8).norm(), 1.0);
+    assert_eq!(Wrapping(0_u32).norm(), 0.0);
+    assert_eq!(poly.norm_infinity(), 128.0);
+    assert_eq!(poly.norm_l1(), 1.0 + 1.0 + 128.0 + 126.0);
+    assert_eq!(signed.norm_infinity(), 128.0);
+    assert_eq!(signed.norm_l1(), 136.0);
+}
+
+#[test]
+fn test_checked_known_answer() {
+    let poly: Polynomial<Checked<u16>> = Polynomial::from_coeffs(vec![Checked(300), Checked(2)]);
+
+    assert_eq!((&poly + &poly).coeffs, vec![Checked(600), Checked(4)]);
+    assert_eq!(Checked(7_i64) - Checked(9), Checked(-2));
+}
+
+#[test]
+#[should_panic(expected = "Integer arithmetic should not overflow. 127 + 1")]
+fn test_checked_overflow_fail() {
+    let _ = Checked(127_i8) + Checked(1);
+}
+
+#[test]
+#[should_panic(expected = "Integer arithmetic should not overflow. 0 - 5")]
+fn test_checked_unsigned_negacyclic_fail() {
+    // x^N = -1 needs a negative coefficient, which a checked unsigned integer cannot hold.
+    let poly: Polynomial<Checked<u32>, Negacyclic> = Polynomial::from_coeffs(vec![Checked(0), Checked(5)]);
+
+    let _ = poly.multiply_by_x();
+}
+
+fn check_integer_vector<T: Ring + SampleUniform + PartialOrd>(low: T, high: T) {
+    // Generate a random vector and matrix.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_rows = rng.gen_range(1..=MAX_TEST_LENGTH);
+    let num_cols = rng.gen_range(1..=MAX_TEST_LENGTH);
+    let vector: Vector<T> = generate_random_integer_polynomial_vector(num_rows, ring_degree, low.clone(), high.clone());
+    let matrix = generate_random_integer_polynomial_matrix(num_rows, num_cols, ring_degree, low, high);
+
+    // Test that the product is the dot product of the vector with each column.
+    let prod = &vector * &matrix;
+
+    assert_eq!(prod.length, num_cols);
+    for (poly, col) in prod.polys.iter().zip(matrix.cols.iter()) {
+        assert_eq!(poly.coeffs, vector.dot_product(col).coeffs);
+    }
+}
+
+#[test]
+fn test_integer_vector_matrix_i16() {
+    check_integer_vector::<i16>(-1, 1);
+}
+
+#[test]
+fn test_integer_vector_matrix_u128() {
+    check_integer_vector::<u128>(0, 1 << 40);
+}
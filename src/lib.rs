@@ -1,53 +1,102 @@
 //! Math module
+#[macro_use]
+mod macros;
+
 pub mod polynomial;
+pub mod quotient;
+pub mod ntt;
+pub mod fft;
+pub mod automorphism;
+pub mod gadget;
 pub mod vector;
 pub mod ring;
+pub mod integer;
+pub mod bigint;
+pub mod rns;
 pub mod utilities;
+pub mod error;
 
-pub use crate::traits::{Zero, One, Abs};
+pub use crate::traits::{Zero, One, Abs, Inverse, Norm, MulRef, Ring, CommutativeRing, Field, ModularRing};
+pub use crate::error::RingMathError;
 
 pub mod traits;
 
-impl Zero<f32> for f32 {
+impl Zero for f32 {
     fn zero() -> f32 {
-        return 0.0;
+        0.0
     }
 }
 
-impl Zero<f64> for f64 {
+impl Zero for f64 {
     fn zero() -> f64 {
-        return 0.0;
+        0.0
     }
 }
 
-impl One<f32> for f32 {
+impl One for f32 {
     fn one() -> f32 {
-        return 1.0;
+        1.0
     }
 }
 
-impl One<f64> for f64 {
+impl One for f64 {
     fn one() -> f64 {
-        return 1.0;
+        1.0
     }
 }
 
-impl Abs<f32> for f32 {
+impl Abs for f32 {
     fn abs(self) -> f64 {
         if self > 0.0 {
-            return self as f64;
+            self as f64
         } else {
-            return -self as f64;
+            -self as f64
         }
     }
 }
 
-impl Abs<f64> for f64 {
+impl Abs for f64 {
     fn abs(self) -> f64 {
         if self > 0.0 {
-            return self;
+            self
         } else {
-            return -self;
+            -self
         }
     }
-}
\ No newline at end of file
+}
+
+impl Inverse for f32 {
+    fn inverse(self) -> f32 {
+        1.0 / self
+    }
+}
+
+impl Inverse for f64 {
+    fn inverse(self) -> f64 {
+        1.0 / self
+    }
+}
+
+impl Norm for f32 {
+    fn norm(self) -> f64 {
+        Abs::abs(self)
+    }
+}
+
+impl Norm for f64 {
+    fn norm(self) -> f64 {
+        Abs::abs(self)
+    }
+}
+
+impl Ring for f32 {}
+
+impl Ring for f64 {}
+
+impl CommutativeRing for f32 {}
+
+impl CommutativeRing for f64 {}
+
+impl Field for f32 {}
+
+impl Field for f64 {}
@@ -0,0 +1,141 @@
+//! macros module
+
+/// Implement a binary operator for owned operands and mixed owned/borrowed operands by
+/// forwarding to the implementation for two references.
+macro_rules! forward_binop {
+    (impl[$($g:tt)*] $imp:ident, $method:ident for $lhs:ty, $rhs:ty => $out:ty
+     where $($bounds:tt)*) => {
+        impl<$($g)*> std::ops::$imp<$rhs> for $lhs where $($bounds)* {
+            type Output = $out;
+
+            fn $method(self, other: $rhs) -> $out {
+                std::ops::$imp::$method(&self, &other)
+            }
+        }
+
+        impl<$($g)*> std::ops::$imp<&$rhs> for $lhs where $($bounds)* {
+            type Output = $out;
+
+            fn $method(self, other: &$rhs) -> $out {
+                std::ops::$imp::$method(&self, other)
+            }
+        }
+
+        impl<$($g)*> std::ops::$imp<$rhs> for &$lhs where $($bounds)* {
+            type Output = $out;
+
+            fn $method(self, other: $rhs) -> $out {
+                std::ops::$imp::$method(self, &other)
+            }
+        }
+    };
+}
+
+/// Implement negation of an owned operand by forwarding to the implementation for a reference.
+macro_rules! forward_neg {
+    (impl[$($g:tt)*] for $ty:ty where $($bounds:tt)*) => {
+        impl<$($g)*> std::ops::Neg for $ty where $($bounds)* {
+            type Output = $ty;
+
+            fn neg(self) -> $ty {
+                std::ops::Neg::neg(&self)
+            }
+        }
+    };
+}
+
+/// Implement multiplication of two references to a `Copy` scalar, by forwarding to the
+/// implementation for owned operands.
+macro_rules! ref_mul_copy {
+    (impl[$($g:tt)*] for $ty:ty) => {
+        impl<$($g)*> std::ops::Mul<&$ty> for &$ty {
+            type Output = $ty;
+
+            fn mul(self, other: &$ty) -> $ty {
+                *self * *other
+            }
+        }
+    };
+}
+
+/// Implement multiplication of a polynomial by a scalar on the left, for a concrete scalar type.
+macro_rules! scalar_mul_left {
+    (impl[$($g:tt)*] $scalar:ty) => {
+        impl<$($g)* Q: Quotient<$scalar>> std::ops::Mul<&Polynomial<$scalar, Q>> for $scalar {
+            type Output = Polynomial<$scalar, Q>;
+
+            fn mul(self, poly: &Polynomial<$scalar, Q>) -> Polynomial<$scalar, Q> {
+                poly.multiply_by_scalar(self)
+            }
+        }
+
+        impl<$($g)* Q: Quotient<$scalar>> std::ops::Mul<Polynomial<$scalar, Q>> for $scalar {
+            type Output = Polynomial<$scalar, Q>;
+
+            fn mul(self, poly: Polynomial<$scalar, Q>) -> Polynomial<$scalar, Q> {
+                poly.multiply_by_scalar(self)
+            }
+        }
+    };
+}
+
+/// Implement a coefficient-wise conversion of polynomials, from the conversion of their coefficients.
+macro_rules! convert_polynomial {
+    (From<$from:ty> for $to:ty) => {
+        impl<Q> From<Polynomial<$from, Q>> for Polynomial<$to, Q> {
+            fn from(poly: Polynomial<$from, Q>) -> Polynomial<$to, Q> {
+                let coeffs: Vec<$to> = poly.coeffs.into_iter().map(<$to>::from).collect();
+                Polynomial{ring_degree: poly.ring_degree, coeffs, quotient: PhantomData}
+            }
+        }
+    };
+    (TryFrom<$from:ty> for $to:ty) => {
+        impl<Q> TryFrom<Polynomial<$from, Q>> for Polynomial<$to, Q> {
+            type Error = RingMathError;
+
+            fn try_from(poly: Polynomial<$from, Q>) -> Result<Polynomial<$to, Q>, RingMathError> {
+                let coeffs = poly.coeffs.into_iter().map(<$to>::try_from).collect::<Result<Vec<$to>, _>>()?;
+                Ok(Polynomial{ring_degree: poly.ring_degree, coeffs, quotient: PhantomData})
+            }
+        }
+    };
+}
+
+/// Implement a polynomial-wise conversion of vectors and matrices, from the conversion of their polynomials.
+macro_rules! convert_vector {
+    (From<$from:ty> for $to:ty) => {
+        impl<Q> From<Vector<$from, Q>> for Vector<$to, Q> {
+            fn from(vector: Vector<$from, Q>) -> Vector<$to, Q> {
+                let polys: Vec<Polynomial<$to, Q>> = vector.polys.into_iter().map(Polynomial::from).collect();
+                Vector{ring_degree: vector.ring_degree, length: vector.length, polys}
+            }
+        }
+
+        impl<Q> From<Matrix<$from, Q>> for Matrix<$to, Q> {
+            fn from(matrix: Matrix<$from, Q>) -> Matrix<$to, Q> {
+                let cols: Vec<Vector<$to, Q>> = matrix.cols.into_iter().map(Vector::from).collect();
+                Matrix{ring_degree: matrix.ring_degree, num_rows: matrix.num_rows, num_cols: matrix.num_cols, cols}
+            }
+        }
+    };
+    (TryFrom<$from:ty> for $to:ty) => {
+        impl<Q> TryFrom<Vector<$from, Q>> for Vector<$to, Q> {
+            type Error = RingMathError;
+
+            fn try_from(vector: Vector<$from, Q>) -> Result<Vector<$to, Q>, RingMathError> {
+                let polys = vector.polys.into_iter().map(Polynomial::try_from)
+                    .collect::<Result<Vec<Polynomial<$to, Q>>, _>>()?;
+                Ok(Vector{ring_degree: vector.ring_degree, length: vector.length, polys})
+            }
+        }
+
+        impl<Q> TryFrom<Matrix<$from, Q>> for Matrix<$to, Q> {
+            type Error = RingMathError;
+
+            fn try_from(matrix: Matrix<$from, Q>) -> Result<Matrix<$to, Q>, RingMathError> {
+                let cols = matrix.cols.into_iter().map(Vector::try_from).collect::<Result<Vec<Vector<$to, Q>>, _>>()?;
+                Ok(Matrix{ring_degree: matrix.ring_degree, num_rows: matrix.num_rows, num_cols: matrix.num_cols, cols})
+            }
+        }
+    };
+}
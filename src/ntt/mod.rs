@@ -0,0 +1,333 @@
+//! ntt module
+use std::marker::PhantomData;
+use crate::traits::One;
+use crate::ring::ModInteger;
+use crate::polynomial::Polynomial;
+use crate::quotient::{Quotient, Cyclic};
+use crate::error::RingMathError;
+use crate::automorphism::{Automorphism, gcd};
+
+/// Structure representing a number-theoretic transform of size N over the integers mod a prime M
+///
+/// The twiddle factors are computed once by `new`, so a single instantiation can be reused to
+/// multiply any number of polynomials of degree N. For the cyclic ring the transform evaluates a
+/// polynomial at the powers of a primitive N-th root of unity ω. For the negacyclic ring the
+/// coefficients are first twisted by the powers of a primitive 2N-th root of unity ψ with ψ^2 = ω,
+/// which turns the negacyclic product into a cyclic one.
+///
+/// # Attributes
+/// * `ring_degree` - the degree N of the polynomial ring, a power of two
+/// * `twiddles` - the powers ω^k for k < N/2
+/// * `inv_twiddles` - the powers ω^-k for k < N/2
+/// * `twist` - the powers ψ^i applied before the forward transform, all 1 in the cyclic ring
+/// * `inv_twist` - the powers N^-1 ψ^-i applied after the inverse transform
+/// * `quotient` - marker for the quotient of the ring, `Cyclic` (X^N - 1) or `Negacyclic` (X^N + 1)
+pub struct Ntt<const M: u64, Q = Cyclic> {
+    pub ring_degree: usize,
+    twiddles: Vec<ModInteger<M>>,
+    inv_twiddles: Vec<ModInteger<M>>,
+    twist: Vec<ModInteger<M>>,
+    inv_twist: Vec<ModInteger<M>>,
+    quotient: PhantomData<Q>
+}
+
+impl<const M: u64, Q: Quotient<ModInteger<M>>> Ntt<M, Q> {
+
+    /// Return a new transform of size N, with precomputed twiddle factors.
+    ///
+    /// # Arguments
+    /// * `ring_degree` - Degree N of ring, a power of two.
+    ///
+    /// # Output
+    /// * a new instantiation of an Ntt
+    ///
+    /// # Panics if N is not a power of two, if M is not prime, or if M - 1 is not divisible by N in
+    /// the cyclic ring or 2N in the negacyclic ring.
+    /// ```
+    pub fn new(ring_degree: usize) -> Ntt<M, Q> {
+        if !ring_degree.is_power_of_two() {
+            panic!("Ring degree should be a power of two. {}", ring_degree);
+        }
+
+        if !Self::is_prime() {
+            panic!("Modulus should be prime. {}", M);
+        }
+
+        // The quotient tells us whether x^N = 1 or x^N = -1.
+        let one: ModInteger<M> = ModInteger::one();
+        let negacyclic = Q::NEGACYCLIC;
+        let order = if negacyclic { 2 * ring_degree as u64 } else { ring_degree as u64 };
+        if !(M - 1).is_multiple_of(order) {
+            panic!("Modulus minus one should be divisible by {}. {} - 1 is not", order, M);
+        }
+
+        let root = Self::primitive_root(order);
+        let omega = if negacyclic { root * root } else { root };
+        let omega_inv = omega.inverse();
+        let psi = if negacyclic { root } else { one };
+        let psi_inv = psi.inverse();
+        let degree_inv = ModInteger::<M>::new(ring_degree as u64).inverse();
+
+        let mut twiddles: Vec<ModInteger<M>> = Vec::new();
+        let mut inv_twiddles: Vec<ModInteger<M>> = Vec::new();
+        let mut power = one;
+        let mut inv_power = one;
+        for _ in 0..ring_degree / 2 {
+            twiddles.push(power);
+            inv_twiddles.push(inv_power);
+            power *= omega;
+            inv_power *= omega_inv;
+        }
+
+        let mut twist: Vec<ModInteger<M>> = Vec::new();
+        let mut inv_twist: Vec<ModInteger<M>> = Vec::new();
+        let mut power = one;
+        let mut inv_power = degree_inv;
+        for _ in 0..ring_degree {
+            twist.push(power);
+            inv_twist.push(inv_power);
+            power *= psi;
+            inv_power *= psi_inv;
+        }
+
+        Ntt {ring_degree, twiddles, inv_twiddles, twist, inv_twist, quotient: PhantomData}
+    }
+
+    /// Check whether M is prime, with the Miller-Rabin test.
+    ///
+    /// The first twelve primes as witnesses make the test deterministic for every 64-bit M.
+    fn is_prime() -> bool {
+        const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+        for p in WITNESSES {
+            if M.is_multiple_of(p) {
+                return M == p;
+            }
+        }
+
+        let one: ModInteger<M> = ModInteger::one();
+        let shift = (M - 1).trailing_zeros();
+        let odd = (M - 1) >> shift;
+        'witness: for a in WITNESSES {
+            let mut x = ModInteger::<M>::new(a).pow(odd);
+            if x == one || x == -one {
+                continue;
+            }
+            for _ in 1..shift {
+                x *= x;
+                if x == -one {
+                    continue 'witness;
+                }
+            }
+            return false;
+        }
+        true
+    }
+
+    /// Find a primitive root of unity of a given power of two order.
+    ///
+    /// A quadratic non-residue g has g^((M - 1)/2) = -1, so g^((M - 1)/order) has order exactly
+    /// `order`. Half of all residues are non-residues, so the search ends quickly for a prime M.
+    fn primitive_root(order: u64) -> ModInteger<M> {
+        let one: ModInteger<M> = ModInteger::one();
+        if order == 1 {
+            return one;
+        }
+        for g in 2..M {
+            let root = ModInteger::<M>::new(g).pow((M - 1) / order);
+            if root.pow(order / 2) == -one {
+                return root;
+            }
+        }
+        panic!("Modulus should be prime. {} has no primitive root of order {}", M, order);
+    }
+
+    /// Check that a polynomial or list of evaluations has the size of the transform.
+    ///
+    /// # Output
+    /// * `Err(RingMathError::RingDegreeMismatch)` if the size does not match N
+    /// ```
+    fn try_check_ring_degree(&self, ring_degree: usize) -> Result<(), RingMathError> {
+        if ring_degree != self.ring_degree {
+            return Err(RingMathError::RingDegreeMismatch(ring_degree, self.ring_degree));
+        }
+        Ok(())
+    }
+
+    /// Compute the cyclic transform in place, with the Cooley-Tukey butterfly.
+    ///
+    /// # Arguments
+    /// * `values` - the N values to transform, replaced by the transform in natural order
+    /// * `twiddles` - the powers of the root of unity to evaluate at
+    /// ```
+    fn transform(&self, values: &mut [ModInteger<M>], twiddles: &[ModInteger<M>]) {
+        let n = self.ring_degree;
+
+        // Permute the values into bit-reversed order.
+        let mut j = 0;
+        for i in 1..n {
+            let mut bit = n >> 1;
+            while j & bit != 0 {
+                j ^= bit;
+                bit >>= 1;
+            }
+            j |= bit;
+            if i < j {
+                values.swap(i, j);
+            }
+        }
+
+        // Combine transforms of size len / 2 into transforms of size len.
+        let mut len = 2;
+        while len <= n {
+            let step = n / len;
+            for start in (0..n).step_by(len) {
+                for k in 0..len / 2 {
+                    let u = values[start + k];
+                    let v = values[start + k + len / 2] * twiddles[k * step];
+                    values[start + k] = u + v;
+                    values[start + k + len / 2] = u - v;
+                }
+            }
+            len <<= 1;
+        }
+    }
+
+    /// Compute the forward transform of a polynomial.
+    ///
+    /// # Arguments
+    /// * `poly` - polynomial to transform
+    ///
+    /// # Output
+    /// * the N evaluations of the polynomial, or the `RingMathError` describing why the polynomial
+    ///   cannot be transformed
+    /// ```
+    pub fn try_forward(&self, poly: &Polynomial<ModInteger<M>, Q>) -> Result<Vec<ModInteger<M>>, RingMathError> {
+        poly.try_check_coeff_length()?;
+        self.try_check_ring_degree(poly.ring_degree)?;
+
+        let mut values: Vec<ModInteger<M>> = Vec::new();
+        for i in 0..self.ring_degree {
+            values.push(poly.coeffs[i] * self.twist[i]);
+        }
+        self.transform(&mut values, &self.twiddles);
+        Ok(values)
+    }
+
+    /// Compute the forward transform of a polynomial.
+    ///
+    /// # Arguments
+    /// * `poly` - polynomial to transform
+    ///
+    /// # Output
+    /// * the N evaluations of the polynomial
+    /// ```
+    pub fn forward(&self, poly: &Polynomial<ModInteger<M>, Q>) -> Vec<ModInteger<M>> {
+        self.try_forward(poly).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Compute the inverse transform of a list of evaluations.
+    ///
+    /// # Arguments
+    /// * `values` - N evaluations, as returned by `forward`
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial with the given evaluations, or the `RingMathError`
+    ///   describing why the evaluations cannot be transformed
+    /// ```
+    pub fn try_inverse(&self, values: &[ModInteger<M>]) -> Result<Polynomial<ModInteger<M>, Q>, RingMathError> {
+        self.try_check_ring_degree(values.len())?;
+
+        let mut coeffs: Vec<ModInteger<M>> = values.to_vec();
+        self.transform(&mut coeffs, &self.inv_twiddles);
+        for (coeff, inv_twist) in coeffs.iter_mut().zip(self.inv_twist.iter()) {
+            *coeff *= *inv_twist;
+        }
+        Ok(Polynomial::from_coeffs(coeffs))
+    }
+
+    /// Compute the inverse transform of a list of evaluations.
+    ///
+    /// # Arguments
+    /// * `values` - N evaluations, as returned by `forward`
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial with the given evaluations
+    /// ```
+    pub fn inverse(&self, values: &[ModInteger<M>]) -> Polynomial<ModInteger<M>, Q> {
+        self.try_inverse(values).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Multiply two polynomials in the ring, by pointwise multiplication of their transforms.
+    ///
+    /// # Arguments
+    /// * `poly1` - first polynomial to multiply
+    /// * `poly2` - second polynomial to multiply
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the product of the two polynomials, or the
+    ///   `RingMathError` describing why the polynomials cannot be multiplied
+    /// ```
+    pub fn try_multiply(&self, poly1: &Polynomial<ModInteger<M>, Q>, poly2: &Polynomial<ModInteger<M>, Q>)
+            -> Result<Polynomial<ModInteger<M>, Q>, RingMathError> {
+        let mut values = self.try_forward(poly1)?;
+        let values2 = self.try_forward(poly2)?;
+        for i in 0..self.ring_degree {
+            values[i] *= values2[i];
+        }
+        self.try_inverse(&values)
+    }
+
+    /// Multiply two polynomials in the ring, by pointwise multiplication of their transforms.
+    ///
+    /// # Arguments
+    /// * `poly1` - first polynomial to multiply
+    /// * `poly2` - second polynomial to multiply
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the product of the two polynomials
+    /// ```
+    pub fn multiply(&self, poly1: &Polynomial<ModInteger<M>, Q>, poly2: &Polynomial<ModInteger<M>, Q>)
+            -> Polynomial<ModInteger<M>, Q> {
+        self.try_multiply(poly1, poly2).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Apply the Galois automorphism X -> X^k to a polynomial in NTT form, which permutes its
+    /// evaluations.
+    ///
+    /// # Arguments
+    /// * `values` - N evaluations of a(X), as returned by `forward`
+    /// * `index` - the index k, coprime to 2N
+    ///
+    /// # Output
+    /// * the N evaluations of a(X^k), or the `RingMathError` describing why the evaluations cannot
+    ///   be permuted
+    /// ```
+    pub fn try_automorphism(&self, values: &[ModInteger<M>], index: usize) -> Result<Vec<ModInteger<M>>, RingMathError> {
+        self.try_check_ring_degree(values.len())?;
+        let order = 2 * self.ring_degree;
+        if gcd(index, order) != 1 {
+            return Err(RingMathError::IndexNotCoprime(index, order));
+        }
+
+        Ok(Automorphism::cached(self.ring_degree, index).apply_evaluations(values, Q::NEGACYCLIC))
+    }
+
+    /// Apply the Galois automorphism X -> X^k to a polynomial in NTT form, which permutes its
+    /// evaluations.
+    ///
+    /// # Arguments
+    /// * `values` - N evaluations of a(X), as returned by `forward`
+    /// * `index` - the index k, coprime to 2N
+    ///
+    /// # Output
+    /// * the N evaluations of a(X^k)
+    ///
+    /// # Panics if k is not coprime to 2N.
+    /// ```
+    pub fn automorphism(&self, values: &[ModInteger<M>], index: usize) -> Vec<ModInteger<M>> {
+        self.try_automorphism(values, index).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+#[cfg(test)]
+mod tests;
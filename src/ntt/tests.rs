@@ -0,0 +1,152 @@
+// Tests
+use super::*;
+use rand::Rng;
+use crate::quotient::Negacyclic;
+use crate::utilities::generate_random_modint_polynomial;
+
+const MAX_TEST_LOG_DEGREE: u32 = 10;
+const KYBER_Q: u64 = 3329;
+const FALCON_Q: u64 = 12289;
+const GOLDILOCKS_Q: u64 = 0xffff_ffff_0000_0001;
+
+fn modint_coeffs<const M: u64>(values: &[u64]) -> Vec<ModInteger<M>> {
+    values.iter().map(|&value| ModInteger::new(value)).collect()
+}
+
+#[test]
+fn test_multiply_known_answer() {
+    let cyclic: Ntt<FALCON_Q> = Ntt::new(4);
+    let negacyclic: Ntt<FALCON_Q, Negacyclic> = Ntt::new(4);
+    let poly1: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(modint_coeffs(&[1, 2, 3, 4]));
+    let poly2: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(modint_coeffs(&[5, 6, 7, 8]));
+    let poly3: Polynomial<ModInteger<FALCON_Q>, Negacyclic> = Polynomial::from_coeffs(poly1.coeffs.clone());
+    let poly4: Polynomial<ModInteger<FALCON_Q>, Negacyclic> = Polynomial::from_coeffs(poly2.coeffs.clone());
+
+    // (1 + 2x + 3x^2 + 4x^3)(5 + 6x + 7x^2 + 8x^3) = 5 + 16x + 34x^2 + 60x^3 + 61x^4 + 52x^5 + 32x^6
+    assert_eq!(cyclic.multiply(&poly1, &poly2).coeffs, modint_coeffs(&[66, 68, 66, 60]));
+    assert_eq!(negacyclic.multiply(&poly3, &poly4).coeffs,
+               modint_coeffs(&[FALCON_Q - 56, FALCON_Q - 36, 2, 60]));
+}
+
+#[test]
+fn test_forward_evaluates_at_roots_of_unity() {
+    let ntt: Ntt<KYBER_Q> = Ntt::new(4);
+    let poly: Polynomial<ModInteger<KYBER_Q>> = Polynomial::from_coeffs(modint_coeffs(&[1, 1, 1, 1]));
+
+    // 1 + x + x^2 + x^3 vanishes at every 4th root of unity except 1.
+    assert_eq!(ntt.forward(&poly), modint_coeffs(&[4, 0, 0, 0]));
+}
+
+fn test_inverse_roundtrip<const M: u64, Q: Quotient<ModInteger<M>>>() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = 1 << rng.gen_range(0..=MAX_TEST_LOG_DEGREE);
+    let ntt: Ntt<M, Q> = Ntt::new(ring_degree);
+    let poly: Polynomial<ModInteger<M>, Q> =
+        Polynomial::from_coeffs(generate_random_modint_polynomial::<M>(ring_degree).coeffs);
+
+    let roundtrip = ntt.inverse(&ntt.forward(&poly));
+
+    assert_eq!(roundtrip.ring_degree, ring_degree);
+    assert_eq!(roundtrip.coeffs, poly.coeffs);
+}
+
+#[test]
+fn test_inverse_roundtrip_falcon() {
+    test_inverse_roundtrip::<FALCON_Q, Cyclic>();
+    test_inverse_roundtrip::<FALCON_Q, Negacyclic>();
+}
+
+#[test]
+fn test_inverse_roundtrip_goldilocks() {
+    test_inverse_roundtrip::<GOLDILOCKS_Q, Cyclic>();
+    test_inverse_roundtrip::<GOLDILOCKS_Q, Negacyclic>();
+}
+
+fn test_multiply_matches_schoolbook<const M: u64, Q: Quotient<ModInteger<M>>>(max_log_degree: u32) {
+    let mut rng = rand::thread_rng();
+    let ring_degree = 1 << rng.gen_range(0..=max_log_degree);
+    let ntt: Ntt<M, Q> = Ntt::new(ring_degree);
+
+    // Reuse the same twiddle factors for several products.
+    for _ in 0..4 {
+        let poly1: Polynomial<ModInteger<M>, Q> =
+            Polynomial::from_coeffs(generate_random_modint_polynomial::<M>(ring_degree).coeffs);
+        let poly2: Polynomial<ModInteger<M>, Q> =
+            Polynomial::from_coeffs(generate_random_modint_polynomial::<M>(ring_degree).coeffs);
+
+        let prod1 = poly1.multiply_ntt(&poly2, &ntt);
+        let prod2 = poly1.multiply(&poly2);
+
+        assert_eq!(prod1.ring_degree, prod2.ring_degree);
+        assert_eq!(prod1.coeffs, prod2.coeffs);
+    }
+}
+
+#[test]
+fn test_multiply_matches_schoolbook_kyber() {
+    // 3329 - 1 = 13 * 2^8, so the negacyclic transform only goes up to N = 128.
+    test_multiply_matches_schoolbook::<KYBER_Q, Cyclic>(8);
+    test_multiply_matches_schoolbook::<KYBER_Q, Negacyclic>(7);
+}
+
+#[test]
+fn test_multiply_matches_schoolbook_falcon() {
+    test_multiply_matches_schoolbook::<FALCON_Q, Cyclic>(MAX_TEST_LOG_DEGREE);
+    test_multiply_matches_schoolbook::<FALCON_Q, Negacyclic>(MAX_TEST_LOG_DEGREE);
+}
+
+#[test]
+fn test_multiply_matches_schoolbook_goldilocks() {
+    test_multiply_matches_schoolbook::<GOLDILOCKS_Q, Cyclic>(MAX_TEST_LOG_DEGREE);
+    test_multiply_matches_schoolbook::<GOLDILOCKS_Q, Negacyclic>(MAX_TEST_LOG_DEGREE);
+}
+
+#[test]
+#[should_panic(expected = "Ring degree should be a power of two. 12")]
+fn test_new_not_power_of_two_fail() {
+    // Should panic because there is no radix-2 transform of size 12.
+    let _: Ntt<FALCON_Q> = Ntt::new(12);
+}
+
+#[test]
+#[should_panic(expected = "Modulus should be prime. 18446743979220271189")]
+fn test_new_composite_modulus_fail() {
+    // Should panic rather than search for a root of unity, since (2^32 - 5)(2^32 - 17) is composite.
+    let _: Ntt<18446743979220271189> = Ntt::new(4);
+}
+
+#[test]
+fn test_new_large_prime_modulus() {
+    let _: Ntt<GOLDILOCKS_Q, Negacyclic> = Ntt::new(1024);
+    let _: Ntt<KYBER_Q> = Ntt::new(256);
+}
+
+#[test]
+#[should_panic(expected = "Modulus minus one should be divisible by 512. 3329 - 1 is not")]
+fn test_new_no_root_of_unity_fail() {
+    // Should panic because 3329 has no primitive 512th root of unity.
+    let _: Ntt<KYBER_Q, Negacyclic> = Ntt::new(256);
+}
+
+#[test]
+fn test_try_multiply_different_ring_degrees() {
+    let ntt: Ntt<FALCON_Q> = Ntt::new(8);
+    let poly1: Polynomial<ModInteger<FALCON_Q>> = generate_random_modint_polynomial(8);
+    let poly2: Polynomial<ModInteger<FALCON_Q>> = generate_random_modint_polynomial(16);
+
+    assert_eq!(ntt.try_multiply(&poly1, &poly2).err(), Some(RingMathError::RingDegreeMismatch(16, 8)));
+    assert_eq!(ntt.try_inverse(&poly2.coeffs).err(), Some(RingMathError::RingDegreeMismatch(16, 8)));
+}
+
+#[test]
+fn test_try_automorphism_invalid_index() {
+    let ntt: Ntt<FALCON_Q, Negacyclic> = Ntt::new(8);
+    let poly: Polynomial<ModInteger<FALCON_Q>, Negacyclic> =
+        Polynomial::from_coeffs(generate_random_modint_polynomial::<FALCON_Q>(8).coeffs);
+    let values = ntt.forward(&poly);
+
+    assert_eq!(ntt.try_automorphism(&values, 4).err(), Some(RingMathError::IndexNotCoprime(4, 16)));
+    assert_eq!(ntt.try_automorphism(&values, 0).err(), Some(RingMathError::IndexNotCoprime(0, 16)));
+    assert_eq!(ntt.try_automorphism(&values[1..], 3).err(), Some(RingMathError::RingDegreeMismatch(7, 8)));
+    assert!(ntt.try_automorphism(&values, 5).is_ok());
+}
@@ -1,18 +1,45 @@
 //! polynomial module
-use std::ops::{Add, Mul, AddAssign};
-use crate::traits::Zero;
+use std::marker::PhantomData;
+use rand::Rng;
+use std::ops;
+use std::fmt;
+use std::borrow::Cow;
+use std::cmp::PartialEq;
+use std::convert::TryFrom;
+use crate::traits::{Zero, One, Norm, Ring, CommutativeRing, Field, ModularRing};
+use crate::ring::{Complex, ModInteger32, ModInteger64, ModInteger, ContextModInteger, Torus32, Torus64};
+use crate::quotient::{Quotient, Cyclic, QuotientRing};
+use crate::error::RingMathError;
+use crate::ntt::Ntt;
+use crate::fft::{Fft, FftCoefficient};
+use crate::automorphism::{Automorphism, gcd};
 
-/// Structure representing a polynomial in a generic ring T[x]/(X^N - 1)
+/// Structure representing a polynomial in a generic ring T[x]/(X^N ± 1)
 ///
 /// # Attributes
 /// * `ring_degree` - the degree N of the polynomial ring
 /// * `coeffs` - the coefficients of the polynomial
-pub struct Polynomial<T: Add<Output = T> + Mul<Output = T> + Copy + Zero<T> + AddAssign> {
+/// * `quotient` - marker for the quotient of the ring, `Cyclic` (X^N - 1) or `Negacyclic` (X^N + 1)
+///
+/// Polynomials are a `Ring` themselves, so `Polynomial<Polynomial<T>>` holds bivariate polynomials.
+/// `Zero::zero()` and `One::one()` cannot know a ring degree, so they return context-free
+/// constants, like those of `ContextModInteger`, which take the ring degree of the polynomial they
+/// meet in an operator. A constant c is held with ring degree 0 and the single coefficient c,
+/// which no polynomial of a ring has.
+pub struct Polynomial<T: Ring, Q = Cyclic> {
     pub ring_degree: usize,
-    pub coeffs: Vec<T>
+    pub coeffs: Vec<T>,
+    quotient: PhantomData<Q>
 }
 
-impl<T: Add<Output = T> + Mul<Output = T> + Copy + Zero<T> + AddAssign> Polynomial<T> {
+/// The quotient and remainder of a polynomial division.
+pub type QuotientRemainder<T, Q = Cyclic> = (Polynomial<T, Q>, Polynomial<T, Q>);
+
+/// The monic GCD g of two polynomials a and b, with Bézout coefficients s and t such that
+/// s * a + t * b = g.
+pub type GcdBezout<T, Q = Cyclic> = (Polynomial<T, Q>, Polynomial<T, Q>, Polynomial<T, Q>);
+
+impl<T: Ring, Q: Quotient<T>> Polynomial<T, Q> {
 
     /// Return a new zero polynomial.
     ///
@@ -22,19 +49,60 @@ impl<T: Add<Output = T> + Mul<Output = T> + Copy + Zero<T> + AddAssign> Polynomi
     /// # Output
     /// * a new instantiation of a Polynomial, equal to 0
     /// ```
-    pub fn new(ring_degree: usize) -> Polynomial<T> {
+    pub fn new(ring_degree: usize) -> Polynomial<T, Q> {
         Polynomial {ring_degree,
-                    coeffs: vec![T::zero(); ring_degree] }
+                    coeffs: vec![T::zero(); ring_degree],
+                    quotient: PhantomData }
+    }
+
+    /// Return a new polynomial with the given coefficients.
+    ///
+    /// # Arguments
+    /// * `coeffs` - Coefficients of the polynomial. The degree N of the ring is their length.
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial with the given coefficients
+    /// ```
+    pub fn from_coeffs(coeffs: Vec<T>) -> Polynomial<T, Q> {
+        Polynomial {ring_degree: coeffs.len(),
+                    coeffs,
+                    quotient: PhantomData }
     }
 
     /// Check length of coefficients.
     ///
-    /// # Panics if length of coeffs does not match degree of ring.
+    /// # Output
+    /// * `Err(RingMathError::CoeffLengthInvalid)` if length of coeffs does not match degree of ring
     /// ```
-    fn check_coeff_length(&self){
+    pub fn try_check_coeff_length(&self) -> Result<(), RingMathError> {
         if self.ring_degree != self.coeffs.len() {
-            panic!("Ring degree should be equal to vector length. {} != {}", self.ring_degree, self.coeffs.len());
+            return Err(RingMathError::CoeffLengthInvalid(self.ring_degree, self.coeffs.len()));
+        }
+        Ok(())
+    }
+
+    /// Check length of coefficients.
+    ///
+    /// # Panics if length of coeffs does not match degree of ring.
+    /// ```
+    pub(crate) fn check_coeff_length(&self){
+        if let Err(err) = self.try_check_coeff_length() {
+            panic!("{}", err);
+        }
+    }
+
+    /// Check that another polynomial is well formed and belongs to the same ring.
+    ///
+    /// # Output
+    /// * `Err(RingMathError)` if either polynomial has the wrong number of coefficients, or the
+    ///   ring degrees differ
+    /// ```
+    pub(crate) fn try_check_same_ring(&self, other: &Polynomial<T, Q>) -> Result<(), RingMathError> {
+        self.try_check_coeff_length()?;
+        if self.ring_degree != other.ring_degree {
+            return Err(RingMathError::RingDegreeMismatch(self.ring_degree, other.ring_degree));
         }
+        other.try_check_coeff_length()
     }
 
     /// Add a polynomial to another polynomial in the ring.
@@ -45,44 +113,123 @@ impl<T: Add<Output = T> + Mul<Output = T> + Copy + Zero<T> + AddAssign> Polynomi
     /// # Output
     /// * a new instantiation of a Polynomial, which is the sum of the two polynomials
     /// ```
-    pub fn add(&self, other: &Polynomial<T>) -> Polynomial<T> {
-        self.check_coeff_length();
-        if self.ring_degree != other.ring_degree {
-            panic!("Ring degrees should be equal. {} != {}", self.ring_degree, other.ring_degree);
+    pub fn add(&self, other: &Polynomial<T, Q>) -> Polynomial<T, Q> {
+        self.try_add(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Add a polynomial to another polynomial in the ring, without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to add
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the sum of the two polynomials, or the
+    ///   `RingMathError` describing why the polynomials cannot be added
+    /// ```
+    pub fn try_add(&self, other: &Polynomial<T, Q>) -> Result<Polynomial<T, Q>, RingMathError> {
+        self.try_check_same_ring(other)?;
+
+        let mut sum: Polynomial<T, Q> = Polynomial::new(self.ring_degree);
+        for i in 0..self.ring_degree {
+            sum.coeffs[i] = self.coeffs[i].clone() + other.coeffs[i].clone();
         }
+        Ok(sum)
+    }
+
+    /// Add another polynomial to a polynomial in place.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to add
+    /// ```
+    pub fn add_assign(&mut self, other: &Polynomial<T, Q>) {
+        self.try_add_assign(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Add another polynomial to a polynomial in place, without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to add
+    ///
+    /// # Output
+    /// * `Err(RingMathError)` describing why the polynomials cannot be added, in which case the
+    ///   polynomial is left unchanged
+    /// ```
+    pub fn try_add_assign(&mut self, other: &Polynomial<T, Q>) -> Result<(), RingMathError> {
+        self.try_check_same_ring(other)?;
 
-        let mut sum: Polynomial<T> = Polynomial::new(self.ring_degree);
         for i in 0..self.ring_degree {
-            sum.coeffs[i] = self.coeffs[i] + other.coeffs[i];
+            self.coeffs[i] += other.coeffs[i].clone();
         }
-        sum
+        Ok(())
     }
 
     /// Multiply a polynomial to another polynomial in the ring.
     ///
+    /// This is the schoolbook product for every coefficient type, floats included, so products of
+    /// integer-valued floats are exact. For large float or complex polynomials, `multiply_fft`
+    /// runs in O(N log N) instead, at the cost of a rounding error in every coefficient.
+    ///
     /// # Arguments
     /// * `other` - other polynomial to add
     ///
     /// # Output
     /// * a new instantiation of a Polynomial, which is the product of the two polynomials
     /// ```
-    pub fn multiply(&self, other: &Polynomial<T>) -> Polynomial<T> {
-        self.check_coeff_length();
-        if self.ring_degree != other.ring_degree {
-            panic!("Ring degrees should be equal. {} != {}", self.ring_degree, other.ring_degree);
-        }
+    pub fn multiply(&self, other: &Polynomial<T, Q>) -> Polynomial<T, Q> {
+        self.try_multiply(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Multiply a polynomial to another polynomial in the ring, without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to multiply
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the product of the two polynomials, or the
+    ///   `RingMathError` describing why the polynomials cannot be multiplied
+    /// ```
+    pub fn try_multiply(&self, other: &Polynomial<T, Q>) -> Result<Polynomial<T, Q>, RingMathError> {
+        self.try_check_same_ring(other)?;
 
-        let mut prod: Polynomial<T> = Polynomial::new(self.ring_degree);
+        let mut prod: Polynomial<T, Q> = Polynomial::new(self.ring_degree);
         for i in 0..self.ring_degree {
             // Compute the x^i term in the product.
             for j in 0..=i {
-                prod.coeffs[i] += self.coeffs[j] * other.coeffs[i - j];
+                prod.coeffs[i] += self.coeffs[j].mul_ref(&other.coeffs[i - j]);
             }
             // Compute the x^(N + i) term in the product.
+            let mut wrapped = T::zero();
             for j in (i+1)..self.ring_degree {
-                // Set it to the x^i term since x^i = x^(N+i) in the polynomial ring.
-                prod.coeffs[i] += self.coeffs[j] * other.coeffs[self.ring_degree + i - j];
+                wrapped += self.coeffs[j].mul_ref(&other.coeffs[self.ring_degree + i - j]);
             }
+            // Fold it onto the x^i term since x^(N+i) = ±x^i in the polynomial ring.
+            prod.coeffs[i] += Q::wrap(wrapped);
+        }
+        Ok(prod)
+    }
+
+    /// Multiply a polynomial by another polynomial in place.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to multiply
+    /// ```
+    pub fn multiply_assign(&mut self, other: &Polynomial<T, Q>) {
+        *self = self.multiply(other);
+    }
+
+    /// Multiply a polynomial by a scalar.
+    ///
+    /// # Arguments
+    /// * `scalar` - scalar to multiply by
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the product of the polynomial with the scalar
+    /// ```
+    pub fn multiply_by_scalar(&self, scalar: T) -> Polynomial<T, Q> {
+        self.check_coeff_length();
+        let mut prod: Polynomial<T, Q> = Polynomial::new(self.ring_degree);
+        for i in 0..self.ring_degree {
+            prod.coeffs[i] = self.coeffs[i].mul_ref(&scalar);
         }
         prod
     }
@@ -92,16 +239,1201 @@ impl<T: Add<Output = T> + Mul<Output = T> + Copy + Zero<T> + AddAssign> Polynomi
     /// # Output
     /// * a new instantiation of a Polynomial, which is the product of the polynomial with x
     /// ```
-    pub fn multiply_by_x(&self) -> Polynomial<T> {
+    pub fn multiply_by_x(&self) -> Polynomial<T, Q> {
         self.check_coeff_length();
-        let mut prod: Polynomial<T> = Polynomial::new(self.ring_degree);
-        prod.coeffs[0] = self.coeffs[self.ring_degree - 1];
+        let mut prod: Polynomial<T, Q> = Polynomial::new(self.ring_degree);
+        prod.coeffs[0] = Q::wrap(self.coeffs[self.ring_degree - 1].clone());
         for i in 1..self.ring_degree {
-            prod.coeffs[i] = self.coeffs[i - 1]
+            prod.coeffs[i] = self.coeffs[i - 1].clone()
         }
         prod
     }
+
+    /// Multiply a polynomial by the monomial x^k.
+    ///
+    /// # Arguments
+    /// * `exponent` - the power k of x, which may be negative since x^2N = 1 in the ring
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the product of the polynomial with x^k
+    /// ```
+    pub fn multiply_by_monomial(&self, exponent: i64) -> Polynomial<T, Q> {
+        self.check_coeff_length();
+        let mut prod: Polynomial<T, Q> = Polynomial::from_coeffs(self.coeffs.clone());
+        prod.multiply_by_monomial_assign(exponent);
+        prod
+    }
+
+    /// Multiply a polynomial by the monomial x^k in place.
+    ///
+    /// # Arguments
+    /// * `exponent` - the power k of x, which may be negative since x^2N = 1 in the ring
+    /// ```
+    pub fn multiply_by_monomial_assign(&mut self, exponent: i64) {
+        self.check_coeff_length();
+        if self.ring_degree == 0 {
+            return;
+        }
+
+        // Reduce k to [0, 2N), then rotate by k mod N. The x^i terms with i < k mod N wrapped past
+        // x^N once, and every term wraps once more if k >= N.
+        let shift = exponent.rem_euclid(2 * self.ring_degree as i64) as usize;
+        let rotation = shift % self.ring_degree;
+        let wrapped_all = shift >= self.ring_degree;
+        self.coeffs.rotate_right(rotation);
+        for i in 0..self.ring_degree {
+            let mut wraps = if i < rotation { 1 } else { 0 };
+            if wrapped_all {
+                wraps += 1;
+            }
+            for _ in 0..wraps {
+                self.coeffs[i] = Q::wrap(self.coeffs[i].clone());
+            }
+        }
+    }
+
+    /// Apply the Galois automorphism X -> X^k to a polynomial.
+    ///
+    /// The permutation and sign tables are cached per (N, k), see `Automorphism::cached`.
+    ///
+    /// # Arguments
+    /// * `index` - the index k, coprime to 2N
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is a(X^k)
+    ///
+    /// # Panics if k is not coprime to 2N.
+    /// ```
+    pub fn automorphism(&self, index: usize) -> Polynomial<T, Q> {
+        self.try_automorphism(index).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Apply the Galois automorphism X -> X^k to a polynomial, without panicking.
+    ///
+    /// # Arguments
+    /// * `index` - the index k, coprime to 2N
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is a(X^k), or the `RingMathError` describing
+    ///   why the automorphism cannot be applied
+    /// ```
+    pub fn try_automorphism(&self, index: usize) -> Result<Polynomial<T, Q>, RingMathError> {
+        self.try_check_coeff_length()?;
+        if self.ring_degree == 0 {
+            // The ring of degree 0 only holds the empty polynomial, which every X -> X^k fixes.
+            return Ok(Polynomial::new(0));
+        }
+        let order = 2 * self.ring_degree;
+        if gcd(index, order) != 1 {
+            return Err(RingMathError::IndexNotCoprime(index, order));
+        }
+
+        Ok(Automorphism::cached(self.ring_degree, index).apply(self))
+    }
+
+    /// Evaluate a polynomial at a point, by Horner's method.
+    ///
+    /// # Arguments
+    /// * `point` - the point to evaluate at
+    ///
+    /// # Output
+    /// * the value of the polynomial at the point
+    /// ```
+    pub fn evaluate(&self, point: T) -> T {
+        self.check_coeff_length();
+        horner(&self.coeffs, point)
+    }
+
+    /// Subtract another polynomial from a polynomial in the ring.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to subtract
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the difference of the two polynomials
+    /// ```
+    pub fn sub(&self, other: &Polynomial<T, Q>) -> Polynomial<T, Q> {
+        self.try_sub(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Subtract another polynomial from a polynomial in the ring, without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to subtract
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the difference of the two polynomials, or
+    ///   the `RingMathError` describing why the polynomials cannot be subtracted
+    /// ```
+    pub fn try_sub(&self, other: &Polynomial<T, Q>) -> Result<Polynomial<T, Q>, RingMathError> {
+        self.try_check_same_ring(other)?;
+
+        let mut diff: Polynomial<T, Q> = Polynomial::new(self.ring_degree);
+        for i in 0..self.ring_degree {
+            diff.coeffs[i] = self.coeffs[i].clone() - other.coeffs[i].clone();
+        }
+        Ok(diff)
+    }
+
+    /// Subtract another polynomial from a polynomial in place.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to subtract
+    /// ```
+    pub fn sub_assign(&mut self, other: &Polynomial<T, Q>) {
+        self.try_sub_assign(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Subtract another polynomial from a polynomial in place, without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to subtract
+    ///
+    /// # Output
+    /// * `Err(RingMathError)` describing why the polynomials cannot be subtracted, in which case
+    ///   the polynomial is left unchanged
+    /// ```
+    pub fn try_sub_assign(&mut self, other: &Polynomial<T, Q>) -> Result<(), RingMathError> {
+        self.try_check_same_ring(other)?;
+
+        for i in 0..self.ring_degree {
+            self.coeffs[i] = self.coeffs[i].clone() - other.coeffs[i].clone();
+        }
+        Ok(())
+    }
+
+    /// Negate a polynomial.
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the additive inverse of the polynomial
+    /// ```
+    pub fn neg(&self) -> Polynomial<T, Q> {
+        self.check_coeff_length();
+        let mut neg: Polynomial<T, Q> = Polynomial::new(self.ring_degree);
+        for i in 0..self.ring_degree {
+            neg.coeffs[i] = T::zero() - self.coeffs[i].clone();
+        }
+        neg
+    }
+
+    /// Multiply a polynomial to another polynomial in the ring, using Karatsuba multiplication.
+    ///
+    /// Unlike `multiply_ntt` and `multiply_fft` this works for any coefficient ring, including
+    /// ones without roots of unity such as the integers mod 2^64, in O(N^1.585) operations.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to multiply
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the product of the two polynomials
+    /// ```
+    pub fn multiply_karatsuba(&self, other: &Polynomial<T, Q>) -> Polynomial<T, Q> {
+        self.try_multiply_karatsuba(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Multiply a polynomial to another polynomial in the ring, using Karatsuba multiplication,
+    /// without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to multiply
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the product of the two polynomials, or the
+    ///   `RingMathError` describing why the polynomials cannot be multiplied
+    /// ```
+    pub fn try_multiply_karatsuba(&self, other: &Polynomial<T, Q>) -> Result<Polynomial<T, Q>, RingMathError> {
+        self.try_check_same_ring(other)?;
+
+        // Compute the full product of degree up to 2N - 2, then fold the x^(N + i) terms onto x^i.
+        let full = karatsuba(&self.coeffs, &other.coeffs);
+        let mut prod: Polynomial<T, Q> = Polynomial::from_coeffs(full[..self.ring_degree].to_vec());
+        for (i, value) in full.iter().enumerate().skip(self.ring_degree) {
+            prod.coeffs[i - self.ring_degree] += Q::wrap(value.clone());
+        }
+        Ok(prod)
+    }
+
+    /// Multiply a polynomial to another polynomial modulo an arbitrary monic polynomial f(x).
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to multiply
+    /// * `ring` - quotient ring T[x]/(f) of the polynomials
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the product of the two polynomials mod f
+    /// ```
+    pub fn multiply_mod(&self, other: &Polynomial<T, Q>, ring: &QuotientRing<T>) -> Polynomial<T, Q> {
+        ring.multiply(self, other)
+    }
+
+    /// Return the degree of a polynomial, as a plain polynomial of degree less than N.
+    ///
+    /// # Output
+    /// * the index of the highest nonzero coefficient, or `None` for the zero polynomial
+    /// ```
+    pub fn degree(&self) -> Option<usize> {
+        degree_plain(&self.coeffs)
+    }
+
+    /// Return the inverse of a polynomial with coefficients mod 2^k, modulo the polynomial f of
+    /// degree N with the given coefficients.
+    ///
+    /// The inverse is found mod 2 by the extended Euclidean algorithm over GF(2), then lifted by
+    /// Newton's iteration b -> b (2 - a b), which doubles the number of correct bits each step.
+    fn inverse_lifted(&self, modulus: &[T], bits: u32, is_odd: fn(T) -> bool,
+                      multiply: impl Fn(&Polynomial<T, Q>, &Polynomial<T, Q>) -> Polynomial<T, Q>)
+            -> Option<Polynomial<T, Q>> {
+        let poly_mod_two: Vec<bool> = self.coeffs.iter().map(|coeff| is_odd(coeff.clone())).collect();
+        let modulus_mod_two: Vec<bool> = modulus.iter().map(|coeff| is_odd(coeff.clone())).collect();
+        let inverse_mod_two = inverse_mod_two(&poly_mod_two, &modulus_mod_two)?;
+
+        let two = T::one() + T::one();
+        let mut inverse: Polynomial<T, Q> = Polynomial::from_coeffs(
+            inverse_mod_two.iter().map(|&bit| if bit { T::one() } else { T::zero() }).collect());
+        let mut precision = 1;
+        while precision < bits {
+            let mut error = multiply(self, &inverse).neg();
+            error.coeffs[0] += two.clone();
+            inverse = multiply(&inverse, &error);
+            precision *= 2;
+        }
+        Some(inverse)
+    }
+
+    /// Evaluate a polynomial at many points, with a subproduct tree.
+    ///
+    /// The polynomial is reduced modulo the product of x - x_i over each half of the points, then
+    /// each quarter, down to the single points, in O(M(n) log n) operations where M(n) is the cost
+    /// of a Karatsuba product. Over the floats the remainders lose precision quickly as the number
+    /// of points grows, so prefer `evaluate` there unless the points are few and well spread.
+    ///
+    /// # Arguments
+    /// * `points` - the points to evaluate at
+    ///
+    /// # Output
+    /// * the values of the polynomial at the points, in the same order
+    /// ```
+    pub fn evaluate_many(&self, points: &[T]) -> Vec<T> {
+        self.check_coeff_length();
+        if points.is_empty() {
+            return Vec::new();
+        }
+
+        let tree = subproduct_tree(points);
+        let mut remainders: Vec<Vec<T>> = vec![remainder_monic(&self.coeffs, &tree[tree.len() - 1][0])];
+        for level in tree.iter().rev().skip(1) {
+            let mut next: Vec<Vec<T>> = Vec::new();
+            for (j, node) in level.iter().enumerate() {
+                next.push(remainder_monic(&remainders[j / 2], node));
+            }
+            remainders = next;
+        }
+        remainders.iter().map(|remainder| remainder[0].clone()).collect()
+    }
+
+    /// Return a context-free constant, which takes the ring degree of the polynomial it meets.
+    fn constant(value: T) -> Polynomial<T, Q> {
+        Polynomial {ring_degree: 0,
+                    coeffs: vec![value],
+                    quotient: PhantomData }
+    }
+
+    /// Return whether a polynomial is a context-free constant.
+    fn is_constant(&self) -> bool {
+        self.ring_degree == 0 && self.coeffs.len() == 1
+    }
+
+    /// Return a context-free constant c as c + 0x + ... + 0x^(N - 1) in the ring of degree N, or
+    /// any other polynomial unchanged.
+    fn bind(&self, ring_degree: usize) -> Cow<'_, Polynomial<T, Q>> {
+        if !self.is_constant() {
+            return Cow::Borrowed(self);
+        }
+        let mut coeffs: Vec<T> = vec![T::zero(); ring_degree];
+        if let Some(coeff) = coeffs.first_mut() {
+            *coeff = self.coeffs[0].clone();
+        }
+        Cow::Owned(Polynomial::from_coeffs(coeffs))
+    }
+
+    /// Apply an operation to two polynomials, after binding a context-free constant to the ring
+    /// degree of the other polynomial. Two constants are combined in the ring of degree 1, where
+    /// no term wraps, and give a constant.
+    fn combine(&self, other: &Self, op: fn(&Self, &Self) -> Self) -> Self {
+        if self.is_constant() && other.is_constant() {
+            let mut result = op(&self.bind(1), &other.bind(1));
+            return Polynomial::constant(result.coeffs.swap_remove(0));
+        }
+        op(&self.bind(other.ring_degree), &other.bind(self.ring_degree))
+    }
+}
+
+/// Polynomials with at most this many coefficients are multiplied by the schoolbook method in
+/// `multiply_karatsuba`, since the extra additions of Karatsuba outweigh the saved multiplications.
+pub const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Compute the full product of two polynomials of the same length n, of length 2n - 1.
+///
+/// Toom-3 would need to divide by 2 and 3, which is not possible in every coefficient ring, so
+/// only the two-way split is used: with a = a0 + a1 x^h and b = b0 + b1 x^h,
+/// ab = a0 b0 + ((a0 + a1)(b0 + b1) - a0 b0 - a1 b1) x^h + a1 b1 x^2h.
+fn karatsuba<T: Ring>(a: &[T], b: &[T]) -> Vec<T> {
+    let n = a.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut prod: Vec<T> = vec![T::zero(); 2 * n - 1];
+    if n <= KARATSUBA_THRESHOLD {
+        for i in 0..n {
+            for j in 0..n {
+                prod[i + j] += a[i].mul_ref(&b[j]);
+            }
+        }
+        return prod;
+    }
+
+    let half = n.div_ceil(2);
+    let (a0, a1) = a.split_at(half);
+    let (b0, b1) = b.split_at(half);
+    let low = karatsuba(a0, b0);
+    let high = karatsuba(a1, b1);
+
+    // The upper halves are at most as long as the lower halves, so pad them with zeros.
+    let mut a_sum: Vec<T> = a0.to_vec();
+    let mut b_sum: Vec<T> = b0.to_vec();
+    for i in 0..n - half {
+        a_sum[i] += a1[i].clone();
+        b_sum[i] += b1[i].clone();
+    }
+    let mut middle = karatsuba(&a_sum, &b_sum);
+    for i in 0..low.len() {
+        middle[i] = middle[i].clone() - low[i].clone();
+    }
+    for i in 0..high.len() {
+        middle[i] = middle[i].clone() - high[i].clone();
+    }
+
+    for i in 0..low.len() {
+        prod[i] += low[i].clone();
+        prod[i + half] += middle[i].clone();
+    }
+    for i in 0..high.len() {
+        prod[i + 2 * half] += high[i].clone();
+    }
+    prod
+}
+
+/// Evaluate the polynomial with the given coefficients at a point, by Horner's method.
+fn horner<T: Ring>(coeffs: &[T], point: T) -> T {
+    let mut value = T::zero();
+    for coeff in coeffs.iter().rev() {
+        value = value * point.clone() + coeff.clone();
+    }
+    value
+}
+
+/// Compute the full product of two polynomials of any nonzero lengths.
+fn multiply_full<T: Ring>(a: &[T], b: &[T]) -> Vec<T> {
+    let n = a.len().max(b.len());
+    let mut a_padded: Vec<T> = a.to_vec();
+    let mut b_padded: Vec<T> = b.to_vec();
+    a_padded.resize(n, T::zero());
+    b_padded.resize(n, T::zero());
+    let mut prod = karatsuba(&a_padded, &b_padded);
+    prod.truncate(a.len() + b.len() - 1);
+    prod
+}
+
+/// Compute the power series inverse mod x^k of a polynomial with constant term 1, by Newton's
+/// iteration h -> h (2 - g h), which doubles the number of correct terms each step.
+fn inverse_series<T: Ring>(g: &[T], k: usize) -> Vec<T> {
+    let two = T::one() + T::one();
+    let mut inverse: Vec<T> = vec![T::one()];
+    let mut precision = 1;
+    while precision < k {
+        precision = (2 * precision).min(k);
+        let mut error = multiply_full(&g[..precision.min(g.len())], &inverse);
+        error.resize(precision, T::zero());
+        for coeff in error.iter_mut() {
+            *coeff = T::zero() - coeff.clone();
+        }
+        error[0] += two.clone();
+        inverse = multiply_full(&inverse, &error);
+        inverse.truncate(precision);
+    }
+    inverse
 }
 
+/// Compute the remainder of a polynomial modulo a monic polynomial m of degree d, as d coefficients.
+///
+/// Short quotients use long division. Longer ones reverse the polynomials, so that the quotient
+/// becomes a power series product with the inverse of the reversed m.
+fn remainder_monic<T: Ring>(a: &[T], m: &[T]) -> Vec<T> {
+    let degree = m.len() - 1;
+    if a.len() <= degree {
+        let mut remainder: Vec<T> = a.to_vec();
+        remainder.resize(degree, T::zero());
+        return remainder;
+    }
+
+    let quotient_len = a.len() - degree;
+    if quotient_len <= KARATSUBA_THRESHOLD || degree <= KARATSUBA_THRESHOLD {
+        let mut remainder: Vec<T> = a.to_vec();
+        for k in (degree..a.len()).rev() {
+            let top = remainder[k].clone();
+            for i in 0..degree {
+                remainder[k - degree + i] = remainder[k - degree + i].clone() - top.mul_ref(&m[i]);
+            }
+        }
+        remainder.truncate(degree);
+        return remainder;
+    }
+
+    let a_reversed: Vec<T> = a.iter().rev().cloned().collect();
+    let m_reversed: Vec<T> = m.iter().rev().cloned().collect();
+    let mut quotient = multiply_full(&a_reversed[..quotient_len], &inverse_series(&m_reversed, quotient_len));
+    quotient.truncate(quotient_len);
+    quotient.reverse();
+
+    let prod = multiply_full(m, &quotient);
+    let mut remainder: Vec<T> = Vec::new();
+    for i in 0..degree {
+        remainder.push(a[i].clone() - prod[i].clone());
+    }
+    remainder
+}
+
+/// Build the subproduct tree of a nonempty list of points.
+///
+/// Level 0 holds the polynomials x - x_i, and each node of the next level is the product of two
+/// neighbouring nodes, or a copy of the last node if the level has odd length. The last level holds
+/// the single product of all the x - x_i.
+fn subproduct_tree<T: Ring>(points: &[T]) -> Vec<Vec<Vec<T>>> {
+    let mut tree: Vec<Vec<Vec<T>>> = vec![points.iter().map(|point| vec![T::zero() - point.clone(), T::one()]).collect()];
+    while tree[tree.len() - 1].len() > 1 {
+        let level = &tree[tree.len() - 1];
+        let mut next: Vec<Vec<T>> = Vec::new();
+        for pair in level.chunks(2) {
+            if pair.len() == 2 {
+                next.push(multiply_full(&pair[0], &pair[1]));
+            } else {
+                next.push(pair[0].clone());
+            }
+        }
+        tree.push(next);
+    }
+    tree
+}
+
+impl<T: Field, Q: Quotient<T>> Polynomial<T, Q> {
+
+    /// Return the polynomial of degree less than n which takes the given values at n points, by
+    /// Lagrange interpolation.
+    ///
+    /// # Arguments
+    /// * `ring_degree` - Degree N of ring, at least the number of points.
+    /// * `points` - the distinct points x_i
+    /// * `values` - the values y_i at the points
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial p with p(x_i) = y_i, or the `RingMathError` describing
+    ///   why the points cannot be interpolated
+    /// ```
+    pub fn try_interpolate(ring_degree: usize, points: &[T], values: &[T]) -> Result<Polynomial<T, Q>, RingMathError> {
+        if points.len() != values.len() {
+            return Err(RingMathError::LengthMismatch(points.len(), values.len()));
+        }
+        if points.len() > ring_degree {
+            return Err(RingMathError::TooManyPoints(points.len(), ring_degree));
+        }
+
+        // Compute M(x) = (x - x_0)...(x - x_(n-1)).
+        let mut vanishing: Vec<T> = vec![T::one()];
+        for point in points.iter() {
+            let mut next: Vec<T> = vec![T::zero(); vanishing.len() + 1];
+            for (k, coeff) in vanishing.iter().enumerate() {
+                next[k + 1] += coeff.clone();
+                next[k] = next[k].clone() - coeff.mul_ref(point);
+            }
+            vanishing = next;
+        }
+
+        // Add y_i L_i(x), where L_i(x) = M(x) / ((x - x_i) M'(x_i)).
+        let mut poly: Polynomial<T, Q> = Polynomial::new(ring_degree);
+        for (i, point) in points.iter().enumerate() {
+            let mut basis: Vec<T> = vec![T::zero(); points.len()];
+            let mut carry = T::zero();
+            for k in (0..points.len()).rev() {
+                carry = vanishing[k + 1].clone() + carry * point.clone();
+                basis[k] = carry.clone();
+            }
+            let denominator = horner(&basis, point.clone());
+            if denominator == T::zero() {
+                let j = (0..points.len()).find(|&j| j != i && points[j] == *point).unwrap_or(i);
+                return Err(RingMathError::RepeatedPoint(i.min(j), i.max(j)));
+            }
+            let scale = values[i].clone() * denominator.inverse();
+            for (coeff, term) in poly.coeffs.iter_mut().zip(basis) {
+                *coeff += term * scale.clone();
+            }
+        }
+        Ok(poly)
+    }
+
+    /// Return the polynomial of degree less than n which takes the given values at n points, by
+    /// Lagrange interpolation.
+    ///
+    /// # Arguments
+    /// * `ring_degree` - Degree N of ring, at least the number of points.
+    /// * `points` - the distinct points x_i
+    /// * `values` - the values y_i at the points
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial p with p(x_i) = y_i
+    /// ```
+    pub fn interpolate(ring_degree: usize, points: &[T], values: &[T]) -> Polynomial<T, Q> {
+        Polynomial::try_interpolate(ring_degree, points, values).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Divide a polynomial by another polynomial, with remainder.
+    ///
+    /// Both polynomials are treated as plain polynomials of degree less than N, so the quotient X^N ± 1
+    /// of the ring plays no part. The coefficients should form a field, such as the integers modulo a
+    /// prime.
+    ///
+    /// # Arguments
+    /// * `divisor` - nonzero polynomial to divide by
+    ///
+    /// # Output
+    /// * the quotient q and remainder r with self = q * divisor + r and deg r < deg divisor
+    /// ```
+    pub fn divide(&self, divisor: &Polynomial<T, Q>) -> QuotientRemainder<T, Q> {
+        self.try_divide(divisor).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Divide a polynomial by another polynomial, with remainder.
+    ///
+    /// # Arguments
+    /// * `divisor` - nonzero polynomial to divide by
+    ///
+    /// # Output
+    /// * the quotient and remainder, or the `RingMathError` describing why the division failed
+    /// ```
+    pub fn try_divide(&self, divisor: &Polynomial<T, Q>)
+            -> Result<QuotientRemainder<T, Q>, RingMathError> {
+        self.try_check_same_ring(divisor)?;
+        let divisor_degree = divisor.degree().ok_or(RingMathError::DivisionByZero)?;
+
+        let (quotient, remainder) = divide_plain(&self.coeffs, &divisor.coeffs, divisor_degree);
+        Ok((Polynomial::from_coeffs(quotient), Polynomial::from_coeffs(remainder)))
+    }
+
+    /// Compute the monic greatest common divisor of two polynomials, by Euclid's algorithm.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial
+    ///
+    /// # Output
+    /// * the monic GCD of the two polynomials, or zero if both are zero
+    /// ```
+    pub fn gcd(&self, other: &Polynomial<T, Q>) -> Polynomial<T, Q> {
+        self.try_gcd(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Compute the monic greatest common divisor of two polynomials, by Euclid's algorithm.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial
+    ///
+    /// # Output
+    /// * the monic GCD, or the `RingMathError` describing why it could not be computed
+    /// ```
+    pub fn try_gcd(&self, other: &Polynomial<T, Q>) -> Result<Polynomial<T, Q>, RingMathError> {
+        self.try_check_same_ring(other)?;
+        let mut a: Polynomial<T, Q> = Polynomial::from_coeffs(self.coeffs.clone());
+        let mut b: Polynomial<T, Q> = Polynomial::from_coeffs(other.coeffs.clone());
+        while b.degree().is_some() {
+            let (_, remainder) = a.try_divide(&b)?;
+            a = b;
+            b = remainder;
+        }
+        Ok(match a.degree() {
+            Some(degree) => a.multiply_by_scalar(a.coeffs[degree].clone().inverse()),
+            None => a
+        })
+    }
+
+    /// Compute the monic greatest common divisor g of two polynomials a and b, with Bézout
+    /// coefficients s and t such that s * a + t * b = g, by the extended Euclidean algorithm.
+    ///
+    /// As with `divide`, the polynomials are plain polynomials of degree less than N. The Bézout
+    /// coefficients have degree at most deg b and deg a, so the identity holds both for plain
+    /// polynomials and in the ring.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial b
+    ///
+    /// # Output
+    /// * the tuple (g, s, t)
+    /// ```
+    pub fn extended_gcd(&self, other: &Polynomial<T, Q>) -> GcdBezout<T, Q> {
+        self.try_extended_gcd(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Compute the monic GCD of two polynomials with Bézout coefficients, by the extended
+    /// Euclidean algorithm.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial b
+    ///
+    /// # Output
+    /// * the tuple (g, s, t), or the `RingMathError` describing why it could not be computed
+    /// ```
+    pub fn try_extended_gcd(&self, other: &Polynomial<T, Q>)
+            -> Result<GcdBezout<T, Q>, RingMathError> {
+        self.try_check_same_ring(other)?;
+        let (gcd, s, t) = extended_euclid_plain(self.coeffs.clone(), other.coeffs.clone());
+        let (gcd, s, t): GcdBezout<T, Q> = (Polynomial::from_coeffs(gcd), Polynomial::from_coeffs(s),
+                                            Polynomial::from_coeffs(t));
+        Ok(match gcd.degree() {
+            Some(degree) => {
+                let scale = gcd.coeffs[degree].clone().inverse();
+                (gcd.multiply_by_scalar(scale.clone()), s.multiply_by_scalar(scale.clone()), t.multiply_by_scalar(scale))
+            },
+            None => (gcd, s, t)
+        })
+    }
+
+    /// Return the inverse of a polynomial in the ring T[x]/(X^N ± 1), by the extended Euclidean
+    /// algorithm.
+    ///
+    /// The coefficients should form a field, such as the integers modulo a prime.
+    ///
+    /// # Output
+    /// * the inverse of the polynomial, or `None` if it shares a factor with X^N ± 1
+    /// ```
+    pub fn inverse(&self) -> Option<Polynomial<T, Q>> {
+        self.check_coeff_length();
+        self.inverse_modulo(&quotient_modulus::<T, Q>(self.ring_degree))
+    }
+
+    /// Return the inverse of a polynomial modulo an arbitrary monic polynomial f(x), by the extended
+    /// Euclidean algorithm.
+    ///
+    /// # Arguments
+    /// * `ring` - quotient ring T[x]/(f) of the polynomial
+    ///
+    /// # Output
+    /// * the inverse of the polynomial mod f, or `None` if it shares a factor with f
+    ///
+    /// # Panics if the ring degree of the polynomial does not match the degree of f.
+    /// ```
+    pub fn inverse_mod(&self, ring: &QuotientRing<T>) -> Option<Polynomial<T, Q>> {
+        self.check_coeff_length();
+        ring.check_ring_degree(self);
+        self.inverse_modulo(&ring.modulus)
+    }
+
+    /// Return the inverse of a polynomial modulo the polynomial f of degree N with the given
+    /// coefficients.
+    fn inverse_modulo(&self, modulus: &[T]) -> Option<Polynomial<T, Q>> {
+        let mut coeffs: Vec<T> = self.coeffs.clone();
+        coeffs.resize(modulus.len(), T::zero());
+
+        // Only the Bézout coefficient t with s f + t a = g is needed, and it has degree less than N.
+        let (gcd, _, mut inverse) = extended_euclid_plain(modulus.to_vec(), coeffs);
+        if degree_plain(&gcd) != Some(0) {
+            return None;
+        }
+        let scale = gcd[0].clone().inverse();
+        inverse.truncate(self.ring_degree);
+        Some(Polynomial::from_coeffs(inverse).multiply_by_scalar(scale))
+    }
+}
+
+/// Return the inverse of a polynomial over GF(2) modulo a polynomial f of degree N, as N bits, or
+/// `None` if they share a factor.
+///
+/// Each step cancels the top term of the longer remainder with a shift of the shorter one, keeping
+/// r_i = t_i a mod f, so no division is needed.
+fn inverse_mod_two(a: &[bool], modulus: &[bool]) -> Option<Vec<bool>> {
+    let degree = |coeffs: &[bool]| coeffs.iter().rposition(|&bit| bit);
+    let mut r0: Vec<bool> = modulus.to_vec();
+    let mut r1: Vec<bool> = a.to_vec();
+    r1.resize(modulus.len(), false);
+    let mut t0: Vec<bool> = vec![false; modulus.len()];
+    let mut t1: Vec<bool> = vec![false; modulus.len()];
+    t1[0] = true;
+
+    while let Some(r1_degree) = degree(&r1) {
+        while let Some(r0_degree) = degree(&r0).filter(|&r0_degree| r0_degree >= r1_degree) {
+            let shift = r0_degree - r1_degree;
+            for i in 0..=r1_degree {
+                r0[i + shift] ^= r1[i];
+            }
+            for i in 0..modulus.len() - shift {
+                t0[i + shift] ^= t1[i];
+            }
+        }
+        std::mem::swap(&mut r0, &mut r1);
+        std::mem::swap(&mut t0, &mut t1);
+    }
+
+    if degree(&r0) != Some(0) {
+        return None;
+    }
+    t0.truncate(modulus.len() - 1);
+    Some(t0)
+}
+
+/// Return the coefficients of X^N ± 1, the polynomial the quotient Q of the ring reduces by.
+fn quotient_modulus<T: Ring, Q: Quotient<T>>(ring_degree: usize) -> Vec<T> {
+    let mut modulus: Vec<T> = vec![T::zero(); ring_degree + 1];
+    modulus[0] = T::zero() - Q::wrap(T::one());
+    modulus[ring_degree] = T::one();
+    modulus
+}
+
+/// Return the index of the highest nonzero coefficient, or `None` for the zero polynomial.
+fn degree_plain<T: Ring>(coeffs: &[T]) -> Option<usize> {
+    coeffs.iter().rposition(|coeff| *coeff != T::zero())
+}
+
+/// Divide a plain polynomial by a nonzero one of the given degree, over a field.
+///
+/// The quotient and remainder have as many coefficients as the dividend.
+fn divide_plain<T: Field>(a: &[T], b: &[T], b_degree: usize) -> (Vec<T>, Vec<T>) {
+    let lead_inverse = b[b_degree].clone().inverse();
+    let mut quotient: Vec<T> = vec![T::zero(); a.len()];
+    let mut remainder: Vec<T> = a.to_vec();
+    for k in (b_degree..a.len()).rev() {
+        if remainder[k] == T::zero() {
+            continue;
+        }
+        let coeff = remainder[k].mul_ref(&lead_inverse);
+        quotient[k - b_degree] = coeff.clone();
+        for i in 0..b_degree {
+            remainder[k - b_degree + i] = remainder[k - b_degree + i].clone() - coeff.mul_ref(&b[i]);
+        }
+        remainder[k] = T::zero();
+    }
+    (quotient, remainder)
+}
+
+/// Run the extended Euclidean algorithm on two plain polynomials with the same number of
+/// coefficients, over a field.
+///
+/// Returns a GCD g, which need not be monic, and s and t with s a + t b = g.
+fn extended_euclid_plain<T: Field>(a: Vec<T>, b: Vec<T>) -> (Vec<T>, Vec<T>, Vec<T>) {
+    let mut r0 = a;
+    let mut r1 = b;
+    let mut s0: Vec<T> = vec![T::zero(); r0.len()];
+    let mut s1: Vec<T> = vec![T::zero(); r0.len()];
+    let mut t0: Vec<T> = vec![T::zero(); r0.len()];
+    let mut t1: Vec<T> = vec![T::zero(); r0.len()];
+    s0[0] = T::one();
+    t1[0] = T::one();
+
+    while let Some(r1_degree) = degree_plain(&r1) {
+        let (quotient, remainder) = divide_plain(&r0, &r1, r1_degree);
+        let s2 = subtract_product(&s0, &quotient, &s1);
+        let t2 = subtract_product(&t0, &quotient, &t1);
+        r0 = std::mem::replace(&mut r1, remainder);
+        s0 = std::mem::replace(&mut s1, s2);
+        t0 = std::mem::replace(&mut t1, t2);
+    }
+    (r0, s0, t0)
+}
+
+/// Compute c - a * b for plain polynomials whose product fits in the coefficients of c, skipping
+/// the zero top coefficients so that each step of the extended Euclidean algorithm costs
+/// deg a * deg b.
+fn subtract_product<T: Ring>(c: &[T], a: &[T], b: &[T]) -> Vec<T> {
+    let mut diff: Vec<T> = c.to_vec();
+    if let (Some(a_degree), Some(b_degree)) = (degree_plain(a), degree_plain(b)) {
+        for (i, a_coeff) in a[..=a_degree].iter().enumerate() {
+            for (j, b_coeff) in b[..=b_degree].iter().enumerate() {
+                diff[i + j] = diff[i + j].clone() - a_coeff.mul_ref(b_coeff);
+            }
+        }
+    }
+    diff
+}
+
+/// Enum representing how a coefficient scaled from one modulus to another is rounded
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round to the nearest integer, with halves rounded up.
+    Nearest,
+    /// Round down.
+    Floor,
+    /// Round up with probability equal to the fractional part, so that the rounding is unbiased.
+    Randomized
+}
+
+impl Rounding {
+
+    /// Switch a coefficient x mod q to round(q'/q x) mod q'.
+    ///
+    /// # Arguments
+    /// * `coeff` - the coefficient x mod q
+    ///
+    /// # Output
+    /// * the coefficient mod q', rounded with this rounding mode
+    /// ```
+    pub fn switch<T: ModularRing, U: ModularRing>(self, coeff: T) -> U {
+        // x < q <= 2^64 and q' <= 2^64, so the product fits in 128 bits.
+        let from_modulus = T::modulus();
+        let scaled = coeff.residue() * U::modulus();
+        let rounded = match self {
+            Rounding::Nearest => (scaled + from_modulus / 2) / from_modulus,
+            Rounding::Floor => scaled / from_modulus,
+            Rounding::Randomized => {
+                let carry = rand::thread_rng().gen_range(0..from_modulus) < scaled % from_modulus;
+                scaled / from_modulus + carry as u128
+            }
+        };
+        U::from_residue(rounded % U::modulus())
+    }
+}
+
+impl<T: ModularRing, Q: Quotient<T>> Polynomial<T, Q> {
+
+    /// Switch a polynomial over Z/q to a polynomial over Z/q', by scaling each coefficient by q'/q
+    /// and rounding.
+    ///
+    /// The torus types count in multiples of 2^-32 or 2^-64, so switching to them maps x to x/q on
+    /// the torus.
+    ///
+    /// # Arguments
+    /// * `rounding` - how to round the scaled coefficients
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial over Z/q' with coefficients round(q'/q x)
+    /// ```
+    pub fn switch_modulus<U: ModularRing>(&self, rounding: Rounding) -> Polynomial<U, Q> where Q: Quotient<U> {
+        self.check_coeff_length();
+        Polynomial::from_coeffs(self.coeffs.iter().map(|coeff| rounding.switch(coeff.clone())).collect())
+    }
+
+    /// Map a polynomial over Z/q to the real torus, by dividing each coefficient by q.
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial with coefficients x/q in [0, 1)
+    /// ```
+    pub fn switch_to_real(&self) -> Polynomial<f64, Q> where Q: Quotient<f64> {
+        self.check_coeff_length();
+        let modulus = T::modulus() as f64;
+        Polynomial::from_coeffs(self.coeffs.iter().map(|coeff| coeff.clone().residue() as f64 / modulus).collect())
+    }
+}
+
+impl<T: Ring + Norm, Q: Quotient<T>> Polynomial<T, Q> {
+
+    /// Return the magnitudes the norms are taken over: the centered coefficients for modular
+    /// types, and the canonical embedding for complex polynomials.
+    pub(crate) fn embedding_norms(&self) -> Vec<f64> {
+        self.check_coeff_length();
+        T::embedding_norms(&self.coeffs, Q::NEGACYCLIC)
+    }
+
+    /// Return the infinity norm of a polynomial.
+    ///
+    /// # Output
+    /// * the largest magnitude, with coefficients mod q taken in (-q/2, q/2]
+    /// ```
+    pub fn norm_infinity(&self) -> f64 {
+        self.embedding_norms().into_iter().fold(0.0, f64::max)
+    }
+
+    /// Return the L1 norm of a polynomial.
+    ///
+    /// # Output
+    /// * the sum of the magnitudes, with coefficients mod q taken in (-q/2, q/2]
+    /// ```
+    pub fn norm_l1(&self) -> f64 {
+        self.embedding_norms().into_iter().sum()
+    }
+
+    /// Return the L2 norm of a polynomial.
+    ///
+    /// # Output
+    /// * the square root of the sum of the squared magnitudes, with coefficients mod q taken in
+    ///   (-q/2, q/2]
+    /// ```
+    pub fn norm_l2(&self) -> f64 {
+        self.embedding_norms().into_iter().map(|norm| norm * norm).sum::<f64>().sqrt()
+    }
+}
+
+impl<Q: Quotient<ModInteger32>> Polynomial<ModInteger32, Q> {
+
+    /// Return the inverse of a polynomial in the ring (Z/2^32)[x]/(X^N ± 1), by Hensel lifting its
+    /// inverse mod 2.
+    ///
+    /// # Output
+    /// * the inverse of the polynomial, or `None` if it is not invertible mod 2
+    /// ```
+    pub fn inverse(&self) -> Option<Polynomial<ModInteger32, Q>> {
+        self.check_coeff_length();
+        self.inverse_lifted(&quotient_modulus::<ModInteger32, Q>(self.ring_degree), 32,
+                            |coeff| coeff.value & 1 == 1, |a, b| a.multiply(b))
+    }
+
+    /// Return the inverse of a polynomial modulo an arbitrary monic polynomial f(x), by Hensel
+    /// lifting its inverse mod 2.
+    ///
+    /// # Arguments
+    /// * `ring` - quotient ring (Z/2^32)[x]/(f) of the polynomial
+    ///
+    /// # Output
+    /// * the inverse of the polynomial mod f, or `None` if it is not invertible mod 2
+    ///
+    /// # Panics if the ring degree of the polynomial does not match the degree of f.
+    /// ```
+    pub fn inverse_mod(&self, ring: &QuotientRing<ModInteger32>) -> Option<Polynomial<ModInteger32, Q>> {
+        self.check_coeff_length();
+        ring.check_ring_degree(self);
+        self.inverse_lifted(&ring.modulus, 32, |coeff| coeff.value & 1 == 1, |a, b| ring.multiply(a, b))
+    }
+}
+
+impl<Q: Quotient<ModInteger64>> Polynomial<ModInteger64, Q> {
+
+    /// Return the inverse of a polynomial in the ring (Z/2^64)[x]/(X^N ± 1), by Hensel lifting its
+    /// inverse mod 2.
+    ///
+    /// # Output
+    /// * the inverse of the polynomial, or `None` if it is not invertible mod 2
+    /// ```
+    pub fn inverse(&self) -> Option<Polynomial<ModInteger64, Q>> {
+        self.check_coeff_length();
+        self.inverse_lifted(&quotient_modulus::<ModInteger64, Q>(self.ring_degree), 64,
+                            |coeff| coeff.value & 1 == 1, |a, b| a.multiply(b))
+    }
+
+    /// Return the inverse of a polynomial modulo an arbitrary monic polynomial f(x), by Hensel
+    /// lifting its inverse mod 2.
+    ///
+    /// # Arguments
+    /// * `ring` - quotient ring (Z/2^64)[x]/(f) of the polynomial
+    ///
+    /// # Output
+    /// * the inverse of the polynomial mod f, or `None` if it is not invertible mod 2
+    ///
+    /// # Panics if the ring degree of the polynomial does not match the degree of f.
+    /// ```
+    pub fn inverse_mod(&self, ring: &QuotientRing<ModInteger64>) -> Option<Polynomial<ModInteger64, Q>> {
+        self.check_coeff_length();
+        ring.check_ring_degree(self);
+        self.inverse_lifted(&ring.modulus, 64, |coeff| coeff.value & 1 == 1, |a, b| ring.multiply(a, b))
+    }
+}
+
+impl<const M: u64, Q: Quotient<ModInteger<M>>> Polynomial<ModInteger<M>, Q> {
+
+    /// Multiply a polynomial to another polynomial in the ring, using the number-theoretic transform.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to multiply
+    /// * `ntt` - precomputed transform of size N
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the product of the two polynomials
+    /// ```
+    pub fn multiply_ntt(&self, other: &Polynomial<ModInteger<M>, Q>, ntt: &Ntt<M, Q>) -> Polynomial<ModInteger<M>, Q> {
+        ntt.multiply(self, other)
+    }
+}
+
+impl<T: Ring + FftCoefficient, Q: Quotient<T>> Polynomial<T, Q> {
+
+    /// Multiply a polynomial to another polynomial in the ring, using the fast Fourier transform.
+    ///
+    /// `multiply` does not switch to the transform on its own above some size, since its result
+    /// would then be rounded even where the schoolbook product is exact, such as for polynomials
+    /// with small integer coefficients.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to multiply
+    /// * `fft` - precomputed transform for degree N
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the product of the two polynomials
+    /// ```
+    pub fn multiply_fft(&self, other: &Polynomial<T, Q>, fft: &Fft) -> Polynomial<T, Q> {
+        fft.multiply(self, other)
+    }
+}
+
+impl<T: Ring, Q: Quotient<T>>
+    ops::Add<&Polynomial<T, Q>> for &Polynomial<T, Q> {
+    type Output = Polynomial<T, Q>;
+
+    fn add(self, other: &Polynomial<T, Q>) -> Polynomial<T, Q> {
+        self.combine(other, Polynomial::add)
+    }
+}
+
+forward_binop!(impl[T, Q] Add, add for Polynomial<T, Q>, Polynomial<T, Q> => Polynomial<T, Q>
+               where T: Ring, Q: Quotient<T>);
+
+impl<T: Ring, Q: Quotient<T>> ops::AddAssign for Polynomial<T, Q> {
+    fn add_assign(&mut self, other: Polynomial<T, Q>) {
+        if self.is_constant() || other.is_constant() {
+            *self = self.combine(&other, Polynomial::add);
+        } else {
+            Polynomial::add_assign(self, &other);
+        }
+    }
+}
+
+impl<T: Ring, Q: Quotient<T>> ops::Sub<&Polynomial<T, Q>> for &Polynomial<T, Q> {
+    type Output = Polynomial<T, Q>;
+
+    fn sub(self, other: &Polynomial<T, Q>) -> Polynomial<T, Q> {
+        self.combine(other, Polynomial::sub)
+    }
+}
+
+forward_binop!(impl[T, Q] Sub, sub for Polynomial<T, Q>, Polynomial<T, Q> => Polynomial<T, Q>
+               where T: Ring,
+               Q: Quotient<T>);
+
+impl<T: Ring, Q: Quotient<T>>
+    ops::Mul<&Polynomial<T, Q>> for &Polynomial<T, Q> {
+    type Output = Polynomial<T, Q>;
+
+    fn mul(self, other: &Polynomial<T, Q>) -> Polynomial<T, Q> {
+        self.combine(other, Polynomial::multiply)
+    }
+}
+
+forward_binop!(impl[T, Q] Mul, mul for Polynomial<T, Q>, Polynomial<T, Q> => Polynomial<T, Q>
+               where T: Ring, Q: Quotient<T>);
+
+impl<T: Ring, Q: Quotient<T>> ops::Neg for &Polynomial<T, Q> {
+    type Output = Polynomial<T, Q>;
+
+    fn neg(self) -> Polynomial<T, Q> {
+        if self.is_constant() {
+            return Polynomial::constant(T::zero() - self.coeffs[0].clone());
+        }
+        Polynomial::neg(self)
+    }
+}
+
+forward_neg!(impl[T, Q] for Polynomial<T, Q>
+             where T: Ring,
+             Q: Quotient<T>);
+
+impl<T: Ring, Q> Clone for Polynomial<T, Q> {
+    fn clone(&self) -> Self {
+        Polynomial {ring_degree: self.ring_degree,
+                    coeffs: self.coeffs.clone(),
+                    quotient: PhantomData }
+    }
+}
+
+impl<T: Ring, Q: Quotient<T>> PartialEq for Polynomial<T, Q> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_constant() == other.is_constant() {
+            return self.ring_degree == other.ring_degree && self.coeffs == other.coeffs;
+        }
+        self.bind(other.ring_degree).coeffs == other.bind(self.ring_degree).coeffs
+    }
+}
+
+impl<T: Ring, Q> fmt::Debug for Polynomial<T, Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Polynomial")
+         .field("ring_degree", &self.ring_degree)
+         .field("coeffs", &self.coeffs)
+         .finish()
+    }
+}
+
+impl<T: Ring, Q: Quotient<T>> Zero for Polynomial<T, Q> {
+    fn zero() -> Polynomial<T, Q> {
+        Polynomial::constant(T::zero())
+    }
+}
+
+impl<T: Ring, Q: Quotient<T>> One for Polynomial<T, Q> {
+    fn one() -> Polynomial<T, Q> {
+        Polynomial::constant(T::one())
+    }
+}
+
+impl<T: Ring, Q: Quotient<T>> Ring for Polynomial<T, Q> {}
+
+impl<T: CommutativeRing, Q: Quotient<T>> CommutativeRing for Polynomial<T, Q> {}
+
+impl<T: Ring, Q: Quotient<T>>
+    ops::Mul<T> for &Polynomial<T, Q> {
+    type Output = Polynomial<T, Q>;
+
+    fn mul(self, scalar: T) -> Polynomial<T, Q> {
+        self.multiply_by_scalar(scalar)
+    }
+}
+
+impl<T: Ring, Q: Quotient<T>>
+    ops::Mul<T> for Polynomial<T, Q> {
+    type Output = Polynomial<T, Q>;
+
+    fn mul(self, scalar: T) -> Polynomial<T, Q> {
+        self.multiply_by_scalar(scalar)
+    }
+}
+
+// A scalar on the left can only be implemented for concrete scalar types.
+scalar_mul_left!(impl[] f32);
+scalar_mul_left!(impl[] f64);
+scalar_mul_left!(impl[] Complex<f32>);
+scalar_mul_left!(impl[] Complex<f64>);
+scalar_mul_left!(impl[] ModInteger32);
+scalar_mul_left!(impl[] ModInteger64);
+scalar_mul_left!(impl[const M: u64,] ModInteger<M>);
+
+scalar_mul_left!(impl['a,] ContextModInteger<'a>);
+scalar_mul_left!(impl[] Torus32);
+scalar_mul_left!(impl[] Torus64);
+scalar_mul_left!(impl[] i8);
+scalar_mul_left!(impl[] i16);
+scalar_mul_left!(impl[] i32);
+scalar_mul_left!(impl[] i64);
+scalar_mul_left!(impl[] i128);
+scalar_mul_left!(impl[] u8);
+scalar_mul_left!(impl[] u16);
+scalar_mul_left!(impl[] u32);
+scalar_mul_left!(impl[] u64);
+scalar_mul_left!(impl[] u128);
+
+convert_polynomial!(From<f32> for f64);
+convert_polynomial!(From<f32> for Complex<f32>);
+convert_polynomial!(From<f64> for Complex<f64>);
+convert_polynomial!(From<Complex<f32>> for Complex<f64>);
+convert_polynomial!(From<f32> for ModInteger32);
+convert_polynomial!(From<f64> for ModInteger32);
+convert_polynomial!(From<f32> for ModInteger64);
+convert_polynomial!(From<f64> for ModInteger64);
+convert_polynomial!(From<ModInteger32> for f32);
+convert_polynomial!(From<ModInteger32> for f64);
+convert_polynomial!(From<ModInteger32> for Complex<f32>);
+convert_polynomial!(From<ModInteger32> for Complex<f64>);
+convert_polynomial!(From<ModInteger64> for f32);
+convert_polynomial!(From<ModInteger64> for f64);
+convert_polynomial!(From<ModInteger64> for Complex<f32>);
+convert_polynomial!(From<ModInteger64> for Complex<f64>);
+convert_polynomial!(From<ModInteger32> for ModInteger64);
+convert_polynomial!(From<ModInteger64> for ModInteger32);
+convert_polynomial!(TryFrom<Complex<f32>> for f32);
+convert_polynomial!(TryFrom<Complex<f64>> for f64);
+convert_polynomial!(TryFrom<Complex<f32>> for ModInteger32);
+convert_polynomial!(TryFrom<Complex<f64>> for ModInteger32);
+convert_polynomial!(TryFrom<Complex<f32>> for ModInteger64);
+convert_polynomial!(TryFrom<Complex<f64>> for ModInteger64);
+
 #[cfg(test)]
 mod tests;
\ No newline at end of file
@@ -3,38 +3,38 @@ use super::*;
 use rand::{distributions::Standard, prelude::Distribution, Rng};
 use crate::utilities::{check_vecs_almost_equal, generate_random_float_polynomial,
                        generate_random_complex_polynomial, generate_random_modint32_polynomial,
-                       generate_random_modint64_polynomial};
-use crate::ring::{Complex, ModInteger32, ModInteger64};
+                       generate_random_modint64_polynomial, generate_random_modint_polynomial,
+                       generate_random_context_modint_polynomial, generate_random_torus32_polynomial,
+                       generate_random_torus64_polynomial, generate_random_integer_polynomial};
+use crate::ring::{Complex, ModInteger32, ModInteger64, ModInteger, ModulusContext, Torus32, Torus64};
+use crate::quotient::{Cyclic, Negacyclic};
+use crate::error::RingMathError;
 use crate::traits::{Abs, Zero, One};
-use std::ops::{Sub, Div};
-use std::cmp::{PartialOrd, PartialEq};
-use std::fmt::Debug;
+use std::ops::{AddAssign, Div};
+use std::cmp::PartialOrd;
+use std::marker::PhantomData;
 
 const MAX_TEST_DEGREE: usize = 2048;
 const F32_ADD_ERROR: f64 = 0.000001;
 const F32_MULTIPLY_ERROR: f64 = 0.01;
 const F64_ERROR: f64 = 0.0000000001;
+const F64_EVALUATE_ERROR: f64 = 0.000001;
 
 #[test]
 #[should_panic(expected = "Ring degree should be equal to vector length. 10 != 9")]
 fn test_check_coeff_length() {
-    let poly = Polynomial {
+    let poly: Polynomial<f64> = Polynomial {
         ring_degree: 10,
         coeffs: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0],
+        quotient: PhantomData,
     };
     poly.check_coeff_length();
 }
 
 #[test]
 fn test_add_known_answer() {
-    let poly1 = Polynomial {
-        ring_degree: 10,
-        coeffs: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0],
-    };
-    let poly2 = Polynomial {
-        ring_degree: 10,
-        coeffs: vec![4.0, 5.0, 4.0, 8.0, 9.0, 1.0, 1.0, 1.0, 1.0, 3.0],
-    };
+    let poly1: Polynomial<f64> = Polynomial::from_coeffs(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]);
+    let poly2: Polynomial<f64> = Polynomial::from_coeffs(vec![4.0, 5.0, 4.0, 8.0, 9.0, 1.0, 1.0, 1.0, 1.0, 3.0]);
 
     let sum = poly1.add(&poly2);
 
@@ -43,12 +43,12 @@ fn test_add_known_answer() {
 }
 
 fn test_add_different_ring_degrees_fail<T>() 
-        where T: Add<Output = T> + Mul<Output = T> + Copy + Zero<T> + AddAssign{
+        where T: Ring{
     let poly1: Polynomial<T> = Polynomial::new(10);
     let poly2: Polynomial<T> = Polynomial::new(14);
 
     // Should panic because the polynomials have different ring degrees.
-    poly1.add(&poly2);
+    let _ = poly1.add(&poly2);
 }
 
 #[test]
@@ -88,9 +88,7 @@ fn test_add_different_ring_degrees_fail_modint64() {
 }
 
 fn test_add_commutative_float<T>(error: f64)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd  + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     // Generate random polynomials.
     let mut rng = rand::thread_rng();
     let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
@@ -116,9 +114,7 @@ fn test_add_commutative_float_f64() {
 }
 
 fn test_add_commutative_complex<T>(error: f64)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd  + std::fmt::Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     // Generate random polynomials.
     let mut rng = rand::thread_rng();
     let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
@@ -176,9 +172,7 @@ fn test_add_commutative_modint64() {
 }
 
 fn test_add_associative_float<T>(error: f64)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     // Generate random polynomials.
     let mut rng = rand::thread_rng();
     let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
@@ -208,9 +202,7 @@ fn test_add_associative_float_f64() {
 }
 
 fn test_add_associative_complex<T>(error: f64)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     // Generate random polynomials.
     let mut rng = rand::thread_rng();
     let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
@@ -281,14 +273,8 @@ fn test_add_associative_modint64() {
 
 #[test]
 fn test_multiply_known_answer() {
-    let poly1 = Polynomial {
-        ring_degree: 10,
-        coeffs: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0],
-    };
-    let poly2 = Polynomial {
-        ring_degree: 10,
-        coeffs: vec![4.0, 5.0, 4.0, 8.0, 9.0, 1.0, 1.0, 1.0, 1.0, 3.0],
-    };
+    let poly1: Polynomial<f64> = Polynomial::from_coeffs(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]);
+    let poly2: Polynomial<f64> = Polynomial::from_coeffs(vec![4.0, 5.0, 4.0, 8.0, 9.0, 1.0, 1.0, 1.0, 1.0, 3.0]);
 
     let sum = poly1.multiply(&poly2);
 
@@ -298,7 +284,7 @@ fn test_multiply_known_answer() {
 }
 
 fn test_multiply_different_ring_degrees_fail<T>() 
-        where T: Add<Output = T> + Mul<Output = T> + Copy + Zero<T> + AddAssign{
+        where T: Ring{
     let poly1: Polynomial<T> = Polynomial::new(10);
     let poly2: Polynomial<T> = Polynomial::new(14);
 
@@ -343,9 +329,7 @@ fn test_multiply_different_ring_degrees_fail_modint64() {
 }
 
 fn test_multiply_commutative_float<T>(error: f64)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     // Generate random polynomials.
     let mut rng = rand::thread_rng();
     let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
@@ -371,9 +355,7 @@ fn test_multiply_commutative_float_f64() {
 }
 
 fn test_multiply_commutative_complex<T>(error: f64)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     // Generate random polynomials.
     let mut rng = rand::thread_rng();
     let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
@@ -431,9 +413,7 @@ fn test_multiply_commutative_modint64() {
 }
 
 fn test_multiply_associative_float<T>(error: f64)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     // Generate random polynomials.
     let mut rng = rand::thread_rng();
     let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
@@ -463,9 +443,7 @@ fn test_multiply_associative_float_f64() {
 }
 
 fn test_multiply_associative_complex<T>(error: f64)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     // Generate random polynomials.
     let mut rng = rand::thread_rng();
     let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
@@ -535,9 +513,7 @@ fn test_multiply_associative_modint64() {
 }
 
 fn test_distributive_float<T>(error: f64)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
 
     // Generate random polynomials.
     let mut rng = rand::thread_rng();
@@ -569,9 +545,7 @@ fn test_distributive_float_f64() {
 }
 
 fn test_distributive_complex<T>(error: f64)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
 
     // Generate random polynomials.
     let mut rng = rand::thread_rng();
@@ -646,10 +620,7 @@ fn test_distributive_modint64() {
 
 #[test]
 fn test_multiply_by_x_known_answer() {
-    let poly = Polynomial {
-        ring_degree: 10,
-        coeffs: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0],
-    };
+    let poly: Polynomial<f64> = Polynomial::from_coeffs(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]);
 
     let prod = poly.multiply_by_x();
 
@@ -658,9 +629,7 @@ fn test_multiply_by_x_known_answer() {
 }
 
 fn test_multiply_by_x_compare_multiply_float<T>()
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     // Generate random polynomials.
     let mut rng = rand::thread_rng();
     let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
@@ -668,10 +637,7 @@ fn test_multiply_by_x_compare_multiply_float<T>()
 
     let mut x_vec: Vec<T> = vec![T::zero(); ring_degree];
     x_vec[1] = T::one();
-    let x_poly = Polynomial {
-        ring_degree,
-        coeffs: x_vec,
-    };
+    let x_poly: Polynomial<T> = Polynomial::from_coeffs(x_vec);
 
     let prod1 = poly.multiply_by_x();
     let prod2 = poly.multiply(&x_poly);
@@ -691,9 +657,7 @@ fn test_multiply_by_x_compare_multiply_float_f64() {
 }
 
 fn test_multiply_by_x_compare_multiply_complex<T>()
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
     // Generate random polynomials.
     let mut rng = rand::thread_rng();
     let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
@@ -701,10 +665,7 @@ fn test_multiply_by_x_compare_multiply_complex<T>()
 
     let mut x_vec: Vec<Complex<T>> = vec![Complex::<T>::zero(); ring_degree];
     x_vec[1] = Complex::<T>::one();
-    let x_poly = Polynomial {
-        ring_degree,
-        coeffs: x_vec,
-    };
+    let x_poly: Polynomial<Complex<T>> = Polynomial::from_coeffs(x_vec);
 
     let prod1 = poly.multiply_by_x();
     let prod2 = poly.multiply(&x_poly);
@@ -722,3 +683,1821 @@ fn test_multiply_by_x_compare_multiply_complex_f32() {
 fn test_multiply_by_x_compare_multiply_complex_f64() {
     test_multiply_by_x_compare_multiply_complex::<f64>();
 }
+
+
+#[test]
+fn test_multiply_negacyclic_known_answer() {
+    let poly1: Polynomial<f64, Negacyclic> = Polynomial::from_coeffs(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]);
+    let poly2: Polynomial<f64, Negacyclic> = Polynomial::from_coeffs(vec![4.0, 5.0, 4.0, 8.0, 9.0, 1.0, 1.0, 1.0, 1.0, 3.0]);
+
+    let prod = poly1.multiply(&poly2);
+
+    assert_eq!(prod.ring_degree, poly1.ring_degree);
+    assert_eq!(prod.coeffs, vec![-233.0, -202.0, -173.0, -88.0, 25.0, 60.0, 97.0, 136.0,
+                                 177.0, 244.0]);
+}
+
+#[test]
+fn test_multiply_by_x_negacyclic_known_answer() {
+    let poly: Polynomial<f64, Negacyclic> = Polynomial::from_coeffs(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]);
+
+    let prod = poly.multiply_by_x();
+
+    assert_eq!(prod.ring_degree, poly.ring_degree);
+    assert_eq!(prod.coeffs, vec![-10.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+}
+
+// The negacyclic tests use coefficients in {0, 1}. Wrapped terms are subtracted, so random float
+// coefficients can cancel to almost 0 and blow up the relative error. Small integer coefficients
+// keep every product exact.
+fn generate_random_binary_coeffs<T>(size: usize) -> Vec<T>
+        where T: Ring {
+    let mut rng = rand::thread_rng();
+    let mut rand_vec: Vec<T> = Vec::new();
+    for _ in 0..size {
+        rand_vec.push(if rng.gen::<bool>() { T::one() } else { T::zero() });
+    }
+    rand_vec
+}
+
+fn generate_random_binary_complex_coeffs<T>(size: usize) -> Vec<Complex<T>>
+        where T: Ring + Abs {
+    let reals: Vec<T> = generate_random_binary_coeffs(size);
+    let imags: Vec<T> = generate_random_binary_coeffs(size);
+    reals.into_iter().zip(imags).map(|(real, imag)| Complex{real, imag}).collect()
+}
+
+fn test_multiply_commutative_negacyclic<T>(random_coeffs: fn(usize) -> Vec<T>, error: f64)
+        where T: Ring + Abs {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<T, Negacyclic> = Polynomial::from_coeffs(random_coeffs(ring_degree));
+    let poly2: Polynomial<T, Negacyclic> = Polynomial::from_coeffs(random_coeffs(ring_degree));
+
+    // Test that p1 * p2 = p2 * p1.
+    let prod1 = poly1.multiply(&poly2);
+    let prod2 = poly2.multiply(&poly1);
+
+    assert_eq!(prod1.ring_degree, prod2.ring_degree);
+    assert!(check_vecs_almost_equal(&prod1.coeffs, &prod2.coeffs, error));
+}
+
+#[test]
+fn test_multiply_commutative_negacyclic_f32() {
+    test_multiply_commutative_negacyclic::<f32>(generate_random_binary_coeffs, F32_MULTIPLY_ERROR);
+}
+
+#[test]
+fn test_multiply_commutative_negacyclic_f64() {
+    test_multiply_commutative_negacyclic::<f64>(generate_random_binary_coeffs, F64_ERROR);
+}
+
+#[test]
+fn test_multiply_commutative_negacyclic_complex_f32() {
+    test_multiply_commutative_negacyclic::<Complex<f32>>(generate_random_binary_complex_coeffs,
+                                                         F32_MULTIPLY_ERROR);
+}
+
+#[test]
+fn test_multiply_commutative_negacyclic_complex_f64() {
+    test_multiply_commutative_negacyclic::<Complex<f64>>(generate_random_binary_complex_coeffs,
+                                                         F64_ERROR);
+}
+
+fn test_multiply_associative_negacyclic<T>(random_coeffs: fn(usize) -> Vec<T>, error: f64)
+        where T: Ring + Abs {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<T, Negacyclic> = Polynomial::from_coeffs(random_coeffs(ring_degree));
+    let poly2: Polynomial<T, Negacyclic> = Polynomial::from_coeffs(random_coeffs(ring_degree));
+    let poly3: Polynomial<T, Negacyclic> = Polynomial::from_coeffs(random_coeffs(ring_degree));
+
+    // Test that (p1 * p2) * p3 = p1 * (p2 * p3).
+    let prod1 = poly1.multiply(&poly2);
+    let total_prod1 = prod1.multiply(&poly3);
+
+    let prod2 = poly2.multiply(&poly3);
+    let total_prod2 = poly1.multiply(&prod2);
+
+    assert_eq!(total_prod1.ring_degree, total_prod2.ring_degree);
+    assert!(check_vecs_almost_equal(&total_prod1.coeffs, &total_prod2.coeffs, error));
+}
+
+#[test]
+fn test_multiply_associative_negacyclic_f32() {
+    test_multiply_associative_negacyclic::<f32>(generate_random_binary_coeffs, F32_MULTIPLY_ERROR);
+}
+
+#[test]
+fn test_multiply_associative_negacyclic_f64() {
+    test_multiply_associative_negacyclic::<f64>(generate_random_binary_coeffs, F64_ERROR);
+}
+
+#[test]
+fn test_multiply_associative_negacyclic_complex_f32() {
+    test_multiply_associative_negacyclic::<Complex<f32>>(generate_random_binary_complex_coeffs,
+                                                         F32_MULTIPLY_ERROR);
+}
+
+#[test]
+fn test_multiply_associative_negacyclic_complex_f64() {
+    test_multiply_associative_negacyclic::<Complex<f64>>(generate_random_binary_complex_coeffs,
+                                                         F64_ERROR);
+}
+
+fn test_distributive_negacyclic<T>(random_coeffs: fn(usize) -> Vec<T>, error: f64)
+        where T: Ring + Abs {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<T, Negacyclic> = Polynomial::from_coeffs(random_coeffs(ring_degree));
+    let poly2: Polynomial<T, Negacyclic> = Polynomial::from_coeffs(random_coeffs(ring_degree));
+    let poly3: Polynomial<T, Negacyclic> = Polynomial::from_coeffs(random_coeffs(ring_degree));
+
+    // Test that p1 * (p2 + p3) = p1 * p2 + p1 * p3
+    let sum1 = poly2.add(&poly3);
+    let total1 = poly1.multiply(&sum1);
+
+    let prod1 = poly1.multiply(&poly2);
+    let prod2 = poly1.multiply(&poly3);
+    let total2 = prod1.add(&prod2);
+
+    assert_eq!(total1.ring_degree, total2.ring_degree);
+    assert!(check_vecs_almost_equal(&total1.coeffs, &total2.coeffs, error));
+}
+
+#[test]
+fn test_distributive_negacyclic_f32() {
+    test_distributive_negacyclic::<f32>(generate_random_binary_coeffs, F32_MULTIPLY_ERROR);
+}
+
+#[test]
+fn test_distributive_negacyclic_f64() {
+    test_distributive_negacyclic::<f64>(generate_random_binary_coeffs, F64_ERROR);
+}
+
+#[test]
+fn test_distributive_negacyclic_complex_f32() {
+    test_distributive_negacyclic::<Complex<f32>>(generate_random_binary_complex_coeffs,
+                                                 F32_MULTIPLY_ERROR);
+}
+
+#[test]
+fn test_distributive_negacyclic_complex_f64() {
+    test_distributive_negacyclic::<Complex<f64>>(generate_random_binary_complex_coeffs, F64_ERROR);
+}
+
+fn test_multiply_by_x_compare_multiply_negacyclic<T>(random_coeffs: fn(usize) -> Vec<T>)
+        where T: Ring {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(2..=MAX_TEST_DEGREE);
+    let poly: Polynomial<T, Negacyclic> = Polynomial::from_coeffs(random_coeffs(ring_degree));
+
+    let mut x_vec: Vec<T> = vec![T::zero(); ring_degree];
+    x_vec[1] = T::one();
+    let x_poly: Polynomial<T, Negacyclic> = Polynomial::from_coeffs(x_vec);
+
+    let prod1 = poly.multiply_by_x();
+    let prod2 = poly.multiply(&x_poly);
+
+    assert_eq!(prod1.ring_degree, prod2.ring_degree);
+    assert_eq!(prod1.coeffs, prod2.coeffs);
+}
+
+#[test]
+fn test_multiply_by_x_compare_multiply_negacyclic_f32() {
+    test_multiply_by_x_compare_multiply_negacyclic::<f32>(generate_random_binary_coeffs);
+}
+
+#[test]
+fn test_multiply_by_x_compare_multiply_negacyclic_f64() {
+    test_multiply_by_x_compare_multiply_negacyclic::<f64>(generate_random_binary_coeffs);
+}
+
+#[test]
+fn test_multiply_by_x_compare_multiply_negacyclic_complex_f32() {
+    test_multiply_by_x_compare_multiply_negacyclic::<Complex<f32>>(generate_random_binary_complex_coeffs);
+}
+
+#[test]
+fn test_multiply_by_x_compare_multiply_negacyclic_complex_f64() {
+    test_multiply_by_x_compare_multiply_negacyclic::<Complex<f64>>(generate_random_binary_complex_coeffs);
+}
+
+const FALCON_Q: u64 = 12289;
+const GOLDILOCKS_Q: u64 = 0xffff_ffff_0000_0001;
+
+fn generate_random_modint_coeffs<const Q: u64>(size: usize) -> Vec<ModInteger<Q>> {
+    generate_random_modint_polynomial::<Q>(size).coeffs
+}
+
+fn test_multiply_commutative_modint<const Q: u64>() {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<ModInteger<Q>> = generate_random_modint_polynomial(ring_degree);
+    let poly2: Polynomial<ModInteger<Q>> = generate_random_modint_polynomial(ring_degree);
+
+    // Test that p1 * p2 = p2 * p1.
+    let prod1 = poly1.multiply(&poly2);
+    let prod2 = poly2.multiply(&poly1);
+
+    assert_eq!(prod1.ring_degree, prod2.ring_degree);
+    assert_eq!(prod1.coeffs, prod2.coeffs);
+}
+
+#[test]
+fn test_multiply_commutative_modint_falcon() {
+    test_multiply_commutative_modint::<FALCON_Q>();
+}
+
+#[test]
+fn test_multiply_commutative_modint_goldilocks() {
+    test_multiply_commutative_modint::<GOLDILOCKS_Q>();
+}
+
+fn test_multiply_associative_modint<const Q: u64>() {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<ModInteger<Q>> = generate_random_modint_polynomial(ring_degree);
+    let poly2: Polynomial<ModInteger<Q>> = generate_random_modint_polynomial(ring_degree);
+    let poly3: Polynomial<ModInteger<Q>> = generate_random_modint_polynomial(ring_degree);
+
+    // Test that (p1 * p2) * p3 = p1 * (p2 * p3).
+    let prod1 = poly1.multiply(&poly2);
+    let total_prod1 = prod1.multiply(&poly3);
+
+    let prod2 = poly2.multiply(&poly3);
+    let total_prod2 = poly1.multiply(&prod2);
+
+    assert_eq!(total_prod1.ring_degree, total_prod2.ring_degree);
+    assert_eq!(total_prod1.coeffs, total_prod2.coeffs);
+}
+
+#[test]
+fn test_multiply_associative_modint_falcon() {
+    test_multiply_associative_modint::<FALCON_Q>();
+}
+
+#[test]
+fn test_multiply_associative_modint_goldilocks() {
+    test_multiply_associative_modint::<GOLDILOCKS_Q>();
+}
+
+fn test_distributive_modint<const Q: u64>() {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<ModInteger<Q>> = generate_random_modint_polynomial(ring_degree);
+    let poly2: Polynomial<ModInteger<Q>> = generate_random_modint_polynomial(ring_degree);
+    let poly3: Polynomial<ModInteger<Q>> = generate_random_modint_polynomial(ring_degree);
+
+    // Test that p1 * (p2 + p3) = p1 * p2 + p1 * p3
+    let sum1 = poly2.add(&poly3);
+    let total1 = poly1.multiply(&sum1);
+
+    let prod1 = poly1.multiply(&poly2);
+    let prod2 = poly1.multiply(&poly3);
+    let total2 = prod1.add(&prod2);
+
+    assert_eq!(total1.ring_degree, total2.ring_degree);
+    assert_eq!(total1.coeffs, total2.coeffs);
+}
+
+#[test]
+fn test_distributive_modint_falcon() {
+    test_distributive_modint::<FALCON_Q>();
+}
+
+#[test]
+fn test_distributive_modint_goldilocks() {
+    test_distributive_modint::<GOLDILOCKS_Q>();
+}
+
+#[test]
+fn test_multiply_commutative_negacyclic_modint() {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<ModInteger<FALCON_Q>, Negacyclic> = Polynomial::from_coeffs(generate_random_modint_coeffs(ring_degree));
+    let poly2: Polynomial<ModInteger<FALCON_Q>, Negacyclic> = Polynomial::from_coeffs(generate_random_modint_coeffs(ring_degree));
+
+    // Test that p1 * p2 = p2 * p1.
+    let prod1 = poly1.multiply(&poly2);
+    let prod2 = poly2.multiply(&poly1);
+
+    assert_eq!(prod1.ring_degree, prod2.ring_degree);
+    assert_eq!(prod1.coeffs, prod2.coeffs);
+}
+
+#[test]
+fn test_multiply_associative_negacyclic_modint() {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<ModInteger<FALCON_Q>, Negacyclic> = Polynomial::from_coeffs(generate_random_modint_coeffs(ring_degree));
+    let poly2: Polynomial<ModInteger<FALCON_Q>, Negacyclic> = Polynomial::from_coeffs(generate_random_modint_coeffs(ring_degree));
+    let poly3: Polynomial<ModInteger<FALCON_Q>, Negacyclic> = Polynomial::from_coeffs(generate_random_modint_coeffs(ring_degree));
+
+    // Test that (p1 * p2) * p3 = p1 * (p2 * p3).
+    let total_prod1 = poly1.multiply(&poly2).multiply(&poly3);
+    let total_prod2 = poly1.multiply(&poly2.multiply(&poly3));
+
+    assert_eq!(total_prod1.ring_degree, total_prod2.ring_degree);
+    assert_eq!(total_prod1.coeffs, total_prod2.coeffs);
+}
+
+#[test]
+fn test_distributive_negacyclic_modint() {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<ModInteger<FALCON_Q>, Negacyclic> = Polynomial::from_coeffs(generate_random_modint_coeffs(ring_degree));
+    let poly2: Polynomial<ModInteger<FALCON_Q>, Negacyclic> = Polynomial::from_coeffs(generate_random_modint_coeffs(ring_degree));
+    let poly3: Polynomial<ModInteger<FALCON_Q>, Negacyclic> = Polynomial::from_coeffs(generate_random_modint_coeffs(ring_degree));
+
+    // Test that p1 * (p2 + p3) = p1 * p2 + p1 * p3
+    let total1 = poly1.multiply(&poly2.add(&poly3));
+    let total2 = poly1.multiply(&poly2).add(&poly1.multiply(&poly3));
+
+    assert_eq!(total1.ring_degree, total2.ring_degree);
+    assert_eq!(total1.coeffs, total2.coeffs);
+}
+
+#[test]
+fn test_context_polynomial_known_answer() {
+    let context = ModulusContext::new(17);
+    let poly1: Polynomial<_, Negacyclic> = context.polynomial(&[1, 2, 3, 4]);
+    let poly2: Polynomial<_, Negacyclic> = context.polynomial(&[5, 6, 7, 8]);
+
+    // (1 + 2x + 3x^2 + 4x^3)(5 + 6x + 7x^2 + 8x^3) = -56 - 36x + 2x^2 + 60x^3 mod x^4 + 1
+    let prod = poly1.multiply(&poly2);
+
+    assert_eq!(prod.coeffs, context.polynomial::<Negacyclic>(&[12, 15, 2, 9]).coeffs);
+}
+
+#[test]
+fn test_multiply_compare_context_modint() {
+    // Generate random polynomials.
+    let context = ModulusContext::new(FALCON_Q);
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1 = generate_random_context_modint_polynomial(&context, ring_degree);
+    let poly2 = generate_random_context_modint_polynomial(&context, ring_degree);
+
+    // Test that the product matches the one with a compile-time modulus.
+    let values1: Vec<u64> = poly1.coeffs.iter().map(|coeff| coeff.value()).collect();
+    let values2: Vec<u64> = poly2.coeffs.iter().map(|coeff| coeff.value()).collect();
+    let modint_poly1: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(values1.into_iter().map(ModInteger::new).collect());
+    let modint_poly2: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(values2.into_iter().map(ModInteger::new).collect());
+
+    let prod1: Vec<u64> = poly1.multiply(&poly2).coeffs.iter().map(|coeff| coeff.value()).collect();
+    let prod2: Vec<u64> = modint_poly1.multiply(&modint_poly2).coeffs.iter().map(|coeff| coeff.value()).collect();
+
+    assert_eq!(prod1, prod2);
+}
+
+#[test]
+#[should_panic(expected = "Modulus contexts should be equal. 12289 != 3329")]
+fn test_multiply_mixed_contexts_fail() {
+    let context1 = ModulusContext::new(FALCON_Q);
+    let context2 = ModulusContext::new(3329);
+    let poly1 = generate_random_context_modint_polynomial(&context1, 10);
+    let poly2 = generate_random_context_modint_polynomial(&context2, 10);
+
+    // Should panic because the polynomials belong to different contexts.
+    poly1.multiply(&poly2);
+}
+
+#[test]
+fn test_multiply_torus32_known_answer() {
+    let poly1: Polynomial<Torus32, Negacyclic> = Polynomial::from_coeffs(vec![Torus32{value: u32::MAX}, Torus32{value: 1 << 31}]);
+    let poly2: Polynomial<Torus32, Negacyclic> = Polynomial::from_coeffs(vec![Torus32{value: 3}, Torus32{value: 2}]);
+
+    // (-1 + 2^31 x)(3 + 2x) = -3 + (3 * 2^31 - 2) x - 2^32 x^2 = -3 + (2^31 - 2) x mod x^2 + 1, 2^32
+    let prod = poly1.multiply(&poly2);
+
+    assert_eq!(prod.coeffs, vec![Torus32{value: u32::MAX - 2}, Torus32{value: (1 << 31) - 2}]);
+}
+
+fn test_multiply_commutative_torus<T, Q: Quotient<T>>(generate_random_polynomial: fn(usize) -> Polynomial<T>)
+        where T: Ring {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_polynomial(ring_degree).coeffs);
+    let poly2: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_polynomial(ring_degree).coeffs);
+
+    // Test that p1 * p2 = p2 * p1.
+    let prod1 = poly1.multiply(&poly2);
+    let prod2 = poly2.multiply(&poly1);
+
+    assert_eq!(prod1.ring_degree, prod2.ring_degree);
+    assert_eq!(prod1.coeffs, prod2.coeffs);
+}
+
+fn test_multiply_associative_torus<T, Q: Quotient<T>>(generate_random_polynomial: fn(usize) -> Polynomial<T>)
+        where T: Ring {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_polynomial(ring_degree).coeffs);
+    let poly2: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_polynomial(ring_degree).coeffs);
+    let poly3: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_polynomial(ring_degree).coeffs);
+
+    // Test that (p1 * p2) * p3 = p1 * (p2 * p3).
+    let total_prod1 = poly1.multiply(&poly2).multiply(&poly3);
+    let total_prod2 = poly1.multiply(&poly2.multiply(&poly3));
+
+    assert_eq!(total_prod1.ring_degree, total_prod2.ring_degree);
+    assert_eq!(total_prod1.coeffs, total_prod2.coeffs);
+}
+
+fn test_distributive_torus<T, Q: Quotient<T>>(generate_random_polynomial: fn(usize) -> Polynomial<T>)
+        where T: Ring {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_polynomial(ring_degree).coeffs);
+    let poly2: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_polynomial(ring_degree).coeffs);
+    let poly3: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_polynomial(ring_degree).coeffs);
+
+    // Test that p1 * (p2 + p3) = p1 * p2 + p1 * p3
+    let total1 = poly1.multiply(&poly2.add(&poly3));
+    let total2 = poly1.multiply(&poly2).add(&poly1.multiply(&poly3));
+
+    assert_eq!(total1.ring_degree, total2.ring_degree);
+    assert_eq!(total1.coeffs, total2.coeffs);
+}
+
+#[test]
+fn test_multiply_commutative_torus32_cyclic() {
+    test_multiply_commutative_torus::<Torus32, Cyclic>(generate_random_torus32_polynomial);
+}
+
+#[test]
+fn test_multiply_commutative_torus32_negacyclic() {
+    test_multiply_commutative_torus::<Torus32, Negacyclic>(generate_random_torus32_polynomial);
+}
+
+#[test]
+fn test_multiply_commutative_torus64_cyclic() {
+    test_multiply_commutative_torus::<Torus64, Cyclic>(generate_random_torus64_polynomial);
+}
+
+#[test]
+fn test_multiply_commutative_torus64_negacyclic() {
+    test_multiply_commutative_torus::<Torus64, Negacyclic>(generate_random_torus64_polynomial);
+}
+
+#[test]
+fn test_multiply_associative_torus32_cyclic() {
+    test_multiply_associative_torus::<Torus32, Cyclic>(generate_random_torus32_polynomial);
+}
+
+#[test]
+fn test_multiply_associative_torus32_negacyclic() {
+    test_multiply_associative_torus::<Torus32, Negacyclic>(generate_random_torus32_polynomial);
+}
+
+#[test]
+fn test_multiply_associative_torus64_cyclic() {
+    test_multiply_associative_torus::<Torus64, Cyclic>(generate_random_torus64_polynomial);
+}
+
+#[test]
+fn test_multiply_associative_torus64_negacyclic() {
+    test_multiply_associative_torus::<Torus64, Negacyclic>(generate_random_torus64_polynomial);
+}
+
+#[test]
+fn test_distributive_torus32_cyclic() {
+    test_distributive_torus::<Torus32, Cyclic>(generate_random_torus32_polynomial);
+}
+
+#[test]
+fn test_distributive_torus32_negacyclic() {
+    test_distributive_torus::<Torus32, Negacyclic>(generate_random_torus32_polynomial);
+}
+
+#[test]
+fn test_distributive_torus64_cyclic() {
+    test_distributive_torus::<Torus64, Cyclic>(generate_random_torus64_polynomial);
+}
+
+#[test]
+fn test_distributive_torus64_negacyclic() {
+    test_distributive_torus::<Torus64, Negacyclic>(generate_random_torus64_polynomial);
+}
+
+#[test]
+fn test_sub_known_answer() {
+    let poly1: Polynomial<f64> = Polynomial::from_coeffs(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]);
+    let poly2: Polynomial<f64> = Polynomial::from_coeffs(vec![4.0, 5.0, 4.0, 8.0, 9.0, 1.0, 1.0, 1.0, 1.0, 3.0]);
+
+    let diff = poly1.sub(&poly2);
+
+    assert_eq!(diff.ring_degree, poly1.ring_degree);
+    assert_eq!(diff.coeffs, vec![-3.0, -3.0, -1.0, -4.0, -4.0, 5.0, 6.0, 7.0, 8.0, 7.0]);
+    assert_eq!(poly1.neg().coeffs, vec![-1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, -8.0, -9.0, -10.0]);
+}
+
+#[test]
+#[should_panic(expected = "Ring degrees should be equal. 10 != 14")]
+fn test_sub_different_ring_degrees_fail() {
+    let poly1: Polynomial<ModInteger32> = Polynomial::new(10);
+    let poly2: Polynomial<ModInteger32> = Polynomial::new(14);
+
+    // Should panic because the polynomials have different ring degrees.
+    let _ = poly1.sub(&poly2);
+}
+
+fn test_sub_anticommutative<T>(generate_random_polynomial: fn(usize) -> Polynomial<T>)
+        where T: Ring {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<T> = generate_random_polynomial(ring_degree);
+    let poly2: Polynomial<T> = generate_random_polynomial(ring_degree);
+
+    // Test that p1 - p2 = -(p2 - p1).
+    let diff1 = poly1.sub(&poly2);
+    let diff2 = poly2.sub(&poly1).neg();
+
+    assert_eq!(diff1.ring_degree, diff2.ring_degree);
+    assert_eq!(diff1.coeffs, diff2.coeffs);
+}
+
+#[test]
+fn test_sub_anticommutative_all_types() {
+    test_sub_anticommutative(generate_random_float_polynomial::<f32>);
+    test_sub_anticommutative(generate_random_float_polynomial::<f64>);
+    test_sub_anticommutative(generate_random_complex_polynomial::<f32>);
+    test_sub_anticommutative(generate_random_complex_polynomial::<f64>);
+    test_sub_anticommutative(generate_random_modint32_polynomial);
+    test_sub_anticommutative(generate_random_modint64_polynomial);
+    test_sub_anticommutative(generate_random_modint_polynomial::<FALCON_Q>);
+    test_sub_anticommutative(generate_random_torus32_polynomial);
+    test_sub_anticommutative(generate_random_torus64_polynomial);
+}
+
+fn test_neg_additive_inverse<T>(generate_random_polynomial: fn(usize) -> Polynomial<T>)
+        where T: Ring {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly: Polynomial<T> = generate_random_polynomial(ring_degree);
+
+    // Test that p + (-p) = 0.
+    let sum = poly.add(&poly.neg());
+
+    assert_eq!(sum.coeffs, Polynomial::<T>::new(ring_degree).coeffs);
+}
+
+#[test]
+fn test_neg_additive_inverse_all_types() {
+    test_neg_additive_inverse(generate_random_float_polynomial::<f32>);
+    test_neg_additive_inverse(generate_random_float_polynomial::<f64>);
+    test_neg_additive_inverse(generate_random_complex_polynomial::<f32>);
+    test_neg_additive_inverse(generate_random_complex_polynomial::<f64>);
+    test_neg_additive_inverse(generate_random_modint32_polynomial);
+    test_neg_additive_inverse(generate_random_modint64_polynomial);
+    test_neg_additive_inverse(generate_random_modint_polynomial::<FALCON_Q>);
+    test_neg_additive_inverse(generate_random_torus32_polynomial);
+    test_neg_additive_inverse(generate_random_torus64_polynomial);
+}
+
+fn test_sub_inverts_add<T>(generate_random_polynomial: fn(usize) -> Polynomial<T>)
+        where T: Ring {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<T> = generate_random_polynomial(ring_degree);
+    let poly2: Polynomial<T> = generate_random_polynomial(ring_degree);
+
+    // Test that (p1 + p2) - p2 = p1.
+    let total = poly1.add(&poly2).sub(&poly2);
+
+    assert_eq!(total.coeffs, poly1.coeffs);
+}
+
+#[test]
+fn test_sub_inverts_add_modular_types() {
+    test_sub_inverts_add(generate_random_modint32_polynomial);
+    test_sub_inverts_add(generate_random_modint64_polynomial);
+    test_sub_inverts_add(generate_random_modint_polynomial::<FALCON_Q>);
+    test_sub_inverts_add(generate_random_modint_polynomial::<GOLDILOCKS_Q>);
+    test_sub_inverts_add(generate_random_torus32_polynomial);
+    test_sub_inverts_add(generate_random_torus64_polynomial);
+}
+
+fn test_distributive_sub<T, Q: Quotient<T>>(generate_random_polynomial: fn(usize) -> Polynomial<T>)
+        where T: Ring {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_polynomial(ring_degree).coeffs);
+    let poly2: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_polynomial(ring_degree).coeffs);
+    let poly3: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_polynomial(ring_degree).coeffs);
+
+    // Test that p1 * (p2 - p3) = p1 * p2 - p1 * p3
+    let total1 = poly1.multiply(&poly2.sub(&poly3));
+    let total2 = poly1.multiply(&poly2).sub(&poly1.multiply(&poly3));
+
+    assert_eq!(total1.ring_degree, total2.ring_degree);
+    assert_eq!(total1.coeffs, total2.coeffs);
+}
+
+#[test]
+fn test_distributive_sub_modint32() {
+    test_distributive_sub::<ModInteger32, Cyclic>(generate_random_modint32_polynomial);
+    test_distributive_sub::<ModInteger32, Negacyclic>(generate_random_modint32_polynomial);
+}
+
+#[test]
+fn test_distributive_sub_modint64() {
+    test_distributive_sub::<ModInteger64, Cyclic>(generate_random_modint64_polynomial);
+    test_distributive_sub::<ModInteger64, Negacyclic>(generate_random_modint64_polynomial);
+}
+
+#[test]
+fn test_distributive_sub_modint() {
+    test_distributive_sub::<ModInteger<FALCON_Q>, Cyclic>(generate_random_modint_polynomial::<FALCON_Q>);
+    test_distributive_sub::<ModInteger<FALCON_Q>, Negacyclic>(generate_random_modint_polynomial::<FALCON_Q>);
+}
+
+#[test]
+fn test_distributive_sub_torus() {
+    test_distributive_sub::<Torus32, Negacyclic>(generate_random_torus32_polynomial);
+    test_distributive_sub::<Torus64, Negacyclic>(generate_random_torus64_polynomial);
+}
+
+fn test_assign_matches<T>(generate_random_polynomial: fn(usize) -> Polynomial<T>)
+        where T: Ring {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<T> = generate_random_polynomial(ring_degree);
+    let poly2: Polynomial<T> = generate_random_polynomial(ring_degree);
+
+    // Test that the in-place operations match the ones returning a new polynomial.
+    let mut sum = Polynomial::from_coeffs(poly1.coeffs.clone());
+    sum.add_assign(&poly2);
+    let mut diff = Polynomial::from_coeffs(poly1.coeffs.clone());
+    diff.sub_assign(&poly2);
+    let mut prod = Polynomial::from_coeffs(poly1.coeffs.clone());
+    prod.multiply_assign(&poly2);
+
+    assert_eq!(sum.coeffs, (&poly1 + &poly2).coeffs);
+    assert_eq!(diff.coeffs, (&poly1 - &poly2).coeffs);
+    assert_eq!(prod.coeffs, poly1.multiply(&poly2).coeffs);
+}
+
+#[test]
+fn test_assign_matches_all_types() {
+    test_assign_matches(generate_random_float_polynomial::<f32>);
+    test_assign_matches(generate_random_float_polynomial::<f64>);
+    test_assign_matches(generate_random_complex_polynomial::<f32>);
+    test_assign_matches(generate_random_complex_polynomial::<f64>);
+    test_assign_matches(generate_random_modint32_polynomial);
+    test_assign_matches(generate_random_modint64_polynomial);
+    test_assign_matches(generate_random_modint_polynomial::<FALCON_Q>);
+    test_assign_matches(generate_random_torus32_polynomial);
+    test_assign_matches(generate_random_torus64_polynomial);
+}
+
+#[test]
+fn test_operators_known_answer() {
+    let poly1: Polynomial<f64> = Polynomial::from_coeffs(vec![1.0, 2.0, 3.0]);
+    let poly2: Polynomial<f64> = Polynomial::from_coeffs(vec![4.0, 5.0, 6.0]);
+    let poly3: Polynomial<f64> = Polynomial::from_coeffs(vec![1.0, 1.0, 1.0]);
+
+    // (1 + 2x + 3x^2)(4 + 5x + 6x^2) = (4 + 27) + (13 + 18)x + 28x^2 mod x^3 - 1
+    assert_eq!((&poly1 * &poly2 + &poly3).coeffs, vec![32.0, 32.0, 29.0]);
+    assert_eq!((&poly1 - &poly2).coeffs, vec![-3.0, -3.0, -3.0]);
+    assert_eq!((-&poly1).coeffs, vec![-1.0, -2.0, -3.0]);
+    assert_eq!((&poly1 * 2.0).coeffs, vec![2.0, 4.0, 6.0]);
+    assert_eq!((2.0 * &poly1).coeffs, vec![2.0, 4.0, 6.0]);
+}
+
+#[test]
+#[should_panic(expected = "Ring degrees should be equal. 10 != 14")]
+fn test_operator_different_ring_degrees_fail() {
+    let poly1: Polynomial<ModInteger32> = Polynomial::new(10);
+    let poly2: Polynomial<ModInteger32> = Polynomial::new(14);
+
+    // Should panic because the polynomials have different ring degrees.
+    let _ = poly1 * poly2;
+}
+
+fn test_operators_match_methods<T>(generate_random_polynomial: fn(usize) -> Polynomial<T>)
+        where T: Ring {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<T> = generate_random_polynomial(ring_degree);
+    let poly2: Polynomial<T> = generate_random_polynomial(ring_degree);
+    let poly3: Polynomial<T> = generate_random_polynomial(ring_degree);
+    let scalar = poly3.coeffs[0].clone();
+
+    // Test that the operators match the named methods, for references and owned values.
+    let expected = Polynomial::add(&poly1.multiply(&poly2), &poly3);
+    assert_eq!((&poly1 * &poly2 + &poly3).coeffs, expected.coeffs);
+
+    let expected = Polynomial::sub(&poly1, &poly2).neg();
+    let owned1: Polynomial<T> = Polynomial::from_coeffs(poly1.coeffs.clone());
+    let owned2: Polynomial<T> = Polynomial::from_coeffs(poly2.coeffs.clone());
+    assert_eq!((-(owned1 - owned2)).coeffs, expected.coeffs);
+
+    let expected = poly1.multiply_by_scalar(scalar.clone());
+    assert_eq!((&poly1 * scalar).coeffs, expected.coeffs);
+}
+
+#[test]
+fn test_operators_match_methods_all_types() {
+    test_operators_match_methods(generate_random_float_polynomial::<f32>);
+    test_operators_match_methods(generate_random_float_polynomial::<f64>);
+    test_operators_match_methods(generate_random_complex_polynomial::<f32>);
+    test_operators_match_methods(generate_random_complex_polynomial::<f64>);
+    test_operators_match_methods(generate_random_modint32_polynomial);
+    test_operators_match_methods(generate_random_modint64_polynomial);
+    test_operators_match_methods(generate_random_modint_polynomial::<FALCON_Q>);
+    test_operators_match_methods(generate_random_torus32_polynomial);
+    test_operators_match_methods(generate_random_torus64_polynomial);
+}
+
+#[test]
+fn test_scalar_left_multiply_modint() {
+    let poly: Polynomial<ModInteger<FALCON_Q>, Negacyclic> = Polynomial::from_coeffs(
+        vec![ModInteger::new(1), ModInteger::new(FALCON_Q - 1)]);
+
+    let prod = ModInteger::new(2) * &poly;
+
+    assert_eq!(prod.coeffs, vec![ModInteger::new(2), ModInteger::new(FALCON_Q - 2)]);
+}
+
+#[test]
+fn test_try_operations_different_ring_degrees() {
+    let poly1: Polynomial<ModInteger32> = Polynomial::new(10);
+    let poly2: Polynomial<ModInteger32> = Polynomial::new(14);
+
+    assert_eq!(poly1.try_add(&poly2).err(), Some(RingMathError::RingDegreeMismatch(10, 14)));
+    assert_eq!(poly1.try_sub(&poly2).err(), Some(RingMathError::RingDegreeMismatch(10, 14)));
+    assert_eq!(poly2.try_multiply(&poly1).err(), Some(RingMathError::RingDegreeMismatch(14, 10)));
+}
+
+#[test]
+fn test_try_operations_invalid_coeff_length() {
+    let poly1: Polynomial<f64> = Polynomial {
+        ring_degree: 10,
+        coeffs: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0],
+        quotient: PhantomData,
+    };
+    let poly2: Polynomial<f64> = Polynomial::new(10);
+
+    assert_eq!(poly1.try_check_coeff_length(), Err(RingMathError::CoeffLengthInvalid(10, 9)));
+    assert_eq!(poly2.try_check_coeff_length(), Ok(()));
+    assert_eq!(poly1.try_add(&poly2).err(), Some(RingMathError::CoeffLengthInvalid(10, 9)));
+    // The length of the other polynomial is checked too, rather than indexing out of bounds.
+    assert_eq!(poly2.try_multiply(&poly1).err(), Some(RingMathError::CoeffLengthInvalid(10, 9)));
+}
+
+#[test]
+fn test_try_assign_operations() {
+    let mut poly1: Polynomial<f64> = Polynomial::from_coeffs(vec![1.0, 2.0, 3.0]);
+    let poly2: Polynomial<f64> = Polynomial::from_coeffs(vec![4.0, 5.0, 6.0]);
+    let poly3: Polynomial<f64> = Polynomial::new(4);
+    let poly4: Polynomial<f64> = Polynomial {
+        ring_degree: 3,
+        coeffs: vec![1.0, 2.0],
+        quotient: PhantomData,
+    };
+
+    assert_eq!(poly1.try_add_assign(&poly2), Ok(()));
+    assert_eq!(poly1.coeffs, vec![5.0, 7.0, 9.0]);
+    assert_eq!(poly1.try_sub_assign(&poly2), Ok(()));
+    assert_eq!(poly1.coeffs, vec![1.0, 2.0, 3.0]);
+    assert_eq!(poly1.try_add_assign(&poly3), Err(RingMathError::RingDegreeMismatch(3, 4)));
+    // The length of the other polynomial is checked too, rather than indexing out of bounds.
+    assert_eq!(poly1.try_sub_assign(&poly4), Err(RingMathError::CoeffLengthInvalid(3, 2)));
+    assert_eq!(poly1.coeffs, vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_try_operations_match_panicking_operations() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<ModInteger64> = generate_random_modint64_polynomial(ring_degree);
+    let poly2: Polynomial<ModInteger64> = generate_random_modint64_polynomial(ring_degree);
+
+    assert_eq!(poly1.try_add(&poly2).unwrap().coeffs, (&poly1 + &poly2).coeffs);
+    assert_eq!(poly1.try_sub(&poly2).unwrap().coeffs, (&poly1 - &poly2).coeffs);
+    assert_eq!(poly1.try_multiply(&poly2).unwrap().coeffs, poly1.multiply(&poly2).coeffs);
+}
+
+fn test_karatsuba_matches_schoolbook<T, Q: Quotient<T>>(generate_random_polynomial: fn(usize) -> Polynomial<T>)
+        where T: Ring {
+    // Generate random polynomials of any degree, above and below the threshold.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_polynomial(ring_degree).coeffs);
+    let poly2: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_polynomial(ring_degree).coeffs);
+
+    let prod1 = poly1.multiply(&poly2);
+    let prod2 = poly1.multiply_karatsuba(&poly2);
+
+    assert_eq!(prod1.ring_degree, prod2.ring_degree);
+    assert_eq!(prod1.coeffs, prod2.coeffs);
+}
+
+#[test]
+fn test_karatsuba_matches_schoolbook_modint32() {
+    test_karatsuba_matches_schoolbook::<ModInteger32, Cyclic>(generate_random_modint32_polynomial);
+    test_karatsuba_matches_schoolbook::<ModInteger32, Negacyclic>(generate_random_modint32_polynomial);
+}
+
+#[test]
+fn test_karatsuba_matches_schoolbook_modint64() {
+    test_karatsuba_matches_schoolbook::<ModInteger64, Cyclic>(generate_random_modint64_polynomial);
+    test_karatsuba_matches_schoolbook::<ModInteger64, Negacyclic>(generate_random_modint64_polynomial);
+}
+
+#[test]
+fn test_karatsuba_matches_schoolbook_modint() {
+    test_karatsuba_matches_schoolbook::<ModInteger<FALCON_Q>, Cyclic>(generate_random_modint_polynomial::<FALCON_Q>);
+    test_karatsuba_matches_schoolbook::<ModInteger<FALCON_Q>, Negacyclic>(
+        generate_random_modint_polynomial::<FALCON_Q>);
+}
+
+#[test]
+fn test_karatsuba_matches_schoolbook_torus() {
+    test_karatsuba_matches_schoolbook::<Torus32, Negacyclic>(generate_random_torus32_polynomial);
+    test_karatsuba_matches_schoolbook::<Torus64, Negacyclic>(generate_random_torus64_polynomial);
+}
+
+#[test]
+fn test_karatsuba_small_degrees() {
+    // Cover every split around the threshold, including odd lengths.
+    for ring_degree in 1..=(3 * KARATSUBA_THRESHOLD) {
+        let poly1: Polynomial<ModInteger64, Negacyclic> =
+            Polynomial::from_coeffs(generate_random_modint64_polynomial(ring_degree).coeffs);
+        let poly2: Polynomial<ModInteger64, Negacyclic> =
+            Polynomial::from_coeffs(generate_random_modint64_polynomial(ring_degree).coeffs);
+
+        assert_eq!(poly1.multiply_karatsuba(&poly2).coeffs, poly1.multiply(&poly2).coeffs);
+    }
+}
+
+fn test_karatsuba_float<T>(error: f64)
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<T> = generate_random_float_polynomial::<T>(ring_degree);
+    let poly2: Polynomial<T> = generate_random_float_polynomial::<T>(ring_degree);
+
+    let prod1 = poly1.multiply(&poly2);
+    let prod2 = poly1.multiply_karatsuba(&poly2);
+
+    assert!(check_vecs_almost_equal(&prod1.coeffs, &prod2.coeffs, error));
+}
+
+#[test]
+fn test_karatsuba_float_f32() {
+    test_karatsuba_float::<f32>(F32_MULTIPLY_ERROR);
+}
+
+#[test]
+fn test_karatsuba_float_f64() {
+    test_karatsuba_float::<f64>(F64_ERROR);
+}
+
+#[test]
+fn test_try_multiply_karatsuba_different_ring_degrees() {
+    let poly1: Polynomial<ModInteger64> = Polynomial::new(10);
+    let poly2: Polynomial<ModInteger64> = Polynomial::new(14);
+
+    assert_eq!(poly1.try_multiply_karatsuba(&poly2).err(), Some(RingMathError::RingDegreeMismatch(10, 14)));
+}
+
+/// An integer on the heap, which is `Clone` but not `Copy`, like a big integer.
+#[derive(Clone, Debug, PartialEq)]
+struct BoxedInteger(Box<i64>);
+
+impl std::ops::Add for BoxedInteger {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        BoxedInteger(Box::new(self.0.wrapping_add(*other.0)))
+    }
+}
+
+impl AddAssign for BoxedInteger {
+    fn add_assign(&mut self, other: Self) {
+        *self.0 = self.0.wrapping_add(*other.0);
+    }
+}
+
+impl std::ops::Sub for BoxedInteger {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        BoxedInteger(Box::new(self.0.wrapping_sub(*other.0)))
+    }
+}
+
+impl std::ops::Mul for BoxedInteger {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        &self * &other
+    }
+}
+
+impl std::ops::Mul<&BoxedInteger> for &BoxedInteger {
+    type Output = BoxedInteger;
+
+    fn mul(self, other: &BoxedInteger) -> BoxedInteger {
+        BoxedInteger(Box::new(self.0.wrapping_mul(*other.0)))
+    }
+}
+
+impl Zero for BoxedInteger {
+    fn zero() -> BoxedInteger {
+        BoxedInteger(Box::new(0))
+    }
+}
+
+impl One for BoxedInteger {
+    fn one() -> BoxedInteger {
+        BoxedInteger(Box::new(1))
+    }
+}
+
+impl Ring for BoxedInteger {}
+
+fn box_polynomial<Q: Quotient<i64> + Quotient<BoxedInteger>>(poly: &Polynomial<i64, Q>) -> Polynomial<BoxedInteger, Q> {
+    Polynomial::from_coeffs(poly.coeffs.iter().map(|&coeff| BoxedInteger(Box::new(coeff))).collect())
+}
+
+fn test_clone_coefficients_match_copy<Q: Quotient<i64> + Quotient<BoxedInteger>>() {
+    // Generate random polynomials, large enough to take the recursive Karatsuba path.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(KARATSUBA_THRESHOLD + 1..=MAX_TEST_DEGREE);
+    let poly1: Polynomial<i64, Q> = Polynomial::from_coeffs(generate_random_integer_polynomial(ring_degree, -1000, 1000).coeffs);
+    let poly2: Polynomial<i64, Q> = Polynomial::from_coeffs(generate_random_integer_polynomial(ring_degree, -1000, 1000).coeffs);
+    let boxed1 = box_polynomial(&poly1);
+    let boxed2 = box_polynomial(&poly2);
+
+    // Test that the Clone coefficients give the same results as the Copy ones.
+    assert_eq!(Polynomial::add(&boxed1, &boxed2).coeffs, box_polynomial(&Polynomial::add(&poly1, &poly2)).coeffs);
+    assert_eq!(Polynomial::neg(&boxed1).coeffs, box_polynomial(&Polynomial::neg(&poly1)).coeffs);
+    assert_eq!(boxed1.multiply(&boxed2).coeffs, box_polynomial(&poly1.multiply(&poly2)).coeffs);
+    assert_eq!(boxed1.multiply_karatsuba(&boxed2).coeffs, box_polynomial(&poly1.multiply_karatsuba(&poly2)).coeffs);
+    assert_eq!(boxed1.multiply_by_monomial(3).coeffs, box_polynomial(&poly1.multiply_by_monomial(3)).coeffs);
+    assert_eq!(boxed1.evaluate(BoxedInteger(Box::new(-1))), BoxedInteger(Box::new(poly1.evaluate(-1))));
+}
+
+#[test]
+fn test_clone_coefficients_match_copy_cyclic() {
+    test_clone_coefficients_match_copy::<Cyclic>();
+}
+
+#[test]
+fn test_clone_coefficients_match_copy_negacyclic() {
+    test_clone_coefficients_match_copy::<Negacyclic>();
+}
+
+/// Multiply two bivariate polynomials term by term, folding x^m and y^n with their quotients.
+fn bivariate_product(a: &[Vec<i64>], b: &[Vec<i64>], x_negacyclic: bool, y_negacyclic: bool) -> Vec<Vec<i64>> {
+    let (n, m) = (a.len(), a[0].len());
+    let mut prod: Vec<Vec<i64>> = vec![vec![0; m]; n];
+    for i in 0..n {
+        for j in 0..m {
+            for k in 0..n {
+                for l in 0..m {
+                    let mut term = a[i][j] * b[k][l];
+                    if y_negacyclic && i + k >= n {
+                        term = -term;
+                    }
+                    if x_negacyclic && j + l >= m {
+                        term = -term;
+                    }
+                    prod[(i + k) % n][(j + l) % m] += term;
+                }
+            }
+        }
+    }
+    prod
+}
+
+fn test_bivariate_multiply<QX, QY>()
+        where QX: Quotient<i64>, QY: Quotient<Polynomial<i64, QX>> {
+    // Generate random polynomials in x and y, with y above the Karatsuba threshold.
+    let mut rng = rand::thread_rng();
+    let x_degree = rng.gen_range(1..=8);
+    let y_degree = rng.gen_range(KARATSUBA_THRESHOLD + 1..=2 * KARATSUBA_THRESHOLD);
+    let a: Vec<Vec<i64>> = (0..y_degree).map(|_| (0..x_degree).map(|_| rng.gen_range(-100..=100)).collect()).collect();
+    let b: Vec<Vec<i64>> = (0..y_degree).map(|_| (0..x_degree).map(|_| rng.gen_range(-100..=100)).collect()).collect();
+    let to_bivariate = |rows: &[Vec<i64>]| -> Polynomial<Polynomial<i64, QX>, QY> {
+        Polynomial::from_coeffs(rows.iter().map(|row| Polynomial::from_coeffs(row.clone())).collect())
+    };
+    let (poly1, poly2) = (to_bivariate(&a), to_bivariate(&b));
+
+    // Test that the nested products match the product computed term by term.
+    let expected = to_bivariate(&bivariate_product(&a, &b, QX::NEGACYCLIC, QY::NEGACYCLIC));
+    assert_eq!(&poly1 * &poly2, expected);
+    assert_eq!(poly1.multiply_karatsuba(&poly2), expected);
+}
+
+#[test]
+fn test_bivariate_multiply_all_quotients() {
+    test_bivariate_multiply::<Cyclic, Cyclic>();
+    test_bivariate_multiply::<Negacyclic, Cyclic>();
+    test_bivariate_multiply::<Cyclic, Negacyclic>();
+    test_bivariate_multiply::<Negacyclic, Negacyclic>();
+}
+
+#[test]
+fn test_polynomial_constants() {
+    let poly: Polynomial<i64, Negacyclic> = Polynomial::from_coeffs(vec![1, 2, 3]);
+    let zero: Polynomial<i64, Negacyclic> = Polynomial::zero();
+    let one: Polynomial<i64, Negacyclic> = Polynomial::one();
+    let two = &one + &one;
+
+    // The constants take the ring degree of the polynomial they meet.
+    assert_eq!(&poly + &zero, poly);
+    assert_eq!(&one * &poly, poly);
+    assert_eq!(&two * &poly, Polynomial::from_coeffs(vec![2, 4, 6]));
+    assert_eq!(&zero - &poly, -&poly);
+    assert_eq!(-&two, &zero - &two);
+    assert_eq!(two, Polynomial::from_coeffs(vec![2, 0, 0]));
+    assert_ne!(two, Polynomial::from_coeffs(vec![2, 1, 0]));
+    assert_ne!(one, zero);
+    let mut sum = zero.clone();
+    sum += poly.clone();
+    sum += one;
+    assert_eq!(sum.coeffs, vec![2, 2, 3]);
+}
+
+#[test]
+fn test_multiply_by_monomial_known_answer() {
+    let cyclic: Polynomial<f64> = Polynomial::from_coeffs(vec![1.0, 2.0, 3.0, 4.0]);
+    let negacyclic: Polynomial<f64, Negacyclic> = Polynomial::from_coeffs(vec![1.0, 2.0, 3.0, 4.0]);
+
+    assert_eq!(cyclic.multiply_by_monomial(2).coeffs, vec![3.0, 4.0, 1.0, 2.0]);
+    assert_eq!(cyclic.multiply_by_monomial(-1).coeffs, vec![2.0, 3.0, 4.0, 1.0]);
+    assert_eq!(negacyclic.multiply_by_monomial(2).coeffs, vec![-3.0, -4.0, 1.0, 2.0]);
+    assert_eq!(negacyclic.multiply_by_monomial(4).coeffs, vec![-1.0, -2.0, -3.0, -4.0]);
+    assert_eq!(negacyclic.multiply_by_monomial(7).coeffs, vec![2.0, 3.0, 4.0, -1.0]);
+    assert_eq!(negacyclic.multiply_by_monomial(-1).coeffs, vec![2.0, 3.0, 4.0, -1.0]);
+    assert_eq!(negacyclic.multiply_by_monomial(8).coeffs, vec![1.0, 2.0, 3.0, 4.0]);
+}
+
+#[test]
+fn test_degree_zero_polynomial() {
+    let cyclic: Polynomial<ModInteger32> = Polynomial::new(0);
+    let negacyclic: Polynomial<ModInteger32, Negacyclic> = Polynomial::new(0);
+
+    assert!(cyclic.multiply_by_monomial(3).coeffs.is_empty());
+    assert!(negacyclic.multiply_by_monomial(-5).coeffs.is_empty());
+    assert!(cyclic.multiply_karatsuba(&cyclic).coeffs.is_empty());
+    assert!(negacyclic.multiply_karatsuba(&negacyclic).coeffs.is_empty());
+    assert!(cyclic.multiply(&cyclic).coeffs.is_empty());
+}
+
+fn test_multiply_by_monomial_compare_multiply_by_x<T, Q: Quotient<T>>(
+        generate_random_polynomial: fn(usize) -> Polynomial<T>)
+        where T: Ring {
+    // Generate a random polynomial and exponent in [0, 2N).
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE / 16);
+    let exponent = rng.gen_range(0..2 * ring_degree);
+    let poly: Polynomial<T, Q> = Polynomial::from_coeffs(generate_random_polynomial(ring_degree).coeffs);
+
+    let mut prod1: Polynomial<T, Q> = Polynomial::from_coeffs(poly.coeffs.clone());
+    for _ in 0..exponent {
+        prod1 = prod1.multiply_by_x();
+    }
+    let prod2 = poly.multiply_by_monomial(exponent as i64);
+    let mut prod3: Polynomial<T, Q> = Polynomial::from_coeffs(poly.coeffs.clone());
+    prod3.multiply_by_monomial_assign(exponent as i64);
+
+    assert_eq!(prod1.coeffs, prod2.coeffs);
+    assert_eq!(prod1.coeffs, prod3.coeffs);
+
+    // Test that x^-k undoes x^k.
+    assert_eq!(prod2.multiply_by_monomial(-(exponent as i64)).coeffs, poly.coeffs);
+}
+
+#[test]
+fn test_multiply_by_monomial_compare_multiply_by_x_all_types() {
+    test_multiply_by_monomial_compare_multiply_by_x::<f64, Cyclic>(generate_random_float_polynomial::<f64>);
+    test_multiply_by_monomial_compare_multiply_by_x::<f64, Negacyclic>(generate_random_float_polynomial::<f64>);
+    test_multiply_by_monomial_compare_multiply_by_x::<Complex<f32>, Negacyclic>(
+        generate_random_complex_polynomial::<f32>);
+    test_multiply_by_monomial_compare_multiply_by_x::<ModInteger64, Cyclic>(generate_random_modint64_polynomial);
+    test_multiply_by_monomial_compare_multiply_by_x::<ModInteger64, Negacyclic>(generate_random_modint64_polynomial);
+    test_multiply_by_monomial_compare_multiply_by_x::<Torus32, Negacyclic>(generate_random_torus32_polynomial);
+}
+
+#[test]
+fn test_multiply_by_monomial_compare_multiply() {
+    // Test that multiplying by x^k matches multiplying by the polynomial x^k.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE / 16);
+    let exponent = rng.gen_range(0..ring_degree);
+    let poly: Polynomial<Torus64, Negacyclic> =
+        Polynomial::from_coeffs(generate_random_torus64_polynomial(ring_degree).coeffs);
+    let mut monomial: Polynomial<Torus64, Negacyclic> = Polynomial::new(ring_degree);
+    monomial.coeffs[exponent] = Torus64::one();
+
+    assert_eq!(poly.multiply_by_monomial(exponent as i64).coeffs, poly.multiply(&monomial).coeffs);
+}
+
+#[test]
+fn test_evaluate_known_answer() {
+    let poly: Polynomial<f64> = Polynomial::from_coeffs(vec![1.0, 2.0, 3.0]);
+    let poly_mod: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(
+        vec![ModInteger::new(1), ModInteger::new(2), ModInteger::new(3)]);
+
+    // 1 + 2x + 3x^2 at x = 2 and x = -1
+    assert_eq!(poly.evaluate(2.0), 17.0);
+    assert_eq!(poly.evaluate_many(&[2.0, -1.0, 0.0]), vec![17.0, 2.0, 1.0]);
+    assert_eq!(poly_mod.evaluate(ModInteger::new(FALCON_Q - 1)), ModInteger::new(2));
+}
+
+fn test_evaluate_many_compare_evaluate<T>(generate_random_polynomial: fn(usize) -> Polynomial<T>)
+        where T: Ring {
+    // Generate a random polynomial and random points, more or fewer than the ring degree.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_points = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly: Polynomial<T> = generate_random_polynomial(ring_degree);
+    let points: Vec<T> = generate_random_polynomial(num_points).coeffs;
+
+    let values = poly.evaluate_many(&points);
+
+    assert_eq!(values.len(), num_points);
+    for i in 0..num_points {
+        assert_eq!(values[i], poly.evaluate(points[i].clone()));
+    }
+}
+
+#[test]
+fn test_evaluate_many_compare_evaluate_modint() {
+    test_evaluate_many_compare_evaluate(generate_random_modint_polynomial::<FALCON_Q>);
+    test_evaluate_many_compare_evaluate(generate_random_modint_polynomial::<GOLDILOCKS_Q>);
+}
+
+#[test]
+fn test_evaluate_many_compare_evaluate_modint64() {
+    // The integers mod 2^64 are not a field, but evaluation never divides.
+    test_evaluate_many_compare_evaluate(generate_random_modint64_polynomial);
+}
+
+#[test]
+fn test_evaluate_many_compare_evaluate_float() {
+    // Remainder trees are badly conditioned over the floats, so keep to a few well-spread points.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=8);
+    let poly: Polynomial<f64> = generate_random_float_polynomial(ring_degree);
+    let points: Vec<f64> = (0..ring_degree).map(|i| i as f64 - (ring_degree / 2) as f64 + 0.5).collect();
+
+    let values1 = poly.evaluate_many(&points);
+    let values2: Vec<f64> = points.iter().map(|&point| poly.evaluate(point)).collect();
+
+    assert!(check_vecs_almost_equal(&values1, &values2, F64_EVALUATE_ERROR));
+}
+
+fn test_interpolate_roundtrip<const M: u64>() {
+    // Generate random values at distinct points.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE / 8);
+    let num_points = rng.gen_range(1..=ring_degree);
+    let offset = rng.gen_range(0..M - num_points as u64);
+    let points: Vec<ModInteger<M>> = (0..num_points).map(|i| ModInteger::new(offset + i as u64)).collect();
+    let values: Vec<ModInteger<M>> = generate_random_modint_polynomial::<M>(num_points).coeffs;
+
+    let poly: Polynomial<ModInteger<M>> = Polynomial::interpolate(ring_degree, &points, &values);
+
+    assert_eq!(poly.ring_degree, ring_degree);
+    assert_eq!(poly.evaluate_many(&points), values);
+    for i in num_points..ring_degree {
+        assert_eq!(poly.coeffs[i], ModInteger::zero());
+    }
+}
+
+#[test]
+fn test_interpolate_roundtrip_modint() {
+    test_interpolate_roundtrip::<FALCON_Q>();
+    test_interpolate_roundtrip::<GOLDILOCKS_Q>();
+}
+
+#[test]
+fn test_interpolate_recovers_polynomial() {
+    // Shamir secret sharing: any n shares of a polynomial of degree less than n recover it.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE / 8);
+    let poly: Polynomial<ModInteger<FALCON_Q>, Negacyclic> =
+        Polynomial::from_coeffs(generate_random_modint_polynomial::<FALCON_Q>(ring_degree).coeffs);
+    let points: Vec<ModInteger<FALCON_Q>> = (1..=ring_degree as u64).map(ModInteger::new).collect();
+    let shares = poly.evaluate_many(&points);
+
+    let recovered: Polynomial<ModInteger<FALCON_Q>, Negacyclic> = Polynomial::interpolate(ring_degree, &points, &shares);
+
+    assert_eq!(recovered.coeffs, poly.coeffs);
+    assert_eq!(recovered.evaluate(ModInteger::zero()), poly.coeffs[0]);
+}
+
+#[test]
+fn test_interpolate_float() {
+    let poly: Polynomial<f64> = Polynomial::from_coeffs(vec![1.0, -2.0, 0.5, 3.0, 0.25]);
+    let points = vec![-2.0, -1.0, 0.5, 1.0, 3.0];
+
+    let recovered: Polynomial<f64> = Polynomial::interpolate(5, &points, &poly.evaluate_many(&points));
+
+    assert!(check_vecs_almost_equal(&recovered.coeffs, &poly.coeffs, F64_ERROR));
+}
+
+#[test]
+fn test_interpolate_complex() {
+    let one = Complex{real: 1.0, imag: 0.0};
+    let i = Complex{real: 0.0, imag: 1.0};
+    let points = vec![one, i, -one, -i];
+    let poly: Polynomial<Complex<f64>> = Polynomial::from_coeffs(
+        vec![Complex{real: 1.0, imag: 2.0}, Complex{real: -3.0, imag: 0.5}, one, Complex{real: 0.25, imag: -1.0}]);
+
+    let recovered: Polynomial<Complex<f64>> = Polynomial::interpolate(4, &points, &poly.evaluate_many(&points));
+
+    assert!(check_vecs_almost_equal(&recovered.coeffs, &poly.coeffs, F64_ERROR));
+}
+
+#[test]
+fn test_try_interpolate_invalid_points() {
+    let points: Vec<ModInteger<FALCON_Q>> = vec![ModInteger::new(1), ModInteger::new(2), ModInteger::new(1)];
+    let values: Vec<ModInteger<FALCON_Q>> = vec![ModInteger::new(5), ModInteger::new(6), ModInteger::new(7)];
+
+    assert_eq!(Polynomial::<ModInteger<FALCON_Q>>::try_interpolate(4, &points, &values[..2]).err(),
+               Some(RingMathError::LengthMismatch(3, 2)));
+    assert_eq!(Polynomial::<ModInteger<FALCON_Q>>::try_interpolate(2, &points, &values).err(),
+               Some(RingMathError::TooManyPoints(3, 2)));
+    assert_eq!(Polynomial::<ModInteger<FALCON_Q>>::try_interpolate(4, &points, &values).err(),
+               Some(RingMathError::RepeatedPoint(0, 2)));
+}
+
+fn generate_random_modint_polynomial_of_degree<const Q: u64>(ring_degree: usize, degree: usize)
+        -> Polynomial<ModInteger<Q>> {
+    let mut coeffs = generate_random_modint_coeffs::<Q>(degree + 1);
+    if coeffs[degree] == ModInteger::zero() {
+        coeffs[degree] = ModInteger::one();
+    }
+    coeffs.resize(ring_degree, ModInteger::zero());
+    Polynomial::from_coeffs(coeffs)
+}
+
+#[test]
+fn test_divide_known_answer() {
+    let q = FALCON_Q - 1;
+    let poly1: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(
+        vec![ModInteger::new(1), ModInteger::new(0), ModInteger::new(1), ModInteger::new(0)]);
+    let poly2: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(
+        vec![ModInteger::new(q), ModInteger::new(1), ModInteger::new(0), ModInteger::new(0)]);
+
+    // x^2 + 1 = (x + 1)(x - 1) + 2
+    let (quotient, remainder) = poly1.divide(&poly2);
+
+    assert_eq!(quotient.coeffs,
+               vec![ModInteger::new(1), ModInteger::new(1), ModInteger::new(0), ModInteger::new(0)]);
+    assert_eq!(remainder.coeffs,
+               vec![ModInteger::new(2), ModInteger::new(0), ModInteger::new(0), ModInteger::new(0)]);
+    assert_eq!(remainder.degree(), Some(0));
+    assert_eq!(Polynomial::<ModInteger<FALCON_Q>>::new(4).degree(), None);
+}
+
+fn test_divide_roundtrip<const Q: u64>() {
+    // Generate a random polynomial and a random divisor of lower degree.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let divisor_degree = rng.gen_range(0..ring_degree);
+    let poly: Polynomial<ModInteger<Q>> = generate_random_modint_polynomial(ring_degree);
+    let divisor = generate_random_modint_polynomial_of_degree::<Q>(ring_degree, divisor_degree);
+
+    let (quotient, remainder) = poly.divide(&divisor);
+
+    // Test that p = q * d + r with deg r < deg d. The product has degree less than N, so it does not wrap.
+    assert!(remainder.degree().is_none_or(|degree| degree < divisor_degree));
+    assert_eq!((&(&quotient * &divisor) + &remainder).coeffs, poly.coeffs);
+}
+
+#[test]
+fn test_divide_roundtrip_modint() {
+    test_divide_roundtrip::<FALCON_Q>();
+    test_divide_roundtrip::<GOLDILOCKS_Q>();
+}
+
+fn test_gcd_common_factor<const Q: u64>() {
+    // Generate random polynomials a, b and c with deg a + deg c and deg b + deg c less than N.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(4..=MAX_TEST_DEGREE / 4);
+    let factor_degree = rng.gen_range(1..ring_degree / 2);
+    let factor = generate_random_modint_polynomial_of_degree::<Q>(ring_degree, factor_degree);
+    let poly1 = &generate_random_modint_polynomial_of_degree::<Q>(ring_degree, ring_degree / 2 - 1) * &factor;
+    let poly2 = &generate_random_modint_polynomial_of_degree::<Q>(ring_degree, ring_degree / 2 - 1) * &factor;
+
+    let gcd = poly1.gcd(&poly2);
+
+    // Test that the GCD is monic, divides a c and b c, and is divisible by c.
+    let degree = gcd.degree().unwrap();
+    assert_eq!(gcd.coeffs[degree], ModInteger::one());
+    assert_eq!(poly1.divide(&gcd).1.degree(), None);
+    assert_eq!(poly2.divide(&gcd).1.degree(), None);
+    assert_eq!(gcd.divide(&factor).1.degree(), None);
+}
+
+#[test]
+fn test_gcd_common_factor_modint() {
+    test_gcd_common_factor::<FALCON_Q>();
+    test_gcd_common_factor::<GOLDILOCKS_Q>();
+}
+
+#[test]
+fn test_gcd_with_zero() {
+    let zero: Polynomial<ModInteger<FALCON_Q>> = Polynomial::new(4);
+    let poly: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(
+        vec![ModInteger::new(4), ModInteger::new(2), ModInteger::new(0), ModInteger::new(0)]);
+
+    // gcd(2x + 4, 0) = x + 2 and gcd(0, 0) = 0
+    assert_eq!(poly.gcd(&zero).coeffs,
+               vec![ModInteger::new(2), ModInteger::new(1), ModInteger::new(0), ModInteger::new(0)]);
+    assert_eq!(zero.gcd(&zero).degree(), None);
+}
+
+fn test_extended_gcd_bezout<const Q: u64>() {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE / 4);
+    let poly1: Polynomial<ModInteger<Q>, Negacyclic> =
+        Polynomial::from_coeffs(generate_random_modint_coeffs::<Q>(ring_degree));
+    let poly2: Polynomial<ModInteger<Q>, Negacyclic> =
+        Polynomial::from_coeffs(generate_random_modint_coeffs::<Q>(ring_degree));
+
+    let (gcd, s, t) = poly1.extended_gcd(&poly2);
+
+    // Test that s a + t b = g in the ring, and that g is the GCD.
+    assert_eq!((&(&s * &poly1) + &(&t * &poly2)).coeffs, gcd.coeffs);
+    assert_eq!(gcd.coeffs, poly1.gcd(&poly2).coeffs);
+    assert_eq!(poly1.divide(&gcd).1.degree(), None);
+    assert_eq!(poly2.divide(&gcd).1.degree(), None);
+}
+
+#[test]
+fn test_extended_gcd_bezout_modint() {
+    test_extended_gcd_bezout::<FALCON_Q>();
+    test_extended_gcd_bezout::<GOLDILOCKS_Q>();
+}
+
+#[test]
+fn test_extended_gcd_known_answer() {
+    let q = FALCON_Q - 1;
+    let poly1: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(
+        vec![ModInteger::new(0), ModInteger::new(1), ModInteger::new(0)]);
+    let poly2: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(
+        vec![ModInteger::new(1), ModInteger::new(1), ModInteger::new(0)]);
+
+    // -x + (x + 1) = 1
+    let (gcd, s, t) = poly1.extended_gcd(&poly2);
+
+    assert_eq!(gcd.coeffs, vec![ModInteger::new(1), ModInteger::new(0), ModInteger::new(0)]);
+    assert_eq!(s.coeffs, vec![ModInteger::new(q), ModInteger::new(0), ModInteger::new(0)]);
+    assert_eq!(t.coeffs, vec![ModInteger::new(1), ModInteger::new(0), ModInteger::new(0)]);
+}
+
+#[test]
+fn test_try_divide_invalid() {
+    let poly1: Polynomial<ModInteger<FALCON_Q>> = generate_random_modint_polynomial(4);
+    let poly2: Polynomial<ModInteger<FALCON_Q>> = generate_random_modint_polynomial(5);
+    let zero: Polynomial<ModInteger<FALCON_Q>> = Polynomial::new(4);
+
+    assert_eq!(poly1.try_divide(&zero).err(), Some(RingMathError::DivisionByZero));
+    assert_eq!(poly1.try_divide(&poly2).err(), Some(RingMathError::RingDegreeMismatch(4, 5)));
+    assert_eq!(poly1.try_gcd(&poly2).err(), Some(RingMathError::RingDegreeMismatch(4, 5)));
+    assert_eq!(poly1.try_extended_gcd(&poly2).err(), Some(RingMathError::RingDegreeMismatch(4, 5)));
+}
+
+#[test]
+#[should_panic(expected = "Divisor should be a nonzero polynomial.")]
+fn test_divide_by_zero() {
+    let poly: Polynomial<ModInteger<FALCON_Q>> = generate_random_modint_polynomial(4);
+    let zero: Polynomial<ModInteger<FALCON_Q>> = Polynomial::new(4);
+
+    let _ = poly.divide(&zero);
+}
+
+fn test_inverse_modint<const Q: u64>() {
+    // Generate a random polynomial, which is invertible in the negacyclic ring with high probability.
+    let mut rng = rand::thread_rng();
+    let ring_degree = 1 << rng.gen_range(0..=9);
+    let poly: Polynomial<ModInteger<Q>, Negacyclic> =
+        Polynomial::from_coeffs(generate_random_modint_coeffs::<Q>(ring_degree));
+    let mut one: Polynomial<ModInteger<Q>, Negacyclic> = Polynomial::new(ring_degree);
+    one.coeffs[0] = ModInteger::one();
+
+    // Test that a * a^-1 = 1.
+    if let Some(inverse) = poly.inverse() {
+        assert_eq!((&poly * &inverse).coeffs, one.coeffs);
+        assert_eq!(inverse.inverse().unwrap().coeffs, poly.coeffs);
+    }
+}
+
+#[test]
+fn test_inverse_modint_prime() {
+    test_inverse_modint::<FALCON_Q>();
+    test_inverse_modint::<GOLDILOCKS_Q>();
+}
+
+#[test]
+fn test_inverse_known_answer() {
+    let poly: Polynomial<ModInteger<FALCON_Q>, Negacyclic> =
+        Polynomial::from_coeffs(vec![ModInteger::new(1), ModInteger::new(1)]);
+    let half = ModInteger::new(2).inverse();
+
+    // (1 + x)(1 - x) = 1 - x^2 = 2 when x^2 = -1
+    assert_eq!(poly.inverse().unwrap().coeffs, vec![half, -half]);
+}
+
+#[test]
+fn test_divide_and_inverse_composite_modulus() {
+    let poly1: Polynomial<ModInteger<15>> = Polynomial::from_coeffs(
+        vec![ModInteger::new(1), ModInteger::new(2), ModInteger::new(0), ModInteger::new(0)]);
+    let poly2: Polynomial<ModInteger<15>> = Polynomial::from_coeffs(
+        vec![ModInteger::new(2), ModInteger::new(0), ModInteger::new(0), ModInteger::new(0)]);
+
+    // 2 is a unit mod 15 with inverse 8, so 1 + 2x = 2 (8 + x).
+    let (quotient, remainder) = poly1.divide(&poly2);
+    assert_eq!(quotient.coeffs,
+               vec![ModInteger::new(8), ModInteger::new(1), ModInteger::new(0), ModInteger::new(0)]);
+    assert_eq!(remainder.degree(), None);
+    assert_eq!(poly2.inverse().unwrap().coeffs,
+               vec![ModInteger::new(8), ModInteger::new(0), ModInteger::new(0), ModInteger::new(0)]);
+}
+
+#[test]
+#[should_panic(expected = "Integer should be invertible. 3 has no inverse mod 15")]
+fn test_divide_composite_modulus_zero_divisor_fail() {
+    let poly1: Polynomial<ModInteger<15>> = Polynomial::from_coeffs(
+        vec![ModInteger::new(1), ModInteger::new(2), ModInteger::new(0), ModInteger::new(0)]);
+    let poly2: Polynomial<ModInteger<15>> = Polynomial::from_coeffs(
+        vec![ModInteger::new(0), ModInteger::new(3), ModInteger::new(0), ModInteger::new(0)]);
+
+    // Should panic because the leading coefficient 3 of the divisor is not a unit mod 15.
+    let _ = poly1.divide(&poly2);
+}
+
+#[test]
+fn test_inverse_not_invertible() {
+    let q = FALCON_Q - 1;
+    let zero: Polynomial<ModInteger<FALCON_Q>, Negacyclic> = Polynomial::new(4);
+    let poly: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(
+        vec![ModInteger::new(q), ModInteger::new(1), ModInteger::new(0), ModInteger::new(0)]);
+
+    // x - 1 divides x^N - 1, and 0 is never invertible.
+    assert!(poly.inverse().is_none());
+    assert!(zero.inverse().is_none());
+}
+
+#[test]
+fn test_inverse_mod_modint() {
+    // Ring (Z/q)[x]/(x^7 - x - 1), of the NTRU Prime shape.
+    let ring_degree = 7;
+    let mut modulus: Vec<ModInteger<FALCON_Q>> = vec![ModInteger::zero(); ring_degree + 1];
+    modulus[0] = -ModInteger::one();
+    modulus[1] = -ModInteger::one();
+    modulus[ring_degree] = ModInteger::one();
+    let ring = QuotientRing::new(modulus);
+    let poly: Polynomial<ModInteger<FALCON_Q>> = generate_random_modint_polynomial(ring_degree);
+    let mut one: Polynomial<ModInteger<FALCON_Q>> = Polynomial::new(ring_degree);
+    one.coeffs[0] = ModInteger::one();
+
+    if let Some(inverse) = poly.inverse_mod(&ring) {
+        assert_eq!(poly.multiply_mod(&inverse, &ring).coeffs, one.coeffs);
+    }
+}
+
+fn generate_random_odd_parity_coeffs<T: Ring>(generate_random_polynomial: fn(usize) -> Polynomial<T>,
+                                              is_odd: fn(T) -> bool, size: usize) -> Vec<T> {
+    // Polynomials with an odd number of odd coefficients are the units of GF(2)[x]/(x^N + 1) for N
+    // a power of two.
+    let mut coeffs = generate_random_polynomial(size).coeffs;
+    if coeffs.iter().filter(|&coeff| is_odd(coeff.clone())).count() & 1 == 0 {
+        coeffs[0] += T::one();
+    }
+    coeffs
+}
+
+#[test]
+fn test_inverse_modint32() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = 1 << rng.gen_range(0..=9);
+    let poly: Polynomial<ModInteger32, Negacyclic> = Polynomial::from_coeffs(generate_random_odd_parity_coeffs(
+        generate_random_modint32_polynomial, |coeff| coeff.value & 1 == 1, ring_degree));
+    let mut one: Polynomial<ModInteger32, Negacyclic> = Polynomial::new(ring_degree);
+    one.coeffs[0] = ModInteger32::one();
+
+    let inverse = poly.inverse().unwrap();
+
+    assert_eq!((&poly * &inverse).coeffs, one.coeffs);
+}
+
+#[test]
+fn test_inverse_modint64() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = 1 << rng.gen_range(0..=9);
+    let poly: Polynomial<ModInteger64> = Polynomial::from_coeffs(generate_random_odd_parity_coeffs(
+        generate_random_modint64_polynomial, |coeff| coeff.value & 1 == 1, ring_degree));
+    let mut one: Polynomial<ModInteger64> = Polynomial::new(ring_degree);
+    one.coeffs[0] = ModInteger64::one();
+
+    let inverse = poly.inverse().unwrap();
+
+    assert_eq!((&poly * &inverse).coeffs, one.coeffs);
+}
+
+#[test]
+fn test_inverse_power_of_two_not_invertible() {
+    // 1 + x has an even number of odd coefficients, and 2 is even.
+    let poly1: Polynomial<ModInteger32, Negacyclic> =
+        Polynomial::from_coeffs(vec![ModInteger32{value: 1}, ModInteger32{value: 1}]);
+    let poly2: Polynomial<ModInteger64> = Polynomial::from_coeffs(vec![ModInteger64{value: 2}, ModInteger64{value: 0}]);
+
+    assert!(poly1.inverse().is_none());
+    assert!(poly2.inverse().is_none());
+}
+
+#[test]
+fn test_inverse_mod_modint64() {
+    // Ring (Z/2^64)[x]/(x^3 - x - 1), where x^3 - x - 1 is irreducible mod 2.
+    let ring = QuotientRing::new(vec![-ModInteger64::one(), -ModInteger64::one(), ModInteger64::zero(),
+                                      ModInteger64::one()]);
+    let poly: Polynomial<ModInteger64> = Polynomial::from_coeffs(generate_random_odd_parity_coeffs(
+        generate_random_modint64_polynomial, |coeff| coeff.value & 1 == 1, 3));
+    let mut one: Polynomial<ModInteger64> = Polynomial::new(3);
+    one.coeffs[0] = ModInteger64::one();
+
+    let inverse = poly.inverse_mod(&ring).unwrap();
+
+    assert_eq!(poly.multiply_mod(&inverse, &ring).coeffs, one.coeffs);
+}
+
+const KYBER_Q: u64 = 3329;
+
+#[test]
+fn test_switch_modulus_known_answer() {
+    let poly: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(
+        vec![ModInteger::new(0), ModInteger::new(6145), ModInteger::new(12288)]);
+
+    // 3329/12289 * 6145 = 1664.64 and 3329/12289 * 12288 = 3328.73
+    let nearest: Polynomial<ModInteger<KYBER_Q>> = poly.switch_modulus(Rounding::Nearest);
+    let floor: Polynomial<ModInteger<KYBER_Q>> = poly.switch_modulus(Rounding::Floor);
+
+    assert_eq!(nearest.coeffs, vec![ModInteger::new(0), ModInteger::new(1665), ModInteger::new(0)]);
+    assert_eq!(floor.coeffs, vec![ModInteger::new(0), ModInteger::new(1664), ModInteger::new(3328)]);
+    assert_eq!(poly.switch_modulus::<Torus32>(Rounding::Floor).coeffs[1], Torus32{value: 2147658396});
+}
+
+fn test_switch_modulus_error<T, U>(generate_random_polynomial: fn(usize) -> Polynomial<T>)
+        where T: ModularRing,
+        U: ModularRing {
+    // Generate a random polynomial.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly: Polynomial<T> = generate_random_polynomial(ring_degree);
+
+    // Test that each rounding mode stays within its error of q'/q x, comparing y q with x q' mod q q'.
+    let (from_modulus, to_modulus) = (T::modulus() as i128, U::modulus() as i128);
+    for (rounding, low, high) in [(Rounding::Nearest, -from_modulus / 2, from_modulus / 2),
+                                  (Rounding::Floor, 0, from_modulus - 1),
+                                  (Rounding::Randomized, -from_modulus + 1, from_modulus - 1)] {
+        let switched: Polynomial<U> = poly.switch_modulus(rounding);
+        for (coeff, approx) in poly.coeffs.iter().zip(switched.coeffs.iter()) {
+            let mut diff = coeff.clone().residue() as i128 * to_modulus - approx.clone().residue() as i128 * from_modulus;
+            if diff > from_modulus {
+                diff -= from_modulus * to_modulus;
+            }
+            assert!(low <= diff && diff <= high);
+        }
+    }
+}
+
+#[test]
+fn test_switch_modulus_error_modint() {
+    test_switch_modulus_error::<ModInteger<FALCON_Q>, ModInteger<KYBER_Q>>(generate_random_modint_polynomial::<FALCON_Q>);
+    test_switch_modulus_error::<ModInteger<GOLDILOCKS_Q>, ModInteger<FALCON_Q>>(
+        generate_random_modint_polynomial::<GOLDILOCKS_Q>);
+    test_switch_modulus_error::<ModInteger<FALCON_Q>, ModInteger64>(generate_random_modint_polynomial::<FALCON_Q>);
+}
+
+#[test]
+fn test_switch_modulus_error_native() {
+    test_switch_modulus_error::<ModInteger64, ModInteger<FALCON_Q>>(generate_random_modint64_polynomial);
+    test_switch_modulus_error::<ModInteger32, Torus32>(generate_random_modint32_polynomial);
+    test_switch_modulus_error::<Torus64, ModInteger32>(generate_random_torus64_polynomial);
+}
+
+#[test]
+fn test_switch_modulus_roundtrip() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly: Polynomial<ModInteger<FALCON_Q>, Negacyclic> =
+        Polynomial::from_coeffs(generate_random_modint_coeffs::<FALCON_Q>(ring_degree));
+
+    // Switching up to 2^64 and back to q loses nothing.
+    let switched: Polynomial<ModInteger64, Negacyclic> = poly.switch_modulus(Rounding::Nearest);
+
+    assert_eq!(switched.switch_modulus::<ModInteger<FALCON_Q>>(Rounding::Nearest).coeffs, poly.coeffs);
+}
+
+#[test]
+fn test_switch_modulus_randomized_unbiased() {
+    // 2^31 scaled from 2^32 to 3 is 1.5, which should round up about half of the time.
+    let poly: Polynomial<ModInteger32> = Polynomial::from_coeffs(vec![ModInteger32{value: 1 << 31}; 1000]);
+
+    let switched: Polynomial<ModInteger<3>> = poly.switch_modulus(Rounding::Randomized);
+    let ups = switched.coeffs.iter().filter(|&&coeff| coeff == ModInteger::new(2)).count();
+
+    assert!(switched.coeffs.iter().all(|&coeff| coeff == ModInteger::new(1) || coeff == ModInteger::new(2)));
+    assert!((400..=600).contains(&ups));
+}
+
+#[test]
+fn test_switch_to_real() {
+    let poly: Polynomial<ModInteger32> = Polynomial::from_coeffs(
+        vec![ModInteger32{value: 0}, ModInteger32{value: 1 << 31}, ModInteger32{value: 3 << 30}]);
+
+    assert_eq!(poly.switch_to_real().coeffs, vec![0.0, 0.5, 0.75]);
+}
+
+#[test]
+fn test_norms_modint_centered() {
+    // The centered representatives are 3, -1, 6144 and -6144.
+    let poly: Polynomial<ModInteger<FALCON_Q>> = Polynomial::from_coeffs(
+        vec![ModInteger::new(3), ModInteger::new(FALCON_Q - 1), ModInteger::new(6144), ModInteger::new(6145)]);
+
+    assert_eq!(poly.norm_infinity(), 6144.0);
+    assert_eq!(poly.norm_l1(), 12292.0);
+    assert_eq!(poly.norm_l2(), (9.0_f64 + 1.0 + 2.0 * 6144.0 * 6144.0).sqrt());
+}
+
+#[test]
+fn test_norms_power_of_two_centered() {
+    let poly1: Polynomial<ModInteger32> = Polynomial::from_coeffs(
+        vec![ModInteger32{value: (1 << 32) - 5}, ModInteger32{value: 1 << 31}, ModInteger32{value: 2}]);
+    let poly2: Polynomial<ModInteger64> = Polynomial::from_coeffs(
+        vec![ModInteger64{value: (1 << 64) - 1}, ModInteger64{value: 7}]);
+
+    assert_eq!(poly1.norm_infinity(), 2147483648.0);
+    assert_eq!(poly1.norm_l1(), 2147483655.0);
+    assert_eq!(poly2.norm_infinity(), 7.0);
+    assert_eq!(poly2.norm_l2(), 50.0_f64.sqrt());
+}
+
+#[test]
+fn test_norms_context_modint_centered() {
+    let context = ModulusContext::new(FALCON_Q);
+    let poly: Polynomial<ContextModInteger> = Polynomial::from_coeffs(
+        vec![context.integer(FALCON_Q - 2), context.integer(5), context.integer(0)]);
+
+    assert_eq!(poly.norm_infinity(), 5.0);
+    assert_eq!(poly.norm_l1(), 7.0);
+}
+
+#[test]
+fn test_norms_torus() {
+    // 3/4 is at distance 1/4 from the nearest integer.
+    let poly1: Polynomial<Torus32> = Polynomial::from_coeffs(vec![Torus32{value: 3 << 30}, Torus32{value: 1 << 29}]);
+    let poly2: Polynomial<Torus64> = Polynomial::from_coeffs(vec![Torus64{value: 1 << 63}]);
+
+    assert_eq!(poly1.norm_infinity(), 0.25);
+    assert_eq!(poly1.norm_l1(), 0.375);
+    assert_eq!(poly2.norm_l2(), 0.5);
+}
+
+#[test]
+fn test_norms_float() {
+    let poly: Polynomial<f64> = Polynomial::from_coeffs(vec![3.0, -4.0, 0.0]);
+
+    assert_eq!(poly.norm_infinity(), 4.0);
+    assert_eq!(poly.norm_l1(), 7.0);
+    assert_eq!(poly.norm_l2(), 5.0);
+}
+
+#[test]
+fn test_norms_complex_canonical_embedding() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=64);
+    let mut coeffs = vec![Complex::zero(); ring_degree];
+    coeffs[0] = Complex{real: 3.0, imag: 4.0};
+    let constant: Polynomial<Complex<f64>, Negacyclic> = Polynomial::from_coeffs(coeffs);
+
+    // A constant takes its value at every root, and x has magnitude 1 at every root of X^N + 1.
+    assert!((constant.norm_infinity() - 5.0).abs() < F64_EVALUATE_ERROR);
+    assert!((constant.norm_l1() - 5.0 * ring_degree as f64).abs() < F64_EVALUATE_ERROR * ring_degree as f64);
+    if ring_degree > 1 {
+        let x: Polynomial<Complex<f64>, Negacyclic> = Polynomial::from_coeffs(constant.coeffs.iter().enumerate()
+            .map(|(i, _)| if i == 1 { Complex::one() } else { Complex::zero() }).collect());
+        assert!((x.norm_l2() - (ring_degree as f64).sqrt()).abs() < F64_EVALUATE_ERROR);
+    }
+}
+
+fn test_norms_complex_parseval<Q: Quotient<Complex<f64>>>() {
+    // Generate a random complex polynomial.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=256);
+    let poly: Polynomial<Complex<f64>, Q> =
+        Polynomial::from_coeffs(generate_random_complex_polynomial::<f64>(ring_degree).coeffs);
+
+    // The roots of X^N ± 1 are orthogonal, so the embedding has squared L2 norm N times that of the coefficients.
+    let coeffs_l2: f64 = poly.coeffs.iter().map(|coeff| coeff.real * coeff.real + coeff.imag * coeff.imag).sum();
+    let norm = poly.norm_l2();
+
+    assert!(check_vecs_almost_equal(&[norm * norm], &[ring_degree as f64 * coeffs_l2], F64_EVALUATE_ERROR));
+    assert!(poly.norm_infinity() <= norm && norm <= poly.norm_l1());
+}
+
+#[test]
+fn test_norms_complex_parseval_cyclic() {
+    test_norms_complex_parseval::<Cyclic>();
+}
+
+#[test]
+fn test_norms_complex_parseval_negacyclic() {
+    test_norms_complex_parseval::<Negacyclic>();
+}
+
+#[test]
+fn test_convert_modint_to_float_centered() {
+    let coeffs = vec![ModInteger32{value: (1 << 32) - 1}, ModInteger32{value: 7}, ModInteger32{value: 1 << 31}];
+    let poly1: Polynomial<ModInteger32, Negacyclic> = Polynomial::from_coeffs(coeffs.clone());
+    let poly2: Polynomial<ModInteger32, Negacyclic> = Polynomial::from_coeffs(coeffs);
+
+    let real: Polynomial<f64, Negacyclic> = Polynomial::from(poly1);
+    let complex: Polynomial<Complex<f32>, Negacyclic> = Polynomial::from(poly2);
+
+    assert_eq!(real.ring_degree, 3);
+    assert_eq!(real.coeffs, vec![-1.0, 7.0, 2147483648.0]);
+    assert_eq!(complex.coeffs[0], Complex{real: -1.0, imag: 0.0});
+}
+
+#[test]
+fn test_convert_float_to_modint_round_trip() {
+    // Generate a random polynomial, with the low bits cleared so that its centered lift is exact in an f64.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let coeffs: Vec<ModInteger64> = generate_random_modint64_polynomial(ring_degree).coeffs.iter()
+        .map(|coeff| ModInteger64{value: coeff.value >> 12 << 12}).collect();
+    let poly: Polynomial<ModInteger64> = Polynomial::from_coeffs(coeffs.clone());
+
+    // The centered lift converts back exactly.
+    let real: Polynomial<f64> = Polynomial::from(poly);
+    assert_eq!(Polynomial::<ModInteger64>::from(real).coeffs, coeffs);
+
+    // Rounding perturbations of up to 0.4 recovers integers below 2^50, where the spacing of f64s is
+    // at most 1/8, so that the perturbed value is never rounded onto a tie.
+    let values: Vec<i64> = (0..ring_degree).map(|_| rng.gen_range(-(1 << 50) + 1..1 << 50)).collect();
+    let coeffs: Vec<ModInteger64> = values.iter()
+        .map(|&value| ModInteger64{value: (value as i128).rem_euclid(1 << 64) as u128}).collect();
+    let perturbed: Polynomial<f64> = Polynomial::from_coeffs(values.iter()
+        .map(|&value| value as f64 + if rng.gen::<bool>() { 0.4 } else { -0.4 }).collect());
+
+    assert_eq!(Polynomial::<ModInteger64>::from(perturbed).coeffs, coeffs);
+}
+
+#[test]
+fn test_convert_between_moduli() {
+    // Generate a random polynomial.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let poly: Polynomial<ModInteger32> = generate_random_modint32_polynomial(ring_degree);
+    let square = &poly * &poly;
+
+    // Reducing mod 2^64 and back mod 2^32 is the identity, and reduction commutes with multiplication.
+    let lifted: Polynomial<ModInteger64> = Polynomial::from(Polynomial::from_coeffs(poly.coeffs.clone()));
+    let lifted_square: Polynomial<ModInteger32> = Polynomial::from(&lifted * &lifted);
+
+    assert_eq!(Polynomial::<ModInteger32>::from(lifted).coeffs, poly.coeffs);
+    assert_eq!(lifted_square.coeffs, square.coeffs);
+}
+
+#[test]
+fn test_try_convert_complex() {
+    let real: Polynomial<Complex<f64>> = Polynomial::from_coeffs(vec![Complex::from(2.4), Complex::from(-1.6)]);
+    let complex: Polynomial<Complex<f64>> = Polynomial::from_coeffs(vec![Complex::from(1.0), Complex{real: 0.0, imag: 1.0}]);
+
+    let rounded: Polynomial<ModInteger32> = Polynomial::try_from(real).unwrap();
+
+    assert_eq!(rounded.coeffs, vec![ModInteger32{value: 2}, ModInteger32{value: (1 << 32) - 2}]);
+    assert_eq!(Polynomial::<f64>::try_from(complex).err(), Some(RingMathError::NonzeroImaginaryPart));
+}
@@ -0,0 +1,176 @@
+//! quotient module
+use crate::traits::Ring;
+use crate::polynomial::Polynomial;
+
+/// Trait describing the quotient X^N ± 1 of a polynomial ring T[x]
+///
+/// The quotient decides what happens to a term which wraps around past x^(N - 1):
+/// the x^(N + i) term is folded back onto the x^i term using the value of x^N.
+pub trait Quotient<T> {
+    /// Whether x^N = -1 rather than x^N = 1. This cannot be read off `wrap(1)`, since -1 = 1 in
+    /// characteristic 2.
+    const NEGACYCLIC: bool;
+
+    /// Fold a coefficient of the x^(N + i) term onto the x^i term.
+    ///
+    /// # Arguments
+    /// * `value` - the coefficient of the x^(N + i) term
+    ///
+    /// # Output
+    /// * the value to add to the coefficient of the x^i term
+    /// ```
+    fn wrap(value: T) -> T;
+}
+
+/// Marker for the cyclic ring T[x]/(X^N - 1), where x^N = 1
+#[derive(Copy, Clone, Debug)]
+pub struct Cyclic;
+
+impl<T> Quotient<T> for Cyclic {
+    const NEGACYCLIC: bool = false;
+
+    fn wrap(value: T) -> T {
+        value
+    }
+}
+
+/// Marker for the negacyclic ring T[x]/(X^N + 1), where x^N = -1
+#[derive(Copy, Clone, Debug)]
+pub struct Negacyclic;
+
+impl<T: Ring> Quotient<T> for Negacyclic {
+    const NEGACYCLIC: bool = true;
+
+    fn wrap(value: T) -> T {
+        T::zero() - value
+    }
+}
+
+/// Structure representing the quotient ring T[x]/(f) for an arbitrary monic polynomial f(x)
+///
+/// Polynomials in the ring have `ring_degree` equal to the degree n of f, and are reduced by
+/// replacing x^n with -(f(x) - x^n).
+///
+/// # Attributes
+/// * `ring_degree` - the degree n of the modulus f
+/// * `modulus` - the coefficients of f, from the constant term up to the leading 1
+/// * `terms` - the nonzero coefficients of f below x^n, with their powers
+pub struct QuotientRing<T: Ring> {
+    pub ring_degree: usize,
+    pub modulus: Vec<T>,
+    terms: Vec<(usize, T)>
+}
+
+impl<T: Ring> QuotientRing<T> {
+
+    /// Return a new quotient ring modulo f.
+    ///
+    /// # Arguments
+    /// * `modulus` - Coefficients of f, from the constant term up to the leading 1.
+    ///
+    /// # Output
+    /// * a new instantiation of a QuotientRing
+    ///
+    /// # Panics if f is constant or not monic.
+    /// ```
+    pub fn new(modulus: Vec<T>) -> QuotientRing<T> {
+        if modulus.len() < 2 {
+            panic!("Modulus should have degree at least 1.");
+        }
+        let ring_degree = modulus.len() - 1;
+        if modulus[ring_degree] != T::one() {
+            panic!("Modulus should be monic.");
+        }
+
+        // Sparse moduli like x^p - x - 1 only need to touch a few coefficients per reduction step.
+        let mut terms: Vec<(usize, T)> = Vec::new();
+        for (i, coeff) in modulus.iter().enumerate().take(ring_degree) {
+            if *coeff != T::zero() {
+                terms.push((i, coeff.clone()));
+            }
+        }
+        QuotientRing {ring_degree, modulus, terms}
+    }
+
+    /// Reduce a polynomial of any degree modulo f.
+    ///
+    /// # Arguments
+    /// * `coeffs` - coefficients of the polynomial to reduce, from the constant term up
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial of degree less than n, equal to the input mod f
+    /// ```
+    pub fn reduce<Q: Quotient<T>>(&self, coeffs: &[T]) -> Polynomial<T, Q> {
+        let mut remainder: Vec<T> = coeffs.to_vec();
+        if remainder.len() < self.ring_degree {
+            remainder.resize(self.ring_degree, T::zero());
+        }
+
+        // Eliminate the x^k terms from the top down, using x^k = -(f(x) - x^n) * x^(k - n).
+        for k in (self.ring_degree..remainder.len()).rev() {
+            let top = remainder[k].clone();
+            for (i, coeff) in self.terms.iter() {
+                let index = k - self.ring_degree + i;
+                remainder[index] = remainder[index].clone() - top.mul_ref(coeff);
+            }
+        }
+        remainder.truncate(self.ring_degree);
+        Polynomial::from_coeffs(remainder)
+    }
+
+    /// Check that a polynomial belongs to the ring.
+    ///
+    /// # Panics if the ring degree of the polynomial does not match the degree of f.
+    /// ```
+    pub(crate) fn check_ring_degree<Q: Quotient<T>>(&self, poly: &Polynomial<T, Q>) {
+        if poly.ring_degree != self.ring_degree {
+            panic!("Ring degree should be equal to degree of modulus. {} != {}", poly.ring_degree,
+                   self.ring_degree);
+        }
+    }
+
+    /// Add two polynomials in the ring.
+    ///
+    /// # Arguments
+    /// * `poly1` - first polynomial to add
+    /// * `poly2` - second polynomial to add
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the sum of the two polynomials mod f
+    /// ```
+    pub fn add<Q: Quotient<T>>(&self, poly1: &Polynomial<T, Q>, poly2: &Polynomial<T, Q>)
+            -> Polynomial<T, Q> {
+        self.check_ring_degree(poly1);
+        self.check_ring_degree(poly2);
+        poly1.add(poly2)
+    }
+
+    /// Multiply two polynomials in the ring.
+    ///
+    /// # Arguments
+    /// * `poly1` - first polynomial to multiply
+    /// * `poly2` - second polynomial to multiply
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the product of the two polynomials mod f
+    /// ```
+    pub fn multiply<Q: Quotient<T>>(&self, poly1: &Polynomial<T, Q>, poly2: &Polynomial<T, Q>)
+            -> Polynomial<T, Q> {
+        self.check_ring_degree(poly1);
+        self.check_ring_degree(poly2);
+        poly1.check_coeff_length();
+        poly2.check_coeff_length();
+
+        // Compute the full product of degree up to 2n - 2, then reduce it.
+        let mut prod: Vec<T> = vec![T::zero(); 2 * self.ring_degree - 1];
+        for i in 0..self.ring_degree {
+            for j in 0..self.ring_degree {
+                prod[i + j] += poly1.coeffs[i].mul_ref(&poly2.coeffs[j]);
+            }
+        }
+        self.reduce(&prod)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,181 @@
+// Tests
+use super::*;
+use rand::Rng;
+use crate::ring::Complex;
+use crate::quotient::{Cyclic, Negacyclic};
+
+const MAX_TEST_DEGREE: usize = 64;
+
+// Coefficients in {0, 1} keep every product exact, so results can be compared for equality.
+fn generate_random_binary_coeffs(size: usize) -> Vec<f64> {
+    let mut rng = rand::thread_rng();
+    let mut rand_vec: Vec<f64> = Vec::new();
+    for _ in 0..size {
+        rand_vec.push(if rng.gen::<bool>() { 1.0 } else { 0.0 });
+    }
+    rand_vec
+}
+
+// NTRU Prime style modulus x^p - x - 1.
+fn ntru_prime_modulus(p: usize) -> Vec<f64> {
+    let mut modulus: Vec<f64> = vec![0.0; p + 1];
+    modulus[0] = -1.0;
+    modulus[1] = -1.0;
+    modulus[p] = 1.0;
+    modulus
+}
+
+#[test]
+#[should_panic(expected = "Modulus should be monic.")]
+fn test_new_not_monic_fail() {
+    QuotientRing::new(vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+#[should_panic(expected = "Modulus should have degree at least 1.")]
+fn test_new_constant_fail() {
+    QuotientRing::new(vec![1.0]);
+}
+
+#[test]
+fn test_reduce_known_answer() {
+    let ring = QuotientRing::new(ntru_prime_modulus(3));
+
+    // x^4 + x^3 = (x^2 + x) + (x + 1) = x^2 + 2x + 1 mod x^3 - x - 1
+    let reduced: Polynomial<f64> = ring.reduce(&[0.0, 0.0, 0.0, 1.0, 1.0]);
+
+    assert_eq!(reduced.ring_degree, 3);
+    assert_eq!(reduced.coeffs, vec![1.0, 2.0, 1.0]);
+}
+
+#[test]
+fn test_multiply_ntru_prime_known_answer() {
+    let ring = QuotientRing::new(ntru_prime_modulus(5));
+    let poly1: Polynomial<f64> = Polynomial::from_coeffs(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+    let poly2: Polynomial<f64> = Polynomial::from_coeffs(vec![4.0, 5.0, 4.0, 8.0, 9.0]);
+
+    let prod = poly1.multiply_mod(&poly2, &ring);
+
+    assert_eq!(prod.ring_degree, 5);
+    assert_eq!(prod.coeffs, vec![87.0, 175.0, 181.0, 168.0, 122.0]);
+}
+
+#[test]
+fn test_multiply_cyclotomic_known_answer() {
+    // 9th cyclotomic polynomial x^6 + x^3 + 1.
+    let ring = QuotientRing::new(vec![1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+    let poly1: Polynomial<f64> = Polynomial::from_coeffs(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let poly2: Polynomial<f64> = Polynomial::from_coeffs(vec![6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
+
+    let prod = ring.multiply(&poly1, &poly2);
+
+    assert_eq!(prod.ring_degree, 6);
+    assert_eq!(prod.coeffs, vec![-47.0, -27.0, 0.0, -20.0, 20.0, 59.0]);
+}
+
+#[test]
+fn test_multiply_complex_known_answer() {
+    // x^2 - i, so that x^2 = i.
+    let ring = QuotientRing::new(vec![Complex{real: 0.0, imag: -1.0}, Complex{real: 0.0, imag: 0.0},
+                                      Complex{real: 1.0, imag: 0.0}]);
+    let x: Polynomial<Complex<f64>> = Polynomial::from_coeffs(vec![Complex{real: 0.0, imag: 0.0},
+                                                                   Complex{real: 1.0, imag: 0.0}]);
+
+    let prod = ring.multiply(&x, &x);
+
+    assert_eq!(prod.coeffs, vec![Complex{real: 0.0, imag: 1.0}, Complex{real: 0.0, imag: 0.0}]);
+}
+
+#[test]
+#[should_panic(expected = "Ring degree should be equal to degree of modulus. 4 != 5")]
+fn test_multiply_wrong_ring_degree_fail() {
+    let ring = QuotientRing::new(ntru_prime_modulus(5));
+    let poly1: Polynomial<f64> = Polynomial::new(4);
+    let poly2: Polynomial<f64> = Polynomial::new(4);
+
+    // Should panic because the polynomials do not belong to the ring.
+    ring.multiply(&poly1, &poly2);
+}
+
+#[test]
+fn test_multiply_compare_cyclic() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let mut modulus: Vec<f64> = vec![0.0; ring_degree + 1];
+    modulus[0] = -1.0;
+    modulus[ring_degree] = 1.0;
+    let ring = QuotientRing::new(modulus);
+    let poly1: Polynomial<f64, Cyclic> = Polynomial::from_coeffs(generate_random_binary_coeffs(ring_degree));
+    let poly2: Polynomial<f64, Cyclic> = Polynomial::from_coeffs(generate_random_binary_coeffs(ring_degree));
+
+    // Test that reducing mod x^N - 1 matches the cyclic product.
+    let prod1 = poly1.multiply_mod(&poly2, &ring);
+    let prod2 = poly1.multiply(&poly2);
+
+    assert_eq!(prod1.coeffs, prod2.coeffs);
+}
+
+#[test]
+fn test_multiply_compare_negacyclic() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let mut modulus: Vec<f64> = vec![0.0; ring_degree + 1];
+    modulus[0] = 1.0;
+    modulus[ring_degree] = 1.0;
+    let ring = QuotientRing::new(modulus);
+    let poly1: Polynomial<f64, Negacyclic> = Polynomial::from_coeffs(generate_random_binary_coeffs(ring_degree));
+    let poly2: Polynomial<f64, Negacyclic> = Polynomial::from_coeffs(generate_random_binary_coeffs(ring_degree));
+
+    // Test that reducing mod x^N + 1 matches the negacyclic product.
+    let prod1 = poly1.multiply_mod(&poly2, &ring);
+    let prod2 = poly1.multiply(&poly2);
+
+    assert_eq!(prod1.coeffs, prod2.coeffs);
+}
+
+#[test]
+fn test_multiply_commutative() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(2..=MAX_TEST_DEGREE);
+    let ring = QuotientRing::new(ntru_prime_modulus(ring_degree));
+    let poly1: Polynomial<f64> = Polynomial::from_coeffs(generate_random_binary_coeffs(ring_degree));
+    let poly2: Polynomial<f64> = Polynomial::from_coeffs(generate_random_binary_coeffs(ring_degree));
+
+    // Test that p1 * p2 = p2 * p1.
+    let prod1 = ring.multiply(&poly1, &poly2);
+    let prod2 = ring.multiply(&poly2, &poly1);
+
+    assert_eq!(prod1.coeffs, prod2.coeffs);
+}
+
+#[test]
+fn test_multiply_associative() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(2..=MAX_TEST_DEGREE);
+    let ring = QuotientRing::new(ntru_prime_modulus(ring_degree));
+    let poly1: Polynomial<f64> = Polynomial::from_coeffs(generate_random_binary_coeffs(ring_degree));
+    let poly2: Polynomial<f64> = Polynomial::from_coeffs(generate_random_binary_coeffs(ring_degree));
+    let poly3: Polynomial<f64> = Polynomial::from_coeffs(generate_random_binary_coeffs(ring_degree));
+
+    // Test that (p1 * p2) * p3 = p1 * (p2 * p3).
+    let total_prod1 = ring.multiply(&ring.multiply(&poly1, &poly2), &poly3);
+    let total_prod2 = ring.multiply(&poly1, &ring.multiply(&poly2, &poly3));
+
+    assert_eq!(total_prod1.coeffs, total_prod2.coeffs);
+}
+
+#[test]
+fn test_distributive() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(2..=MAX_TEST_DEGREE);
+    let ring = QuotientRing::new(ntru_prime_modulus(ring_degree));
+    let poly1: Polynomial<f64> = Polynomial::from_coeffs(generate_random_binary_coeffs(ring_degree));
+    let poly2: Polynomial<f64> = Polynomial::from_coeffs(generate_random_binary_coeffs(ring_degree));
+    let poly3: Polynomial<f64> = Polynomial::from_coeffs(generate_random_binary_coeffs(ring_degree));
+
+    // Test that p1 * (p2 + p3) = p1 * p2 + p1 * p3
+    let total1 = ring.multiply(&poly1, &ring.add(&poly2, &poly3));
+    let total2 = ring.add(&ring.multiply(&poly1, &poly2), &ring.multiply(&poly1, &poly3));
+
+    assert_eq!(total1.coeffs, total2.coeffs);
+}
@@ -1,8 +1,14 @@
 //! Complex number module
-use std::ops::{Add, Sub, Mul, AddAssign};
+use std::ops::{Add, Sub, Mul, Neg, AddAssign, SubAssign, MulAssign};
 use std::cmp::{PartialEq, Eq};
 use std::fmt;
-use crate::traits::{Zero, One, Abs};
+use std::convert::TryFrom;
+use std::f64::consts::PI;
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+use crate::traits::{Zero, One, Abs, Inverse, Norm, Ring, CommutativeRing, Field, ModularRing};
+use crate::polynomial::Polynomial;
+use crate::quotient::Quotient;
+use crate::error::RingMathError;
 
 /// Structure representing a complex number
 ///
@@ -10,14 +16,12 @@ use crate::traits::{Zero, One, Abs};
 /// * `real` - Real part of complex number
 /// * `imag` - Imaginary part of complex number
 #[derive(Copy, Clone)]
-pub struct Complex<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T>
-                   + Zero<T> + One<T>+ Copy + Abs<T> + fmt::Debug + PartialEq> {
+pub struct Complex<T: Ring + Abs> {
     pub real: T,
     pub imag: T
 }
 
-impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero<T> + One<T>
-     + Copy + Abs<T> + fmt::Debug + PartialEq> Add for Complex<T> {
+impl<T: Ring + Abs> Add for Complex<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self::Output {
@@ -28,18 +32,14 @@ impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero<T> + One<T>
     }
 }
 
-impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero<T> + One<T>
-     + Copy + Abs<T> + fmt::Debug + PartialEq> AddAssign for Complex<T> {
+impl<T: Ring + Abs> AddAssign for Complex<T> {
     fn add_assign(&mut self, other: Self) {
-        *self = Self {
-            real: self.real + other.real,
-            imag: self.imag + other.imag,
-        };
+        self.real += other.real;
+        self.imag += other.imag;
     }
 }
 
-impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero<T> + One<T>
-     + Copy + Abs<T> + fmt::Debug + PartialEq> Sub for Complex<T> {
+impl<T: Ring + Abs> Sub for Complex<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self::Output {
@@ -50,41 +50,119 @@ impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero<T> + One<T>
     }
 }
 
-impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero<T> + One<T>
-     + Copy + Abs<T> + fmt::Debug + PartialEq> Mul for Complex<T> {
+impl<T: Ring + Abs> SubAssign for Complex<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = self.clone() - other;
+    }
+}
+
+impl<T: Ring + Abs> Neg for Complex<T> {
     type Output = Self;
 
-    fn mul(self, other: Self) -> Self::Output {
+    fn neg(self) -> Self::Output {
         Self {
-            real: self.real * other.real - self.imag * other.imag,
-            imag: self.real * other.imag + self.imag * other.real,
+            real: T::zero() - self.real,
+            imag: T::zero() - self.imag,
         }
     }
 }
 
-impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero<T> + One<T>
-     + Copy + Abs<T> + fmt::Debug + PartialEq> Zero<Complex<T>> for Complex<T> {
+impl<T: Ring + Abs> Mul<&Complex<T>> for &Complex<T> {
+    type Output = Complex<T>;
+
+    fn mul(self, other: &Complex<T>) -> Complex<T> {
+        Complex {
+            real: self.real.mul_ref(&other.real) - self.imag.mul_ref(&other.imag),
+            imag: self.real.mul_ref(&other.imag) + self.imag.mul_ref(&other.real),
+        }
+    }
+}
+
+impl<T: Ring + Abs> Mul for Complex<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        &self * &other
+    }
+}
+
+impl<T: Ring + Abs> MulAssign for Complex<T> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.clone() * other;
+    }
+}
+
+impl<T: Ring + Abs> Zero for Complex<T> {
     fn zero() -> Complex<T> {
-        return Complex{real: T::zero(), imag: T::zero()}
+        Complex{real: T::zero(), imag: T::zero()}
     }
 }
 
-impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero<T> + One<T>
-      + Copy + Abs<T> + fmt::Debug + PartialEq> One<Complex<T>> for Complex<T> {
+impl<T: Ring + Abs> One for Complex<T> {
     fn one() -> Complex<T> {
-        return Complex{real: T::one(), imag: T::zero()}
+        Complex{real: T::one(), imag: T::zero()}
+    }
+}
+
+impl<T: Field + Abs> Inverse for Complex<T> {
+    fn inverse(self) -> Complex<T> {
+        // 1 / (a + bi) = (a - bi) / (a^2 + b^2)
+        let norm_inv = (self.real.clone() * self.real.clone() + self.imag.clone() * self.imag.clone()).inverse();
+        Complex {
+            real: self.real * norm_inv.clone(),
+            imag: (T::zero() - self.imag) * norm_inv,
+        }
     }
 }
 
-impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero<T> + One<T>
-     + Copy + Abs<T> + fmt::Debug + PartialEq> Abs<Complex<T>> for Complex<T> {
+impl<T: Ring + Abs> Abs for Complex<T> {
     fn abs(self) -> f64 {
-        return T::abs(self.real * self.real + self.imag * self.imag);
+        T::abs(self.real.clone() * self.real + self.imag.clone() * self.imag)
+    }
+}
+
+impl Norm for Complex<f32> {
+    fn norm(self) -> f64 {
+        (self.real as f64).hypot(self.imag as f64)
+    }
+
+    fn embedding_norms(coeffs: &[Complex<f32>], negacyclic: bool) -> Vec<f64> {
+        let coeffs: Vec<(f64, f64)> = coeffs.iter().map(|coeff| (coeff.real as f64, coeff.imag as f64)).collect();
+        canonical_embedding_norms(&coeffs, negacyclic)
+    }
+}
+
+impl Norm for Complex<f64> {
+    fn norm(self) -> f64 {
+        self.real.hypot(self.imag)
+    }
+
+    fn embedding_norms(coeffs: &[Complex<f64>], negacyclic: bool) -> Vec<f64> {
+        let coeffs: Vec<(f64, f64)> = coeffs.iter().map(|coeff| (coeff.real, coeff.imag)).collect();
+        canonical_embedding_norms(&coeffs, negacyclic)
     }
 }
 
-impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero<T> + One<T>
-     + Copy + Abs<T> + fmt::Debug + PartialEq> fmt::Debug for Complex<T> {
+/// Return the magnitudes of the canonical embedding of a complex polynomial of degree N, i.e. of
+/// its values at the N roots of X^N - 1, or of X^N + 1 in the negacyclic ring.
+///
+/// The root exp(iπe/N) is computed from e mod 2N, so that large powers lose no precision.
+fn canonical_embedding_norms(coeffs: &[(f64, f64)], negacyclic: bool) -> Vec<f64> {
+    let n = coeffs.len();
+    (0..n).map(|j| {
+        let step = if negacyclic { 2 * j + 1 } else { 2 * j };
+        let (mut real, mut imag) = (0.0, 0.0);
+        for (i, &(coeff_real, coeff_imag)) in coeffs.iter().enumerate() {
+            let angle = PI * ((i * step) % (2 * n)) as f64 / n as f64;
+            let (sin, cos) = angle.sin_cos();
+            real += coeff_real * cos - coeff_imag * sin;
+            imag += coeff_real * sin + coeff_imag * cos;
+        }
+        real.hypot(imag)
+    }).collect()
+}
+
+impl<T: Ring + Abs> fmt::Debug for Complex<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Complex")
          .field("real", &self.real)
@@ -93,15 +171,21 @@ impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero<T> + One<T>
     }
 }
 
-impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero<T> + One<T>
-     + Copy + Abs<T> + fmt::Debug + PartialEq> PartialEq for Complex<T> {
+impl<T: Ring + Abs> PartialEq for Complex<T> {
     fn eq(&self, other: &Self) -> bool {
         self.real == other.real && self.imag == other.imag
     }
 }
 
-impl<T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Zero<T> + One<T>
-     + Copy + Abs<T> + fmt::Debug + PartialEq> Eq for Complex<T> {}
+impl<T: Ring + Abs> Eq for Complex<T> {}
+
+impl<T: Ring + Abs> Ring for Complex<T> {}
+
+impl<T: CommutativeRing + Abs> CommutativeRing for Complex<T> {}
+
+impl Field for Complex<f32> {}
+
+impl Field for Complex<f64> {}
 
 /// Structure representing an integer in mod 2^32
 ///
@@ -117,7 +201,7 @@ impl Add for ModInteger32 {
 
     fn add(self, other: Self) -> Self::Output {
         Self {
-            value: (self.value + other.value) % (2^32)
+            value: (self.value + other.value) % (1 << 32)
         }
     }
 }
@@ -125,24 +209,64 @@ impl Add for ModInteger32 {
 impl AddAssign for ModInteger32 {
     fn add_assign(&mut self, other: Self) {
         *self = Self {
-            value: (self.value + other.value) % (2^32)
+            value: (self.value + other.value) % (1 << 32)
         };
     }
 }
 
+impl Sub for ModInteger32 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            value: (self.value + (1 << 32) - other.value) % (1 << 32)
+        }
+    }
+}
+
+impl SubAssign for ModInteger32 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for ModInteger32 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            value: ((1 << 32) - self.value) % (1 << 32)
+        }
+    }
+}
+
 impl Mul for ModInteger32 {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self::Output {
         Self {
-            value: (self.value * other.value) % (2^32)
+            value: (self.value * other.value) % (1 << 32)
         }
     }
 }
 
-impl Zero<ModInteger32> for ModInteger32 {
+ref_mul_copy!(impl[] for ModInteger32);
+
+impl MulAssign for ModInteger32 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Zero for ModInteger32 {
     fn zero() -> ModInteger32 {
-        return ModInteger32{value: 0};
+        ModInteger32{value: 0}
+    }
+}
+
+impl One for ModInteger32 {
+    fn one() -> ModInteger32 {
+        ModInteger32{value: 1}
     }
 }
 
@@ -162,6 +286,47 @@ impl PartialEq for ModInteger32 {
 
 impl Eq for ModInteger32 {}
 
+impl Ring for ModInteger32 {}
+
+impl CommutativeRing for ModInteger32 {}
+
+impl ModInteger32 {
+    /// Return the centered lift of the integer.
+    ///
+    /// # Output
+    /// * the representative of the integer in (-q/2, q/2]
+    /// ```
+    pub fn centered(self) -> i64 {
+        if self.value > (1 << 31) {
+            self.value as i64 - 2 * (1 << 31)
+        } else {
+            self.value as i64
+        }
+    }
+}
+
+impl Norm for ModInteger32 {
+    fn norm(self) -> f64 {
+        // The centered representative of v lies in (-q/2, q/2], so its magnitude is min(v, q - v).
+        let value = self.value;
+        value.min((1 << 32) - value) as f64
+    }
+}
+
+impl ModularRing for ModInteger32 {
+    fn modulus() -> u128 {
+        1 << 32
+    }
+
+    fn residue(self) -> u128 {
+        self.value as u128
+    }
+
+    fn from_residue(residue: u128) -> ModInteger32 {
+        ModInteger32{value: residue as u64}
+    }
+}
+
 /// Structure representing an integer in mod 2^64
 ///
 /// # Attributes
@@ -176,7 +341,7 @@ impl Add for ModInteger64 {
 
     fn add(self, other: Self) -> Self::Output {
         Self {
-            value: (self.value + other.value) % (2^64)
+            value: (self.value + other.value) % (1 << 64)
         }
     }
 }
@@ -184,24 +349,64 @@ impl Add for ModInteger64 {
 impl AddAssign for ModInteger64 {
     fn add_assign(&mut self, other: Self) {
         *self = Self {
-            value: (self.value + other.value) % (2^64)
+            value: (self.value + other.value) % (1 << 64)
         };
     }
 }
 
+impl Sub for ModInteger64 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            value: (self.value + (1 << 64) - other.value) % (1 << 64)
+        }
+    }
+}
+
+impl SubAssign for ModInteger64 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for ModInteger64 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            value: ((1 << 64) - self.value) % (1 << 64)
+        }
+    }
+}
+
 impl Mul for ModInteger64 {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self::Output {
         Self {
-            value: (self.value * other.value) % (2^64)
+            value: (self.value * other.value) % (1 << 64)
         }
     }
 }
 
-impl Zero<ModInteger64> for ModInteger64 {
+ref_mul_copy!(impl[] for ModInteger64);
+
+impl MulAssign for ModInteger64 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Zero for ModInteger64 {
     fn zero() -> ModInteger64 {
-        return ModInteger64{value: 0};
+        ModInteger64{value: 0}
+    }
+}
+
+impl One for ModInteger64 {
+    fn one() -> ModInteger64 {
+        ModInteger64{value: 1}
     }
 }
 
@@ -221,5 +426,1161 @@ impl PartialEq for ModInteger64 {
 
 impl Eq for ModInteger64 {}
 
+impl Ring for ModInteger64 {}
+
+impl CommutativeRing for ModInteger64 {}
+
+impl ModInteger64 {
+    /// Return the centered lift of the integer.
+    ///
+    /// # Output
+    /// * the representative of the integer in (-q/2, q/2]
+    /// ```
+    pub fn centered(self) -> i128 {
+        if self.value > (1 << 63) {
+            self.value as i128 - 2 * (1 << 63)
+        } else {
+            self.value as i128
+        }
+    }
+}
+
+impl Norm for ModInteger64 {
+    fn norm(self) -> f64 {
+        // The centered representative of v lies in (-q/2, q/2], so its magnitude is min(v, q - v).
+        let value = self.value;
+        value.min((1 << 64) - value) as f64
+    }
+}
+
+impl ModularRing for ModInteger64 {
+    fn modulus() -> u128 {
+        1 << 64
+    }
+
+    fn residue(self) -> u128 {
+        self.value
+    }
+
+    fn from_residue(residue: u128) -> ModInteger64 {
+        ModInteger64{value: residue}
+    }
+}
+
+/// Structure representing an element of the torus R/Z, discretized to Z/2^32
+///
+/// The value v stands for the real number v / 2^32 in [0, 1). Arithmetic is the native wrapping
+/// arithmetic of `u32`, so reduction mod 2^32 is free.
+///
+/// # Attributes
+/// * `value` - Value of the element, as a multiple of 2^-32
+#[derive(Copy, Clone)]
+pub struct Torus32 {
+    pub value: u32
+}
+
+impl Add for Torus32 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            value: self.value.wrapping_add(other.value)
+        }
+    }
+}
+
+impl AddAssign for Torus32 {
+    fn add_assign(&mut self, other: Self) {
+        self.value = self.value.wrapping_add(other.value);
+    }
+}
+
+impl Sub for Torus32 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            value: self.value.wrapping_sub(other.value)
+        }
+    }
+}
+
+impl SubAssign for Torus32 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Torus32 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            value: self.value.wrapping_neg()
+        }
+    }
+}
+
+impl Mul for Torus32 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Self {
+            value: self.value.wrapping_mul(other.value)
+        }
+    }
+}
+
+ref_mul_copy!(impl[] for Torus32);
+
+impl MulAssign for Torus32 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Zero for Torus32 {
+    fn zero() -> Torus32 {
+        Torus32{value: 0}
+    }
+}
+
+impl One for Torus32 {
+    fn one() -> Torus32 {
+        Torus32{value: 1}
+    }
+}
+
+impl From<f64> for Torus32 {
+    /// Round a real number to the nearest element of the torus, taking it mod 1.
+    fn from(real: f64) -> Torus32 {
+        let fraction = real - real.floor();
+        // Rounding can give exactly 2^32, which wraps to 0.
+        Torus32{value: (fraction * 4294967296.0).round() as u64 as u32}
+    }
+}
+
+impl From<Torus32> for f64 {
+    /// Return the real number in [0, 1) represented by the element.
+    fn from(torus: Torus32) -> f64 {
+        torus.value as f64 / 4294967296.0
+    }
+}
+
+impl Distribution<Torus32> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Torus32 {
+        Torus32{value: rng.gen()}
+    }
+}
+
+impl fmt::Debug for Torus32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Torus32")
+         .field("value", &self.value)
+         .finish()
+    }
+}
+
+impl PartialEq for Torus32 {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for Torus32 {}
+
+impl Ring for Torus32 {}
+
+impl CommutativeRing for Torus32 {}
+
+impl Norm for Torus32 {
+    fn norm(self) -> f64 {
+        // Distance from the element to the nearest integer.
+        self.value.min(self.value.wrapping_neg()) as f64 / 4294967296.0
+    }
+}
+
+impl ModularRing for Torus32 {
+    fn modulus() -> u128 {
+        1 << 32
+    }
+
+    fn residue(self) -> u128 {
+        self.value as u128
+    }
+
+    fn from_residue(residue: u128) -> Torus32 {
+        Torus32{value: residue as u32}
+    }
+}
+
+/// Structure representing an element of the torus R/Z, discretized to Z/2^64
+///
+/// The value v stands for the real number v / 2^64 in [0, 1). Arithmetic is the native wrapping
+/// arithmetic of `u64`, so reduction mod 2^64 is free.
+///
+/// # Attributes
+/// * `value` - Value of the element, as a multiple of 2^-64
+#[derive(Copy, Clone)]
+pub struct Torus64 {
+    pub value: u64
+}
+
+impl Add for Torus64 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            value: self.value.wrapping_add(other.value)
+        }
+    }
+}
+
+impl AddAssign for Torus64 {
+    fn add_assign(&mut self, other: Self) {
+        self.value = self.value.wrapping_add(other.value);
+    }
+}
+
+impl Sub for Torus64 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            value: self.value.wrapping_sub(other.value)
+        }
+    }
+}
+
+impl SubAssign for Torus64 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Torus64 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            value: self.value.wrapping_neg()
+        }
+    }
+}
+
+impl Mul for Torus64 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Self {
+            value: self.value.wrapping_mul(other.value)
+        }
+    }
+}
+
+ref_mul_copy!(impl[] for Torus64);
+
+impl MulAssign for Torus64 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Zero for Torus64 {
+    fn zero() -> Torus64 {
+        Torus64{value: 0}
+    }
+}
+
+impl One for Torus64 {
+    fn one() -> Torus64 {
+        Torus64{value: 1}
+    }
+}
+
+impl From<f64> for Torus64 {
+    /// Round a real number to the nearest element of the torus, taking it mod 1.
+    fn from(real: f64) -> Torus64 {
+        let fraction = real - real.floor();
+        // Rounding can give exactly 2^64, which wraps to 0.
+        Torus64{value: (fraction * 18446744073709551616.0).round() as u128 as u64}
+    }
+}
+
+impl From<Torus64> for f64 {
+    /// Return the real number in [0, 1) represented by the element.
+    fn from(torus: Torus64) -> f64 {
+        torus.value as f64 / 18446744073709551616.0
+    }
+}
+
+impl Distribution<Torus64> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Torus64 {
+        Torus64{value: rng.gen()}
+    }
+}
+
+impl fmt::Debug for Torus64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Torus64")
+         .field("value", &self.value)
+         .finish()
+    }
+}
+
+impl PartialEq for Torus64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for Torus64 {}
+
+impl Ring for Torus64 {}
+
+impl CommutativeRing for Torus64 {}
+
+impl Norm for Torus64 {
+    fn norm(self) -> f64 {
+        // Distance from the element to the nearest integer.
+        self.value.min(self.value.wrapping_neg()) as f64 / 18446744073709551616.0
+    }
+}
+
+impl ModularRing for Torus64 {
+    fn modulus() -> u128 {
+        1 << 64
+    }
+
+    fn residue(self) -> u128 {
+        self.value as u128
+    }
+
+    fn from_residue(residue: u128) -> Torus64 {
+        Torus64{value: residue as u64}
+    }
+}
+
+/// Compute q^-1 mod 2^64 for an odd modulus q.
+pub(crate) const fn montgomery_inverse(modulus: u64) -> u64 {
+    // Newton iteration doubles the number of correct low bits each step, starting from 1 bit.
+    let mut inv: u64 = 1;
+    let mut i = 0;
+    while i < 6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(modulus.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv
+}
+
+/// Compute R mod q for R = 2^64.
+const fn montgomery_r(modulus: u64) -> u64 {
+    ((u64::MAX % modulus) + 1) % modulus
+}
+
+/// Compute R^2 mod q for R = 2^64.
+const fn montgomery_r2(modulus: u64) -> u64 {
+    let r = montgomery_r(modulus) as u128;
+    ((r * r) % modulus as u128) as u64
+}
+
+/// Montgomery reduction of t < q * 2^64.
+///
+/// # Arguments
+/// * `t` - Value to reduce.
+/// * `modulus` - Odd modulus q.
+/// * `modulus_inv` - q^-1 mod 2^64.
+///
+/// # Output
+/// * t / 2^64 mod q, in [0, q)
+/// ```
+fn montgomery_reduce(t: u128, modulus: u64, modulus_inv: u64) -> u64 {
+    // m * q agrees with t on the low 64 bits, so t - m * q is a multiple of 2^64.
+    let m = (t as u64).wrapping_mul(modulus_inv);
+    let t_high = (t >> 64) as u64;
+    let mq_high = ((m as u128 * modulus as u128) >> 64) as u64;
+    if t_high >= mq_high {
+        t_high - mq_high
+    } else {
+        t_high.wrapping_sub(mq_high).wrapping_add(modulus)
+    }
+}
+
+/// Add two residues in [0, q) without overflowing, even for q close to 2^64.
+fn add_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    let (sum, carry) = a.overflowing_add(b);
+    if carry || sum >= modulus { sum.wrapping_sub(modulus) } else { sum }
+}
+
+/// Subtract two residues in [0, q).
+fn sub_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    let (diff, borrow) = a.overflowing_sub(b);
+    if borrow { diff.wrapping_add(modulus) } else { diff }
+}
+
+/// Structure representing an integer mod a compile-time modulus Q
+///
+/// The modulus can be any odd number greater than 1, such as the NTT-friendly primes 12289, 3329
+/// or 2^64 - 2^32 + 1. The value is kept in Montgomery form, i.e. multiplied by R = 2^64, so that
+/// multiplication reduces with Montgomery reduction instead of `%` and never overflows `u128`.
+///
+/// # Attributes
+/// * `montgomery` - Value of the integer times R, mod Q
+#[derive(Copy, Clone)]
+pub struct ModInteger<const Q: u64> {
+    montgomery: u64
+}
+
+impl<const Q: u64> ModInteger<Q> {
+    /// The modulus must be odd for R = 2^64 to be invertible mod Q.
+    const VALID_MODULUS: () = assert!(Q % 2 == 1 && Q > 1, "Modulus should be an odd number greater than 1.");
+
+    /// Q^-1 mod 2^64.
+    const Q_INV: u64 = montgomery_inverse(Q);
+
+    /// R mod Q.
+    const R: u64 = montgomery_r(Q);
+
+    /// R^2 mod Q, used to move values into Montgomery form.
+    const R2: u64 = montgomery_r2(Q);
+
+    /// Return a new integer mod Q.
+    ///
+    /// # Arguments
+    /// * `value` - Value of the integer, which is reduced mod Q.
+    ///
+    /// # Output
+    /// * a new instantiation of a ModInteger
+    /// ```
+    pub fn new(value: u64) -> ModInteger<Q> {
+        let () = Self::VALID_MODULUS;
+        ModInteger{montgomery: montgomery_reduce((value % Q) as u128 * Self::R2 as u128, Q, Self::Q_INV)}
+    }
+
+    /// Return the modulus Q.
+    pub fn modulus() -> u64 {
+        Q
+    }
+
+    /// Return the value of the integer, in [0, Q).
+    pub fn value(self) -> u64 {
+        montgomery_reduce(self.montgomery as u128, Q, Self::Q_INV)
+    }
+
+    /// Return the centered lift of the integer.
+    ///
+    /// # Output
+    /// * the representative of the integer in (-Q/2, Q/2]
+    /// ```
+    pub fn centered(self) -> i64 {
+        let value = self.value();
+        if value > Q / 2 {
+            value as i64 - Q as i64
+        } else {
+            value as i64
+        }
+    }
+
+    /// Raise the integer to a power, by square and multiply.
+    ///
+    /// # Arguments
+    /// * `exponent` - the power to raise the integer to
+    ///
+    /// # Output
+    /// * a new instantiation of a ModInteger, equal to the integer to the power `exponent`
+    /// ```
+    pub fn pow(self, exponent: u64) -> ModInteger<Q> {
+        let mut result = ModInteger::one();
+        let mut base = self;
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Return the multiplicative inverse of the integer, by the extended Euclidean algorithm.
+    ///
+    /// # Output
+    /// * a new instantiation of a ModInteger, which is the inverse of the integer
+    ///
+    /// # Panics if the integer is not coprime to Q, such as 0, or 3 mod 15.
+    /// ```
+    pub fn inverse(self) -> ModInteger<Q> {
+        let value = self.value();
+        let (mut r0, mut r1) = (Q as i128, value as i128);
+        let (mut s0, mut s1) = (0_i128, 1_i128);
+        while r1 != 0 {
+            let quotient = r0 / r1;
+            (r0, r1) = (r1, r0 - quotient * r1);
+            (s0, s1) = (s1, s0 - quotient * s1);
+        }
+        if r0 != 1 {
+            panic!("Integer should be invertible. {} has no inverse mod {}", value, Q);
+        }
+        ModInteger::new(s0.rem_euclid(Q as i128) as u64)
+    }
+}
+
+impl<const Q: u64> Add for ModInteger<Q> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            montgomery: add_mod(self.montgomery, other.montgomery, Q)
+        }
+    }
+}
+
+impl<const Q: u64> AddAssign for ModInteger<Q> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const Q: u64> Sub for ModInteger<Q> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            montgomery: sub_mod(self.montgomery, other.montgomery, Q)
+        }
+    }
+}
+
+impl<const Q: u64> SubAssign for ModInteger<Q> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const Q: u64> Neg for ModInteger<Q> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::zero() - self
+    }
+}
+
+impl<const Q: u64> Mul for ModInteger<Q> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Self {
+            montgomery: montgomery_reduce(self.montgomery as u128 * other.montgomery as u128, Q, Self::Q_INV)
+        }
+    }
+}
+
+ref_mul_copy!(impl[const Q: u64] for ModInteger<Q>);
+
+impl<const Q: u64> MulAssign for ModInteger<Q> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<const Q: u64> Zero for ModInteger<Q> {
+    fn zero() -> ModInteger<Q> {
+        let () = Self::VALID_MODULUS;
+        ModInteger{montgomery: 0}
+    }
+}
+
+impl<const Q: u64> One for ModInteger<Q> {
+    fn one() -> ModInteger<Q> {
+        let () = Self::VALID_MODULUS;
+        ModInteger{montgomery: Self::R}
+    }
+}
+
+impl<const Q: u64> Inverse for ModInteger<Q> {
+    fn inverse(self) -> ModInteger<Q> {
+        ModInteger::inverse(self)
+    }
+}
+
+impl<const Q: u64> fmt::Debug for ModInteger<Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ModInteger")
+         .field("value", &self.value())
+         .field("modulus", &Q)
+         .finish()
+    }
+}
+
+impl<const Q: u64> PartialEq for ModInteger<Q> {
+    fn eq(&self, other: &Self) -> bool {
+        self.montgomery == other.montgomery
+    }
+}
+
+impl<const Q: u64> Eq for ModInteger<Q> {}
+
+impl<const Q: u64> Ring for ModInteger<Q> {}
+
+impl<const Q: u64> CommutativeRing for ModInteger<Q> {}
+
+/// Z/Q is only a field when Q is prime. For a composite Q, the field algorithms stay correct as
+/// long as every element they invert is a unit, and panic in `inverse` otherwise.
+impl<const Q: u64> Field for ModInteger<Q> {}
+
+impl<const Q: u64> Norm for ModInteger<Q> {
+    fn norm(self) -> f64 {
+        // The centered representative of v lies in (-q/2, q/2], so its magnitude is min(v, q - v).
+        let value = self.value();
+        value.min(Q - value) as f64
+    }
+}
+
+impl<const Q: u64> ModularRing for ModInteger<Q> {
+    fn modulus() -> u128 {
+        Q as u128
+    }
+
+    fn residue(self) -> u128 {
+        self.value() as u128
+    }
+
+    fn from_residue(residue: u128) -> ModInteger<Q> {
+        ModInteger::new(residue as u64)
+    }
+}
+
+/// Structure holding a modulus q chosen at runtime, with its precomputed Montgomery constants
+///
+/// Integers and polynomials created from a context borrow it, so they cannot outlive it, and
+/// arithmetic between integers of two contexts with different moduli panics.
+///
+/// # Attributes
+/// * `modulus` - the odd modulus q
+/// * `modulus_inv` - q^-1 mod 2^64
+/// * `r2` - R^2 mod q for R = 2^64
+#[derive(Debug)]
+pub struct ModulusContext {
+    pub modulus: u64,
+    modulus_inv: u64,
+    r2: u64
+}
+
+impl ModulusContext {
+
+    /// Return a new modulus context.
+    ///
+    /// # Arguments
+    /// * `modulus` - Odd modulus q greater than 1.
+    ///
+    /// # Output
+    /// * a new instantiation of a ModulusContext
+    ///
+    /// # Panics if q is even or less than 2.
+    /// ```
+    pub fn new(modulus: u64) -> ModulusContext {
+        if modulus & 1 == 0 || modulus < 2 {
+            panic!("Modulus should be an odd number greater than 1. {}", modulus);
+        }
+        ModulusContext {modulus,
+                        modulus_inv: montgomery_inverse(modulus),
+                        r2: montgomery_r2(modulus)}
+    }
+
+    /// Return a new integer mod q tied to this context.
+    ///
+    /// # Arguments
+    /// * `value` - Value of the integer, which is reduced mod q.
+    ///
+    /// # Output
+    /// * a new instantiation of a ContextModInteger
+    /// ```
+    pub fn integer(&self, value: u64) -> ContextModInteger<'_> {
+        ContextModInteger{residue: Residue::Bound(self.value_to_montgomery(value % self.modulus), self)}
+    }
+
+    /// Return a new polynomial with coefficients mod q tied to this context.
+    ///
+    /// # Arguments
+    /// * `values` - Values of the coefficients, which are reduced mod q.
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial
+    /// ```
+    pub fn polynomial<'a, Q: Quotient<ContextModInteger<'a>>>(&'a self, values: &[u64])
+            -> Polynomial<ContextModInteger<'a>, Q> {
+        Polynomial::from_coeffs(values.iter().map(|&value| self.integer(value)).collect())
+    }
+
+    /// Tie an integer to this context, reducing a context-free constant mod q.
+    ///
+    /// # Arguments
+    /// * `integer` - an integer tied to this context, or a context-free constant
+    ///
+    /// # Output
+    /// * the same integer, tied to this context
+    ///
+    /// # Panics if the integer is tied to a context with a different modulus.
+    /// ```
+    pub fn bind<'a>(&'a self, integer: ContextModInteger<'a>) -> ContextModInteger<'a> {
+        match integer.residue {
+            Residue::Constant(constant) => {
+                ContextModInteger{residue: Residue::Bound(self.constant_to_montgomery(constant), self)}
+            }
+            Residue::Bound(_, context) => {
+                if !context.same_modulus(self) {
+                    panic!("Modulus contexts should be equal. {} != {}", context.modulus, self.modulus);
+                }
+                integer
+            }
+        }
+    }
+
+    fn value_to_montgomery(&self, value: u64) -> u64 {
+        montgomery_reduce(value as u128 * self.r2 as u128, self.modulus, self.modulus_inv)
+    }
+
+    fn montgomery_to_value(&self, montgomery: u64) -> u64 {
+        montgomery_reduce(montgomery as u128, self.modulus, self.modulus_inv)
+    }
+}
+
+/// Trait for a modulus chosen at runtime, whose integers are kept in Montgomery form
+///
+/// It holds what the representation `Residue` needs to mix context-free constants with integers
+/// tied to a context, shared by `ContextModInteger` and `BigModInteger`.
+pub(crate) trait MontgomeryContext {
+    /// Montgomery form of a residue mod q.
+    type Montgomery: Copy + PartialEq;
+
+    /// Move a context-free constant into Montgomery form mod q.
+    fn constant_to_montgomery(&self, constant: i64) -> Self::Montgomery;
+
+    /// Return whether two contexts have the same modulus.
+    fn same_modulus(&self, other: &Self) -> bool;
+
+    /// Format the modulus for panic messages.
+    fn modulus_string(&self) -> String;
+
+    /// Return the magnitude of the centered representative of a residue, min(v, q - v).
+    fn centered_magnitude(&self, montgomery: Self::Montgomery) -> f64;
+}
+
+/// Representation of an integer mod a runtime modulus
+pub(crate) enum Residue<'a, C: MontgomeryContext> {
+    /// A context-free integer constant, such as `Zero::zero()` or `One::one()`. It is reduced
+    /// once it meets an integer tied to a context.
+    Constant(i64),
+    /// A residue in Montgomery form, tied to its modulus context.
+    Bound(C::Montgomery, &'a C)
+}
+
+impl<'a, C: MontgomeryContext> Clone for Residue<'a, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, C: MontgomeryContext> Copy for Residue<'a, C> {}
+
+impl<'a, C: MontgomeryContext> Residue<'a, C> {
+
+    /// Return the context of the residue, or None for a context-free constant.
+    pub(crate) fn context(self) -> Option<&'a C> {
+        match self {
+            Residue::Constant(_) => None,
+            Residue::Bound(_, context) => Some(context)
+        }
+    }
+
+    /// Combine two residues with an operation on Montgomery forms, or on constants if neither
+    /// residue has a context.
+    ///
+    /// # Panics if the residues are tied to contexts with different moduli, or if the operation
+    /// on two constants overflows, since a wrapped constant would not be congruent mod q.
+    /// ```
+    pub(crate) fn combine(self, other: Self, bound_op: fn(C::Montgomery, C::Montgomery, &C) -> C::Montgomery,
+                          constant_op: fn(i64, i64) -> Option<i64>, symbol: char) -> Self {
+        let (a, b, context) = match (self, other) {
+            (Residue::Constant(a), Residue::Constant(b)) => {
+                let constant = constant_op(a, b).unwrap_or_else(|| {
+                    panic!("Integer arithmetic should not overflow. {} {} {}", a, symbol, b)
+                });
+                return Residue::Constant(constant);
+            }
+            (Residue::Bound(a, context), Residue::Constant(b)) => (a, context.constant_to_montgomery(b), context),
+            (Residue::Constant(a), Residue::Bound(b, context)) => (context.constant_to_montgomery(a), b, context),
+            (Residue::Bound(a, context1), Residue::Bound(b, context2)) => {
+                if !context1.same_modulus(context2) {
+                    panic!("Modulus contexts should be equal. {} != {}", context1.modulus_string(),
+                           context2.modulus_string());
+                }
+                (a, b, context1)
+            }
+        };
+        Residue::Bound(bound_op(a, b, context), context)
+    }
+
+    /// Return the magnitude of the centered representative, or of the constant.
+    pub(crate) fn norm(self) -> f64 {
+        match self {
+            Residue::Constant(constant) => constant.unsigned_abs() as f64,
+            Residue::Bound(montgomery, context) => context.centered_magnitude(montgomery)
+        }
+    }
+}
+
+impl<'a, C: MontgomeryContext> PartialEq for Residue<'a, C> {
+    fn eq(&self, other: &Self) -> bool {
+        match (*self, *other) {
+            (Residue::Constant(a), Residue::Constant(b)) => a == b,
+            (Residue::Bound(a, context), Residue::Constant(b)) |
+            (Residue::Constant(b), Residue::Bound(a, context)) => a == context.constant_to_montgomery(b),
+            (Residue::Bound(a, context1), Residue::Bound(b, context2)) => {
+                context1.same_modulus(context2) && a == b
+            }
+        }
+    }
+}
+
+impl MontgomeryContext for ModulusContext {
+    type Montgomery = u64;
+
+    fn constant_to_montgomery(&self, constant: i64) -> u64 {
+        let reduced = (constant as i128).rem_euclid(self.modulus as i128) as u64;
+        self.value_to_montgomery(reduced)
+    }
+
+    fn same_modulus(&self, other: &ModulusContext) -> bool {
+        self.modulus == other.modulus
+    }
+
+    fn modulus_string(&self) -> String {
+        self.modulus.to_string()
+    }
+
+    fn centered_magnitude(&self, montgomery: u64) -> f64 {
+        let value = self.montgomery_to_value(montgomery);
+        value.min(self.modulus - value) as f64
+    }
+}
+
+/// Structure representing an integer mod a runtime modulus q held by a `ModulusContext`
+///
+/// # Attributes
+/// * `residue` - Montgomery form of the integer with its context, or a context-free constant
+#[derive(Copy, Clone)]
+pub struct ContextModInteger<'a> {
+    residue: Residue<'a, ModulusContext>
+}
+
+impl<'a> ContextModInteger<'a> {
+
+    /// Return the context of the integer, or None for a context-free constant.
+    pub fn context(self) -> Option<&'a ModulusContext> {
+        self.residue.context()
+    }
+
+    /// Return the value of the integer, in [0, q).
+    ///
+    /// # Panics if the integer is a context-free constant.
+    /// ```
+    pub fn value(self) -> u64 {
+        match self.residue {
+            Residue::Constant(constant) => {
+                panic!("Integer should be tied to a modulus context. {} has none", constant)
+            }
+            Residue::Bound(montgomery, context) => context.montgomery_to_value(montgomery)
+        }
+    }
+}
+
+impl<'a> Add for ContextModInteger<'a> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        let residue = self.residue.combine(other.residue, |a, b, context| add_mod(a, b, context.modulus),
+                                           i64::checked_add, '+');
+        ContextModInteger{residue}
+    }
+}
+
+impl<'a> AddAssign for ContextModInteger<'a> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<'a> Sub for ContextModInteger<'a> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        let residue = self.residue.combine(other.residue, |a, b, context| sub_mod(a, b, context.modulus),
+                                           i64::checked_sub, '-');
+        ContextModInteger{residue}
+    }
+}
+
+impl<'a> SubAssign for ContextModInteger<'a> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<'a> Neg for ContextModInteger<'a> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::zero() - self
+    }
+}
+
+impl<'a> Mul for ContextModInteger<'a> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        let residue = self.residue.combine(other.residue, |a, b, context| {
+            montgomery_reduce(a as u128 * b as u128, context.modulus, context.modulus_inv)
+        }, i64::checked_mul, '*');
+        ContextModInteger{residue}
+    }
+}
+
+ref_mul_copy!(impl['a] for ContextModInteger<'a>);
+
+impl<'a> MulAssign for ContextModInteger<'a> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<'a> Zero for ContextModInteger<'a> {
+    fn zero() -> ContextModInteger<'a> {
+        ContextModInteger{residue: Residue::Constant(0)}
+    }
+}
+
+impl<'a> One for ContextModInteger<'a> {
+    fn one() -> ContextModInteger<'a> {
+        ContextModInteger{residue: Residue::Constant(1)}
+    }
+}
+
+impl<'a> fmt::Debug for ContextModInteger<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.residue {
+            Residue::Constant(constant) => {
+                f.debug_struct("ContextModInteger")
+                 .field("constant", &constant)
+                 .finish()
+            }
+            Residue::Bound(montgomery, context) => {
+                f.debug_struct("ContextModInteger")
+                 .field("value", &context.montgomery_to_value(montgomery))
+                 .field("modulus", &context.modulus)
+                 .finish()
+            }
+        }
+    }
+}
+
+impl<'a> PartialEq for ContextModInteger<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.residue == other.residue
+    }
+}
+
+impl<'a> Eq for ContextModInteger<'a> {}
+
+impl<'a> Ring for ContextModInteger<'a> {}
+
+impl<'a> CommutativeRing for ContextModInteger<'a> {}
+
+impl<'a> Norm for ContextModInteger<'a> {
+    fn norm(self) -> f64 {
+        self.residue.norm()
+    }
+}
+
+impl<T: Ring + Abs> From<T> for Complex<T> {
+    /// Return the complex number with the given real part and a zero imaginary part.
+    fn from(real: T) -> Complex<T> {
+        Complex{real, imag: T::zero()}
+    }
+}
+
+impl From<Complex<f32>> for Complex<f64> {
+    fn from(complex: Complex<f32>) -> Complex<f64> {
+        Complex{real: complex.real as f64, imag: complex.imag as f64}
+    }
+}
+
+impl TryFrom<Complex<f32>> for f32 {
+    type Error = RingMathError;
+
+    /// Return the real part of a complex number, which should have a zero imaginary part.
+    fn try_from(complex: Complex<f32>) -> Result<f32, RingMathError> {
+        if complex.imag != 0.0 {
+            return Err(RingMathError::NonzeroImaginaryPart);
+        }
+        Ok(complex.real)
+    }
+}
+
+impl TryFrom<Complex<f64>> for f64 {
+    type Error = RingMathError;
+
+    /// Return the real part of a complex number, which should have a zero imaginary part.
+    fn try_from(complex: Complex<f64>) -> Result<f64, RingMathError> {
+        if complex.imag != 0.0 {
+            return Err(RingMathError::NonzeroImaginaryPart);
+        }
+        Ok(complex.real)
+    }
+}
+
+/// Round a real number to the nearest integer and reduce it mod 2^bits, for bits at most 64.
+///
+/// The floating point remainder is exact, so large values reduce correctly, and NaN maps to 0.
+fn round_mod_power_of_two(real: f64, bits: u32) -> u128 {
+    let modulus = (1_u128 << bits) as f64;
+    ((real.round() % modulus) as i128).rem_euclid(1 << bits) as u128
+}
+
+impl From<ModInteger32> for f32 {
+    /// Return the centered lift of the integer, in (-q/2, q/2].
+    fn from(integer: ModInteger32) -> f32 {
+        integer.centered() as f32
+    }
+}
+
+impl From<ModInteger32> for f64 {
+    /// Return the centered lift of the integer, in (-q/2, q/2].
+    fn from(integer: ModInteger32) -> f64 {
+        integer.centered() as f64
+    }
+}
+
+impl From<ModInteger32> for Complex<f32> {
+    /// Return the centered lift of the integer, in (-q/2, q/2], as a real complex number.
+    fn from(integer: ModInteger32) -> Complex<f32> {
+        Complex::from(f32::from(integer))
+    }
+}
+
+impl From<ModInteger32> for Complex<f64> {
+    /// Return the centered lift of the integer, in (-q/2, q/2], as a real complex number.
+    fn from(integer: ModInteger32) -> Complex<f64> {
+        Complex::from(f64::from(integer))
+    }
+}
+
+impl From<f32> for ModInteger32 {
+    /// Round a real number to the nearest integer, taking it mod 2^32.
+    fn from(real: f32) -> ModInteger32 {
+        ModInteger32{value: round_mod_power_of_two(real as f64, 32) as u64}
+    }
+}
+
+impl From<f64> for ModInteger32 {
+    /// Round a real number to the nearest integer, taking it mod 2^32.
+    fn from(real: f64) -> ModInteger32 {
+        ModInteger32{value: round_mod_power_of_two(real, 32) as u64}
+    }
+}
+
+impl TryFrom<Complex<f32>> for ModInteger32 {
+    type Error = RingMathError;
+
+    /// Round a complex number with a zero imaginary part to the nearest integer, taking it mod 2^32.
+    fn try_from(complex: Complex<f32>) -> Result<ModInteger32, RingMathError> {
+        f32::try_from(complex).map(ModInteger32::from)
+    }
+}
+
+impl TryFrom<Complex<f64>> for ModInteger32 {
+    type Error = RingMathError;
+
+    /// Round a complex number with a zero imaginary part to the nearest integer, taking it mod 2^32.
+    fn try_from(complex: Complex<f64>) -> Result<ModInteger32, RingMathError> {
+        f64::try_from(complex).map(ModInteger32::from)
+    }
+}
+
+impl From<ModInteger64> for f32 {
+    /// Return the centered lift of the integer, in (-q/2, q/2].
+    fn from(integer: ModInteger64) -> f32 {
+        integer.centered() as f32
+    }
+}
+
+impl From<ModInteger64> for f64 {
+    /// Return the centered lift of the integer, in (-q/2, q/2].
+    fn from(integer: ModInteger64) -> f64 {
+        integer.centered() as f64
+    }
+}
+
+impl From<ModInteger64> for Complex<f32> {
+    /// Return the centered lift of the integer, in (-q/2, q/2], as a real complex number.
+    fn from(integer: ModInteger64) -> Complex<f32> {
+        Complex::from(f32::from(integer))
+    }
+}
+
+impl From<ModInteger64> for Complex<f64> {
+    /// Return the centered lift of the integer, in (-q/2, q/2], as a real complex number.
+    fn from(integer: ModInteger64) -> Complex<f64> {
+        Complex::from(f64::from(integer))
+    }
+}
+
+impl From<f32> for ModInteger64 {
+    /// Round a real number to the nearest integer, taking it mod 2^64.
+    fn from(real: f32) -> ModInteger64 {
+        ModInteger64{value: round_mod_power_of_two(real as f64, 64)}
+    }
+}
+
+impl From<f64> for ModInteger64 {
+    /// Round a real number to the nearest integer, taking it mod 2^64.
+    fn from(real: f64) -> ModInteger64 {
+        ModInteger64{value: round_mod_power_of_two(real, 64)}
+    }
+}
+
+impl TryFrom<Complex<f32>> for ModInteger64 {
+    type Error = RingMathError;
+
+    /// Round a complex number with a zero imaginary part to the nearest integer, taking it mod 2^64.
+    fn try_from(complex: Complex<f32>) -> Result<ModInteger64, RingMathError> {
+        f32::try_from(complex).map(ModInteger64::from)
+    }
+}
+
+impl TryFrom<Complex<f64>> for ModInteger64 {
+    type Error = RingMathError;
+
+    /// Round a complex number with a zero imaginary part to the nearest integer, taking it mod 2^64.
+    fn try_from(complex: Complex<f64>) -> Result<ModInteger64, RingMathError> {
+        f64::try_from(complex).map(ModInteger64::from)
+    }
+}
+
+impl From<ModInteger64> for ModInteger32 {
+    /// Reduce an integer mod 2^64 to an integer mod 2^32.
+    fn from(integer: ModInteger64) -> ModInteger32 {
+        ModInteger32{value: (integer.value & 0xffff_ffff) as u64}
+    }
+}
+
+impl From<ModInteger32> for ModInteger64 {
+    /// Reduce the representative in [0, 2^32) of an integer mod 2^32 to an integer mod 2^64.
+    fn from(integer: ModInteger32) -> ModInteger64 {
+        ModInteger64{value: integer.value as u128}
+    }
+}
+
 #[cfg(test)]
 mod tests;
\ No newline at end of file
@@ -0,0 +1,428 @@
+// Tests
+use super::*;
+use rand::Rng;
+
+const NUM_RANDOM_TESTS: usize = 1000;
+const KYBER_Q: u64 = 3329;
+const FALCON_Q: u64 = 12289;
+const GOLDILOCKS_Q: u64 = 0xffff_ffff_0000_0001;
+const LARGEST_PRIME_Q: u64 = 0xffff_ffff_ffff_ffc5;
+
+#[test]
+fn test_modint32_wraps_at_2_32() {
+    let max = ModInteger32{value: (1 << 32) - 1};
+    let one = ModInteger32{value: 1};
+    let half = ModInteger32{value: 1 << 16};
+
+    assert_eq!(max + one, ModInteger32::zero());
+    assert_eq!(half * half, ModInteger32::zero());
+    assert_eq!(max * max, ModInteger32::one());
+}
+
+#[test]
+fn test_modint64_wraps_at_2_64() {
+    let max = ModInteger64{value: (1 << 64) - 1};
+    let one = ModInteger64{value: 1};
+    let half = ModInteger64{value: 1 << 32};
+
+    assert_eq!(max + one, ModInteger64::zero());
+    assert_eq!(half * half, ModInteger64::zero());
+    assert_eq!(max * max, ModInteger64::one());
+}
+
+#[test]
+fn test_modint_known_answer() {
+    let minus_one: ModInteger<FALCON_Q> = ModInteger::new(FALCON_Q - 1);
+    let two: ModInteger<FALCON_Q> = ModInteger::new(FALCON_Q + 2);
+
+    assert_eq!(two.value(), 2);
+    assert_eq!((minus_one * minus_one).value(), 1);
+    assert_eq!((minus_one + two).value(), 1);
+    assert_eq!((two - minus_one).value(), 3);
+    assert_eq!((-two).value(), FALCON_Q - 2);
+    assert_eq!(ModInteger::<FALCON_Q>::one().value(), 1);
+    assert_eq!(ModInteger::<FALCON_Q>::zero().value(), 0);
+    assert_eq!(ModInteger::<FALCON_Q>::modulus(), FALCON_Q);
+}
+
+fn test_modint_compare_u128<const Q: u64>() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUM_RANDOM_TESTS {
+        let a = rng.gen_range(0..Q);
+        let b = rng.gen_range(0..Q);
+        let mod_a: ModInteger<Q> = ModInteger::new(a);
+        let mod_b: ModInteger<Q> = ModInteger::new(b);
+        let (a, b, q) = (a as u128, b as u128, Q as u128);
+
+        assert_eq!((mod_a + mod_b).value() as u128, (a + b) % q);
+        assert_eq!((mod_a - mod_b).value() as u128, (a + q - b) % q);
+        assert_eq!((mod_a * mod_b).value() as u128, (a * b) % q);
+        assert_eq!((-mod_a).value() as u128, (q - a) % q);
+    }
+}
+
+#[test]
+fn test_modint_compare_u128_kyber() {
+    test_modint_compare_u128::<KYBER_Q>();
+}
+
+#[test]
+fn test_modint_compare_u128_falcon() {
+    test_modint_compare_u128::<FALCON_Q>();
+}
+
+#[test]
+fn test_modint_compare_u128_goldilocks() {
+    test_modint_compare_u128::<GOLDILOCKS_Q>();
+}
+
+#[test]
+fn test_modint_compare_u128_largest_prime() {
+    test_modint_compare_u128::<LARGEST_PRIME_Q>();
+}
+
+#[test]
+#[should_panic(expected = "Modulus should be an odd number greater than 1. 4096")]
+fn test_modulus_context_even_fail() {
+    ModulusContext::new(4096);
+}
+
+#[test]
+fn test_context_modint_known_answer() {
+    let context = ModulusContext::new(FALCON_Q);
+    let minus_one = context.integer(FALCON_Q - 1);
+    let two = context.integer(FALCON_Q + 2);
+
+    assert_eq!(two.value(), 2);
+    assert_eq!((minus_one * minus_one).value(), 1);
+    assert_eq!((minus_one + two).value(), 1);
+    assert_eq!((two - minus_one).value(), 3);
+    assert_eq!((-two).value(), FALCON_Q - 2);
+    assert_eq!(two.context().unwrap().modulus, FALCON_Q);
+}
+
+#[test]
+fn test_context_modint_constants() {
+    let context = ModulusContext::new(FALCON_Q);
+    let five = context.integer(5);
+
+    // Constants pick up the context of the integer they are combined with.
+    assert_eq!((five + ContextModInteger::one()).value(), 6);
+    assert_eq!((ContextModInteger::zero() - five).value(), FALCON_Q - 5);
+    assert_eq!((five * ContextModInteger::zero()).value(), 0);
+    assert_eq!(context.integer(1), ContextModInteger::one());
+    assert_eq!(context.integer(0), ContextModInteger::zero());
+    assert!(ContextModInteger::one().context().is_none());
+}
+
+#[test]
+#[should_panic(expected = "Integer should be tied to a modulus context. 1 has none")]
+fn test_context_modint_constant_value_fail() {
+    ContextModInteger::one().value();
+}
+
+#[test]
+#[should_panic(expected = "Modulus contexts should be equal. 12289 != 3329")]
+fn test_context_modint_mixed_contexts_fail() {
+    let context1 = ModulusContext::new(FALCON_Q);
+    let context2 = ModulusContext::new(KYBER_Q);
+
+    // Should panic because the integers belong to different contexts.
+    let _ = context1.integer(1) + context2.integer(1);
+}
+
+#[test]
+#[should_panic(expected = "Integer arithmetic should not overflow. 4611686018427387904 + 4611686018427387904")]
+fn test_context_modint_constant_overflow_fail() {
+    // Doubling one 63 times overflows an i64, which would not be congruent mod q once wrapped.
+    let mut constant = ContextModInteger::one();
+    for _ in 0..63 {
+        constant = constant + constant;
+    }
+}
+
+fn test_context_modint_compare_modint<const Q: u64>() {
+    let context = ModulusContext::new(Q);
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUM_RANDOM_TESTS {
+        let a = rng.gen_range(0..Q);
+        let b = rng.gen_range(0..Q);
+        let (mod_a, mod_b): (ModInteger<Q>, ModInteger<Q>) = (ModInteger::new(a), ModInteger::new(b));
+        let (context_a, context_b) = (context.integer(a), context.integer(b));
+
+        assert_eq!((context_a + context_b).value(), (mod_a + mod_b).value());
+        assert_eq!((context_a - context_b).value(), (mod_a - mod_b).value());
+        assert_eq!((context_a * context_b).value(), (mod_a * mod_b).value());
+        assert_eq!((-context_a).value(), (-mod_a).value());
+    }
+}
+
+#[test]
+fn test_context_modint_compare_modint_kyber() {
+    test_context_modint_compare_modint::<KYBER_Q>();
+}
+
+#[test]
+fn test_context_modint_compare_modint_goldilocks() {
+    test_context_modint_compare_modint::<GOLDILOCKS_Q>();
+}
+
+#[test]
+fn test_torus32_wraps_at_2_32() {
+    let max = Torus32{value: u32::MAX};
+    let one = Torus32::one();
+
+    assert_eq!(max + one, Torus32::zero());
+    assert_eq!(Torus32::zero() - one, max);
+    assert_eq!(-one, max);
+    assert_eq!(max * max, one);
+}
+
+#[test]
+fn test_torus64_wraps_at_2_64() {
+    let max = Torus64{value: u64::MAX};
+    let one = Torus64::one();
+
+    assert_eq!(max + one, Torus64::zero());
+    assert_eq!(Torus64::zero() - one, max);
+    assert_eq!(-one, max);
+    assert_eq!(max * max, one);
+}
+
+#[test]
+fn test_torus32_f64_known_answer() {
+    assert_eq!(Torus32::from(0.5), Torus32{value: 1 << 31});
+    assert_eq!(Torus32::from(-0.25), Torus32{value: 3 << 30});
+    assert_eq!(Torus32::from(1.75), Torus32{value: 3 << 30});
+    assert_eq!(Torus32::from(1.0 - 1e-12), Torus32::zero());
+    assert_eq!(f64::from(Torus32{value: 1 << 30}), 0.25);
+}
+
+#[test]
+fn test_torus64_f64_known_answer() {
+    assert_eq!(Torus64::from(0.5), Torus64{value: 1 << 63});
+    assert_eq!(Torus64::from(-0.25), Torus64{value: 3 << 62});
+    assert_eq!(Torus64::from(1.0 - 1e-18), Torus64::zero());
+    assert_eq!(f64::from(Torus64{value: 1 << 62}), 0.25);
+}
+
+#[test]
+fn test_torus_f64_round_trip() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUM_RANDOM_TESTS {
+        let real: f64 = rng.gen();
+        let torus32 = Torus32::from(real);
+        let torus64 = Torus64::from(real);
+
+        assert!((f64::from(torus32) - real).abs() <= 1.0 / 4294967296.0);
+        assert!((f64::from(torus64) - real).abs() <= f64::EPSILON);
+        assert_eq!(Torus32::from(f64::from(torus32)), torus32);
+    }
+}
+
+#[test]
+fn test_modint32_sub_neg_known_answer() {
+    let one = ModInteger32::one();
+    let two = ModInteger32{value: 2};
+
+    assert_eq!(one - two, ModInteger32{value: (1 << 32) - 1});
+    assert_eq!(two - one, one);
+    assert_eq!(-one, ModInteger32{value: (1 << 32) - 1});
+    assert_eq!(-ModInteger32::zero(), ModInteger32::zero());
+}
+
+#[test]
+fn test_modint64_sub_neg_known_answer() {
+    let one = ModInteger64::one();
+    let two = ModInteger64{value: 2};
+
+    assert_eq!(one - two, ModInteger64{value: (1 << 64) - 1});
+    assert_eq!(two - one, one);
+    assert_eq!(-one, ModInteger64{value: (1 << 64) - 1});
+    assert_eq!(-ModInteger64::zero(), ModInteger64::zero());
+}
+
+#[test]
+fn test_complex_sub_neg_known_answer() {
+    let a = Complex{real: 1.0, imag: 2.0};
+    let b = Complex{real: 3.0, imag: -1.0};
+
+    assert_eq!(a - b, Complex{real: -2.0, imag: 3.0});
+    assert_eq!(-a, Complex{real: -1.0, imag: -2.0});
+}
+
+fn test_assign_matches<T>(a: T, b: T)
+        where T: Ring + SubAssign + MulAssign {
+    let mut sum = a.clone();
+    sum += b.clone();
+    let mut diff = a.clone();
+    diff -= b.clone();
+    let mut prod = a.clone();
+    prod *= b.clone();
+
+    assert_eq!(sum, a.clone() + b.clone());
+    assert_eq!(diff, a.clone() - b.clone());
+    assert_eq!(prod, a * b);
+}
+
+#[test]
+fn test_assign_matches_all_types() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUM_RANDOM_TESTS {
+        test_assign_matches(Complex{real: rng.gen::<f64>(), imag: rng.gen::<f64>()},
+                            Complex{real: rng.gen::<f64>(), imag: rng.gen::<f64>()});
+        test_assign_matches(ModInteger32{value: rng.gen_range(0..(1 << 32))},
+                            ModInteger32{value: rng.gen_range(0..(1 << 32))});
+        test_assign_matches(ModInteger64{value: rng.gen_range(0..(1 << 64))},
+                            ModInteger64{value: rng.gen_range(0..(1 << 64))});
+        test_assign_matches(ModInteger::<FALCON_Q>::new(rng.gen()), ModInteger::<FALCON_Q>::new(rng.gen()));
+        test_assign_matches(rng.gen::<Torus32>(), rng.gen::<Torus32>());
+        test_assign_matches(rng.gen::<Torus64>(), rng.gen::<Torus64>());
+
+        let context = ModulusContext::new(KYBER_Q);
+        test_assign_matches(context.integer(rng.gen()), context.integer(rng.gen()));
+    }
+}
+
+#[test]
+fn test_modint_pow_inverse() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUM_RANDOM_TESTS {
+        let a: ModInteger<FALCON_Q> = ModInteger::new(rng.gen_range(1..FALCON_Q));
+        let b: ModInteger<LARGEST_PRIME_Q> = ModInteger::new(rng.gen_range(1..LARGEST_PRIME_Q));
+
+        assert_eq!(a * a.inverse(), ModInteger::one());
+        assert_eq!(b * b.inverse(), ModInteger::one());
+        assert_eq!(a.pow(3), a * a * a);
+        assert_eq!(a.pow(0), ModInteger::one());
+    }
+    assert_eq!(ModInteger::<FALCON_Q>::new(2).pow(14), ModInteger::new(16384 - FALCON_Q));
+}
+
+#[test]
+#[should_panic(expected = "Integer should be invertible. 0 has no inverse mod 12289")]
+fn test_modint_inverse_zero_fail() {
+    // Should panic because 0 is not invertible.
+    ModInteger::<FALCON_Q>::zero().inverse();
+}
+
+#[test]
+fn test_modint_inverse_composite_modulus() {
+    // Fermat's x^(Q - 2) is not an inverse for a composite Q, but every unit still has one.
+    assert_eq!(ModInteger::<15>::new(2).inverse(), ModInteger::new(8));
+    for value in [1, 2, 4, 7, 8, 11, 13, 14] {
+        let a = ModInteger::<15>::new(value);
+        assert_eq!(a * a.inverse(), ModInteger::one());
+    }
+}
+
+#[test]
+#[should_panic(expected = "Integer should be invertible. 3 has no inverse mod 15")]
+fn test_modint_inverse_zero_divisor_fail() {
+    // Should panic because 3 divides 15.
+    ModInteger::<15>::new(3).inverse();
+}
+
+#[test]
+fn test_complex_inverse() {
+    let a = Complex{real: 3.0, imag: -4.0};
+
+    // 1 / (3 - 4i) = (3 + 4i) / 25
+    assert_eq!(Inverse::inverse(a), Complex{real: 0.12, imag: 0.16});
+    assert_eq!(a * Inverse::inverse(a), Complex::one());
+}
+
+#[test]
+fn test_centered_known_answer() {
+    assert_eq!(ModInteger32{value: (1 << 32) - 3}.centered(), -3);
+    assert_eq!(ModInteger32{value: 1 << 31}.centered(), 1 << 31);
+    assert_eq!(ModInteger32{value: (1 << 31) + 1}.centered(), 1 - (1 << 31));
+    assert_eq!(ModInteger64{value: (1 << 64) - 1}.centered(), -1);
+    assert_eq!(ModInteger64{value: 1 << 63}.centered(), 1 << 63);
+    assert_eq!(ModInteger::<FALCON_Q>::new(6144).centered(), 6144);
+    assert_eq!(ModInteger::<FALCON_Q>::new(6145).centered(), -6144);
+}
+
+#[test]
+fn test_modint_float_conversions_known_answer() {
+    assert_eq!(f64::from(ModInteger32{value: (1 << 32) - 7}), -7.0);
+    assert_eq!(f32::from(ModInteger64{value: 5}), 5.0);
+    assert_eq!(ModInteger32::from(-2.5_f64), ModInteger32{value: (1 << 32) - 3});
+    assert_eq!(ModInteger32::from(4294967298.4_f64), ModInteger32{value: 2});
+    assert_eq!(ModInteger64::from(1.5_f32), ModInteger64{value: 2});
+    assert_eq!(ModInteger64::from(-(2.0_f64.powi(70) + 1048576.0)), ModInteger64{value: (1 << 64) - (1 << 20)});
+    assert_eq!(ModInteger64::from(f64::NAN), ModInteger64::zero());
+}
+
+#[test]
+fn test_modint_float_round_trip() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..NUM_RANDOM_TESTS {
+        let integer32 = ModInteger32{value: rng.gen_range(0..1 << 32)};
+        let integer64 = ModInteger64{value: rng.gen_range(0..1 << 52)};
+
+        assert_eq!(ModInteger32::from(f64::from(integer32)), integer32);
+        assert_eq!(ModInteger64::from(f64::from(integer64)), integer64);
+        assert_eq!(ModInteger64::from(f64::from(-integer64)), -integer64);
+    }
+}
+
+#[test]
+fn test_modint_moduli_conversions() {
+    let integer64 = ModInteger64{value: (1 << 64) - 1};
+
+    assert_eq!(ModInteger32::from(integer64), ModInteger32{value: (1 << 32) - 1});
+    assert_eq!(ModInteger64::from(ModInteger32{value: (1 << 32) - 1}), ModInteger64{value: (1 << 32) - 1});
+    assert_eq!(ModInteger32::from(ModInteger64::from(ModInteger32{value: 12345})), ModInteger32{value: 12345});
+}
+
+#[test]
+fn test_complex_conversions() {
+    let real = Complex{real: -3.0_f64, imag: 0.0};
+    let complex = Complex{real: 1.0_f32, imag: 2.0};
+
+    assert_eq!(Complex::from(-3.0_f64), real);
+    assert_eq!(Complex::<f64>::from(complex), Complex{real: 1.0, imag: 2.0});
+    assert_eq!(f64::try_from(real), Ok(-3.0));
+    assert_eq!(f32::try_from(complex), Err(RingMathError::NonzeroImaginaryPart));
+    assert_eq!(ModInteger32::try_from(real), Ok(ModInteger32{value: (1 << 32) - 3}));
+    assert_eq!(ModInteger64::try_from(complex), Err(RingMathError::NonzeroImaginaryPart));
+    assert_eq!(Complex::<f64>::from(ModInteger64{value: (1 << 64) - 3}), real);
+}
+
+fn square_polynomial<R: Ring>(coeffs: Vec<R>) -> Vec<R> {
+    let poly: Polynomial<R> = Polynomial::from_coeffs(coeffs);
+
+    (&poly * &poly).coeffs
+}
+
+#[test]
+fn test_ring_generic_polynomial() {
+    // (1 + 2x)^2 = 1 + 4x + 4x^2 = 5 + 4x when x^2 = 1
+    assert_eq!(square_polynomial(vec![1.0, 2.0]), vec![5.0, 4.0]);
+    assert_eq!(square_polynomial(vec![ModInteger32{value: 1}, ModInteger32{value: 2}]),
+               vec![ModInteger32{value: 5}, ModInteger32{value: 4}]);
+    assert_eq!(square_polynomial(vec![Torus64{value: 1}, Torus64{value: 2}]), vec![Torus64{value: 5}, Torus64{value: 4}]);
+    assert_eq!(square_polynomial(vec![Complex::from(1.0_f32), Complex::from(2.0)]),
+               vec![Complex::from(5.0), Complex::from(4.0)]);
+}
+
+fn divide<F: Field>(a: F, b: F) -> F {
+    a * b.inverse()
+}
+
+#[test]
+fn test_field_generic_division() {
+    assert_eq!(divide(3.0, 4.0), 0.75);
+    assert_eq!(divide(ModInteger::<FALCON_Q>::new(6), ModInteger::new(3)), ModInteger::new(2));
+    assert_eq!(divide(Complex{real: 0.0, imag: 2.0}, Complex{real: 0.0, imag: 1.0}), Complex::from(2.0_f64));
+}
+
+#[test]
+fn test_modular_ring_characteristic() {
+    assert_eq!(ModInteger32::characteristic(), 1 << 32);
+    assert_eq!(ModInteger64::characteristic(), 1 << 64);
+    assert_eq!(Torus32::characteristic(), 1 << 32);
+    assert_eq!(ModInteger::<KYBER_Q>::characteristic(), KYBER_Q as u128);
+    assert_eq!(ModInteger::<KYBER_Q>::modulus(), KYBER_Q);
+}
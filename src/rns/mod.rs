@@ -0,0 +1,389 @@
+//! rns module
+//!
+//! A polynomial mod Q = q_0 q_1 ... q_(k-1) is held in residue number system form, as one residue
+//! polynomial mod each q_i, so that additions and products work limb by limb on word-sized
+//! integers. Dropping the last limb moves the polynomial down the modulus chain.
+use crate::ring::{ModulusContext, ContextModInteger};
+use crate::bigint::{BigModulusContext, BigModInteger, limbs_to_hex};
+use crate::polynomial::Polynomial;
+use crate::quotient::{Quotient, Cyclic};
+
+/// Compute a^-1 mod m, by the extended Euclidean algorithm.
+///
+/// # Panics if a and m are not coprime.
+/// ```
+fn inverse_mod(a: u64, modulus: u64) -> u64 {
+    let (mut r0, mut r1) = (modulus as i128, (a % modulus) as i128);
+    let (mut s0, mut s1) = (0_i128, 1_i128);
+    while r1 != 0 {
+        let quotient = r0 / r1;
+        (r0, r1) = (r1, r0 - quotient * r1);
+        (s0, s1) = (s1, s0 - quotient * s1);
+    }
+    if r0 != 1 {
+        panic!("Moduli should be pairwise coprime. {} and {}", a, modulus);
+    }
+    s0.rem_euclid(modulus as i128) as u64
+}
+
+/// Return the integer mod q equal to a signed integer.
+fn integer_from_i128(context: &ModulusContext, value: i128) -> ContextModInteger<'_> {
+    context.integer(value.rem_euclid(context.modulus as i128) as u64)
+}
+
+/// Return the product of the moduli as little-endian 64-bit limbs, without leading zero limbs.
+fn product_limbs(moduli: &[u64]) -> Vec<u64> {
+    let mut product: Vec<u64> = vec![1];
+    for &modulus in moduli.iter() {
+        let mut carry: u64 = 0;
+        for limb in product.iter_mut() {
+            let wide = *limb as u128 * modulus as u128 + carry as u128;
+            *limb = wide as u64;
+            carry = (wide >> 64) as u64;
+        }
+        if carry != 0 {
+            product.push(carry);
+        }
+    }
+    product
+}
+
+/// Structure representing a chain of pairwise coprime moduli q_0, ..., q_(k-1), the RNS basis of
+/// Q = q_0 ... q_(k-1)
+///
+/// # Attributes
+/// * `contexts` - the modulus context of each q_i, from the bottom of the chain
+#[derive(Debug)]
+pub struct RnsBasis {
+    pub contexts: Vec<ModulusContext>
+}
+
+impl RnsBasis {
+
+    /// Return a new RNS basis.
+    ///
+    /// # Arguments
+    /// * `moduli` - Pairwise coprime odd moduli q_i greater than 1, from the bottom of the chain.
+    ///
+    /// # Output
+    /// * a new instantiation of an RnsBasis
+    ///
+    /// # Panics if there are no moduli, or if they are not odd, greater than 1 and pairwise coprime.
+    /// ```
+    pub fn new(moduli: &[u64]) -> RnsBasis {
+        if moduli.is_empty() {
+            panic!("RNS basis should have at least one modulus.");
+        }
+        for (i, &modulus) in moduli.iter().enumerate() {
+            if modulus < 2 {
+                panic!("Moduli should be at least 2. {}", modulus);
+            }
+            for &other in moduli[..i].iter() {
+                inverse_mod(other, modulus);
+            }
+        }
+        RnsBasis {contexts: moduli.iter().map(|&modulus| ModulusContext::new(modulus)).collect()}
+    }
+
+    /// Return the moduli q_i, from the bottom of the chain.
+    pub fn moduli(&self) -> Vec<u64> {
+        self.contexts.iter().map(|context| context.modulus).collect()
+    }
+
+    /// Return a new RNS polynomial with all the limbs of the basis.
+    ///
+    /// # Arguments
+    /// * `values` - Coefficients of the polynomial, which are reduced mod each q_i. The degree N
+    ///   of the ring is their length.
+    ///
+    /// # Output
+    /// * a new instantiation of an RnsPolynomial
+    /// ```
+    pub fn polynomial<'a, Q: Quotient<ContextModInteger<'a>>>(&'a self, values: &[i128]) -> RnsPolynomial<'a, Q> {
+        let limbs = self.contexts.iter().map(|context| {
+            Polynomial::from_coeffs(values.iter().map(|&value| integer_from_i128(context, value)).collect())
+        }).collect();
+        RnsPolynomial{ring_degree: values.len(), basis: self, limbs}
+    }
+
+    /// Return, for each of the first `level` moduli, the inverse of q̂_i = q_0 ... q_(level-1) / q_i
+    /// mod q_i, which scales the residues before they are combined by the CRT.
+    fn crt_inverses(&self, level: usize) -> Vec<u64> {
+        let moduli = self.moduli();
+        (0..level).map(|i| {
+            let context = &self.contexts[i];
+            let q_hat = (0..level).filter(|&j| j != i)
+                .fold(context.integer(1), |product, j| product * context.integer(moduli[j]));
+            inverse_mod(q_hat.value(), moduli[i])
+        }).collect()
+    }
+}
+
+/// Structure representing a polynomial mod Q in RNS form, with a residue polynomial for each of
+/// the first moduli of its basis
+///
+/// # Attributes
+/// * `ring_degree` - the degree N of the polynomial ring
+/// * `basis` - the chain of moduli
+/// * `limbs` - the residue polynomial mod each q_i, from the bottom of the chain
+pub struct RnsPolynomial<'a, Q = Cyclic> {
+    pub ring_degree: usize,
+    pub basis: &'a RnsBasis,
+    pub limbs: Vec<RnsLimb<'a, Q>>
+}
+
+/// The residue polynomial of an RNS polynomial mod one of the moduli.
+pub type RnsLimb<'a, Q = Cyclic> = Polynomial<ContextModInteger<'a>, Q>;
+
+/// The quotient must apply to integers of any context, since base extension moves a polynomial to
+/// the contexts of another basis.
+impl<'a, Q: for<'c> Quotient<ContextModInteger<'c>>> RnsPolynomial<'a, Q> {
+
+    /// Return the number of limbs, which is the level of the polynomial in the modulus chain.
+    pub fn level(&self) -> usize {
+        self.limbs.len()
+    }
+
+    /// Check that another polynomial has the same basis and level.
+    ///
+    /// # Panics if the bases or the numbers of limbs differ.
+    /// ```
+    fn check_same_level(&self, other: &RnsPolynomial<'a, Q>) {
+        if self.level() != other.level() {
+            panic!("Number of limbs should be equal. {} != {}", self.level(), other.level());
+        }
+        if self.basis.moduli() != other.basis.moduli() {
+            panic!("RNS bases should be equal. {:?} != {:?}", self.basis.moduli(), other.basis.moduli());
+        }
+    }
+
+    /// Apply an operation to each pair of limbs of two polynomials.
+    fn map_limbs(&self, other: &RnsPolynomial<'a, Q>,
+                 op: fn(&RnsLimb<'a, Q>, &RnsLimb<'a, Q>) -> RnsLimb<'a, Q>) -> RnsPolynomial<'a, Q> {
+        self.check_same_level(other);
+        let limbs = self.limbs.iter().zip(other.limbs.iter()).map(|(limb1, limb2)| op(limb1, limb2)).collect();
+        RnsPolynomial{ring_degree: self.ring_degree, basis: self.basis, limbs}
+    }
+
+    /// Add a polynomial to another polynomial in the ring, limb by limb.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to add
+    ///
+    /// # Output
+    /// * a new instantiation of an RnsPolynomial, which is the sum of the two polynomials
+    ///
+    /// # Panics if the bases, levels or ring degrees differ.
+    /// ```
+    pub fn add(&self, other: &RnsPolynomial<'a, Q>) -> RnsPolynomial<'a, Q> {
+        self.map_limbs(other, Polynomial::add)
+    }
+
+    /// Subtract another polynomial from a polynomial in the ring, limb by limb.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to subtract
+    ///
+    /// # Output
+    /// * a new instantiation of an RnsPolynomial, which is the difference of the two polynomials
+    ///
+    /// # Panics if the bases, levels or ring degrees differ.
+    /// ```
+    pub fn sub(&self, other: &RnsPolynomial<'a, Q>) -> RnsPolynomial<'a, Q> {
+        self.map_limbs(other, Polynomial::sub)
+    }
+
+    /// Multiply a polynomial to another polynomial in the ring, limb by limb.
+    ///
+    /// Each limb is multiplied with Karatsuba rather than the NTT: `Ntt` takes its modulus as a
+    /// const generic, while the moduli of an RNS basis are only known at runtime, so there is no
+    /// `Ntt` to pick for a limb even when its modulus is NTT-friendly.
+    ///
+    /// # Arguments
+    /// * `other` - other polynomial to multiply
+    ///
+    /// # Output
+    /// * a new instantiation of an RnsPolynomial, which is the product of the two polynomials
+    ///
+    /// # Panics if the bases, levels or ring degrees differ.
+    /// ```
+    pub fn multiply(&self, other: &RnsPolynomial<'a, Q>) -> RnsPolynomial<'a, Q> {
+        self.map_limbs(other, Polynomial::multiply_karatsuba)
+    }
+
+    /// Negate a polynomial.
+    ///
+    /// # Output
+    /// * a new instantiation of an RnsPolynomial, which is the additive inverse of the polynomial
+    /// ```
+    pub fn neg(&self) -> RnsPolynomial<'a, Q> {
+        let limbs = self.limbs.iter().map(|limb| limb.neg()).collect();
+        RnsPolynomial{ring_degree: self.ring_degree, basis: self.basis, limbs}
+    }
+
+    /// Reconstruct the coefficients by the CRT, as their centered lifts, with Garner's mixed radix
+    /// algorithm.
+    ///
+    /// # Output
+    /// * the representatives of the coefficients in (-Q/2, Q/2]
+    ///
+    /// # Panics if Q is 2^127 or more.
+    /// ```
+    pub fn reconstruct_centered(&self) -> Vec<i128> {
+        let moduli = self.basis.moduli();
+        let product = moduli[..self.level()].iter()
+            .try_fold(1_u128, |product, &modulus| product.checked_mul(modulus as u128))
+            .filter(|&product| product < 1 << 127)
+            .unwrap_or_else(|| panic!("Product of the moduli should be less than 2^127. {:?}", &moduli[..self.level()]));
+
+        // The inverse of q_0 ... q_(i-1) mod q_i, for each digit of the mixed radix form.
+        let radix_inverses: Vec<ContextModInteger> = (0..self.level()).map(|i| {
+            let radix = moduli[..i].iter().fold(1_u128, |radix, &modulus| radix * modulus as u128);
+            self.basis.contexts[i].integer(inverse_mod((radix % moduli[i] as u128) as u64, moduli[i]))
+        }).collect();
+
+        (0..self.ring_degree).map(|k| {
+            // x = v_0 + v_1 q_0 + v_2 q_0 q_1 + ..., where each digit v_i is found mod q_i.
+            let mut value: u128 = 0;
+            let mut radix: u128 = 1;
+            for (i, limb) in self.limbs.iter().enumerate() {
+                let context = &self.basis.contexts[i];
+                let partial = context.integer((value % moduli[i] as u128) as u64);
+                let digit = (context.bind(limb.coeffs[k]) - partial) * radix_inverses[i];
+                value += digit.value() as u128 * radix;
+                radix *= moduli[i] as u128;
+            }
+            if value > product / 2 { value as i128 - product as i128 } else { value as i128 }
+        }).collect()
+    }
+
+    /// Reconstruct the coefficients by the CRT, as integers mod Q with L limbs.
+    ///
+    /// # Arguments
+    /// * `context` - the context of the modulus Q, the product of the moduli of the limbs
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial over Z/Q
+    ///
+    /// # Panics if the modulus of the context is not Q.
+    /// ```
+    pub fn reconstruct<'b, const L: usize>(&self, context: &'b BigModulusContext<L>) -> Polynomial<BigModInteger<'b, L>, Q>
+            where Q: Quotient<BigModInteger<'b, L>> {
+        let moduli = self.basis.moduli();
+        let product = product_limbs(&moduli[..self.level()]);
+        if product.len() > L || product[..] != context.modulus[..product.len()]
+                || context.modulus[product.len()..].iter().any(|&limb| limb != 0) {
+            panic!("Modulus of the context should be the product of the moduli. {} != {}",
+                   limbs_to_hex(&context.modulus), limbs_to_hex(&product));
+        }
+
+        // x = sum of [x_i q̂_i^-1]_(q_i) q̂_i mod Q.
+        let crt_inverses = self.basis.crt_inverses(self.level());
+        let q_hats: Vec<BigModInteger<'b, L>> = (0..self.level()).map(|i| {
+            let others: Vec<u64> = (0..self.level()).filter(|&j| j != i).map(|j| moduli[j]).collect();
+            let mut limbs = [0; L];
+            let q_hat = product_limbs(&others);
+            limbs[..q_hat.len()].copy_from_slice(&q_hat);
+            context.integer_from_limbs(limbs)
+        }).collect();
+
+        Polynomial::from_coeffs((0..self.ring_degree).map(|k| {
+            let mut value = context.integer(0);
+            for (i, limb) in self.limbs.iter().enumerate() {
+                let limb_context = &self.basis.contexts[i];
+                let scaled = limb_context.bind(limb.coeffs[k]) * limb_context.integer(crt_inverses[i]);
+                value += context.integer(scaled.value()) * q_hats[i];
+            }
+            value
+        }).collect())
+    }
+
+    /// Extend the polynomial to another basis, by fast base conversion.
+    ///
+    /// Each coefficient x mod p_j is computed as the sum of [x_i q̂_i^-1]_(q_i) q̂_i mod p_j, without
+    /// reducing the sum mod Q first. The result is the residues of x + u Q for some integer u in
+    /// [0, k), where k is the number of limbs, which is the usual tolerance of RNS schemes.
+    ///
+    /// # Arguments
+    /// * `target` - the basis of moduli p_j to extend to, which should be coprime to the q_i
+    ///
+    /// # Output
+    /// * a new instantiation of an RnsPolynomial with all the limbs of the target basis
+    /// ```
+    pub fn extend_basis<'b>(&self, target: &'b RnsBasis) -> RnsPolynomial<'b, Q> {
+        let moduli = self.basis.moduli();
+        let crt_inverses = self.basis.crt_inverses(self.level());
+
+        // Scale the residues once, since the scaled values are shared by every target modulus.
+        let scaled: Vec<Vec<u64>> = self.limbs.iter().enumerate().map(|(i, limb)| {
+            let context = &self.basis.contexts[i];
+            let inverse = context.integer(crt_inverses[i]);
+            limb.coeffs.iter().map(|&coeff| (context.bind(coeff) * inverse).value()).collect()
+        }).collect();
+
+        let limbs = target.contexts.iter().map(|context| {
+            let q_hats: Vec<ContextModInteger<'b>> = (0..self.level()).map(|i| {
+                (0..self.level()).filter(|&j| j != i).fold(context.integer(1), |product, j| product * context.integer(moduli[j]))
+            }).collect();
+            Polynomial::from_coeffs((0..self.ring_degree).map(|k| {
+                let mut value = context.integer(0);
+                for (residues, &q_hat) in scaled.iter().zip(q_hats.iter()) {
+                    value += context.integer(residues[k]) * q_hat;
+                }
+                value
+            }).collect())
+        }).collect();
+        RnsPolynomial{ring_degree: self.ring_degree, basis: target, limbs}
+    }
+
+    /// Drop the last limb, which reduces the polynomial mod Q / q_(k-1).
+    ///
+    /// # Output
+    /// * a new instantiation of an RnsPolynomial with one limb fewer
+    ///
+    /// # Panics if the polynomial has a single limb.
+    /// ```
+    pub fn drop_limb(&self) -> RnsPolynomial<'a, Q> {
+        if self.level() < 2 {
+            panic!("RNS polynomial should have at least two limbs to drop one. {}", self.level());
+        }
+        let limbs = self.limbs[..self.level() - 1].iter().map(|limb| Polynomial::from_coeffs(limb.coeffs.clone())).collect();
+        RnsPolynomial{ring_degree: self.ring_degree, basis: self.basis, limbs}
+    }
+
+    /// Divide the polynomial by the last modulus and drop its limb, rounding to the nearest
+    /// integer, as in the rescaling of CKKS and the modulus switching of BGV.
+    ///
+    /// With r the centered residue of x mod q_(k-1), x - r is divisible by q_(k-1), so each
+    /// remaining limb becomes (x_i - r) q_(k-1)^-1 mod q_i.
+    ///
+    /// # Output
+    /// * a new instantiation of an RnsPolynomial with one limb fewer, equal to round(x / q_(k-1))
+    ///
+    /// # Panics if the polynomial has a single limb.
+    /// ```
+    pub fn rescale(&self) -> RnsPolynomial<'a, Q> {
+        if self.level() < 2 {
+            panic!("RNS polynomial should have at least two limbs to drop one. {}", self.level());
+        }
+        let last = self.level() - 1;
+        let last_context = &self.basis.contexts[last];
+        let last_modulus = last_context.modulus;
+        let remainders: Vec<i128> = self.limbs[last].coeffs.iter().map(|&coeff| {
+            let value = last_context.bind(coeff).value();
+            if value > last_modulus / 2 { value as i128 - last_modulus as i128 } else { value as i128 }
+        }).collect();
+
+        let limbs = self.limbs[..last].iter().enumerate().map(|(i, limb)| {
+            let context = &self.basis.contexts[i];
+            let inverse = context.integer(inverse_mod(last_modulus, context.modulus));
+            Polynomial::from_coeffs(limb.coeffs.iter().zip(remainders.iter()).map(|(&coeff, &remainder)| {
+                (context.bind(coeff) - integer_from_i128(context, remainder)) * inverse
+            }).collect())
+        }).collect();
+        RnsPolynomial{ring_degree: self.ring_degree, basis: self.basis, limbs}
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,210 @@
+// Tests
+use super::*;
+use rand::Rng;
+use crate::quotient::Negacyclic;
+use crate::traits::{Zero, One};
+
+const MAX_TEST_DEGREE: usize = 64;
+const GOLDILOCKS_Q: u64 = 0xffff_ffff_0000_0001;
+const FALCON_Q: u64 = 12289;
+const KYBER_Q: u64 = 3329;
+const MERSENNE_31_Q: u64 = 0x7fff_ffff;
+const MERSENNE_61_Q: u64 = 0x1fff_ffff_ffff_ffff;
+// The product of these moduli is about 2^106, so that the centered lifts fit in an i128.
+const CHAIN: [u64; 3] = [MERSENNE_61_Q, MERSENNE_31_Q, FALCON_Q];
+
+fn product(moduli: &[u64]) -> i128 {
+    moduli.iter().map(|&modulus| modulus as i128).product()
+}
+
+/// Generate random values in [-bound, bound].
+fn random_values(ring_degree: usize, bound: i128) -> Vec<i128> {
+    let mut rng = rand::thread_rng();
+    (0..ring_degree).map(|_| rng.gen_range(-bound..=bound)).collect()
+}
+
+#[test]
+#[should_panic(expected = "Moduli should be at least 2. 0")]
+fn test_rns_basis_zero_modulus_fail() {
+    // Should panic before the coprimality check reduces mod 0.
+    RnsBasis::new(&[3, 0]);
+}
+
+#[test]
+#[should_panic(expected = "Moduli should be at least 2. 1")]
+fn test_rns_basis_unit_modulus_fail() {
+    RnsBasis::new(&[1, 3]);
+}
+
+#[test]
+#[should_panic(expected = "Moduli should be pairwise coprime. 15 and 21")]
+fn test_rns_basis_not_coprime_fail() {
+    RnsBasis::new(&[15, 21]);
+}
+
+#[test]
+fn test_rns_reconstruct_centered_round_trip() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let basis = RnsBasis::new(&CHAIN);
+    let values = random_values(ring_degree, product(&CHAIN) / 2);
+    let poly: RnsPolynomial = basis.polynomial(&values);
+
+    assert_eq!(poly.level(), 3);
+    assert_eq!(poly.reconstruct_centered(), values);
+}
+
+#[test]
+fn test_rns_operations_match_integer() {
+    // Generate small random polynomials, whose products do not wrap mod Q.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let basis = RnsBasis::new(&CHAIN);
+    let values1 = random_values(ring_degree, 1 << 40);
+    let values2 = random_values(ring_degree, 1 << 40);
+    let rns1: RnsPolynomial<Negacyclic> = basis.polynomial(&values1);
+    let rns2: RnsPolynomial<Negacyclic> = basis.polynomial(&values2);
+    let poly1: Polynomial<i128, Negacyclic> = Polynomial::from_coeffs(values1);
+    let poly2: Polynomial<i128, Negacyclic> = Polynomial::from_coeffs(values2);
+
+    assert_eq!(rns1.add(&rns2).reconstruct_centered(), poly1.add(&poly2).coeffs);
+    assert_eq!(rns1.sub(&rns2).reconstruct_centered(), poly1.sub(&poly2).coeffs);
+    assert_eq!(rns1.multiply(&rns2).reconstruct_centered(), poly1.multiply(&poly2).coeffs);
+    assert_eq!(rns1.neg().reconstruct_centered(), poly1.neg().coeffs);
+}
+
+#[test]
+fn test_rns_reconstruct_big() {
+    // The product of these moduli is about 2^182, which needs three limbs.
+    let moduli = [GOLDILOCKS_Q, MERSENNE_61_Q, MERSENNE_31_Q, FALCON_Q, KYBER_Q];
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let basis = RnsBasis::new(&moduli);
+    let mut modulus = [0; 3];
+    modulus.copy_from_slice(&product_limbs(&moduli));
+    let context = BigModulusContext::new(modulus);
+    let values = random_values(ring_degree, i128::MAX);
+    let poly: RnsPolynomial = basis.polynomial(&values);
+
+    let reconstructed: Polynomial<BigModInteger<3>> = poly.reconstruct(&context);
+    for (&coeff, &value) in reconstructed.coeffs.iter().zip(values.iter()) {
+        let magnitude = value.unsigned_abs();
+        let expected = context.integer_from_limbs([magnitude as u64, (magnitude >> 64) as u64, 0]);
+        assert_eq!(coeff, if value < 0 { BigModInteger::zero() - expected } else { expected });
+    }
+}
+
+#[test]
+#[should_panic(expected = "Modulus of the context should be the product of the moduli. 0xd != 0xf")]
+fn test_rns_reconstruct_big_wrong_modulus_fail() {
+    let basis = RnsBasis::new(&[3, 5]);
+    let context = BigModulusContext::new([13, 0]);
+    let poly: RnsPolynomial = basis.polynomial(&[1, 2]);
+
+    let _ = poly.reconstruct(&context);
+}
+
+#[test]
+#[should_panic(expected = "Product of the moduli should be less than 2^127.")]
+fn test_rns_reconstruct_centered_too_large_fail() {
+    let basis = RnsBasis::new(&[GOLDILOCKS_Q, MERSENNE_61_Q, MERSENNE_31_Q]);
+    let poly: RnsPolynomial = basis.polynomial(&[1, 2]);
+
+    poly.reconstruct_centered();
+}
+
+#[test]
+fn test_rns_extend_basis() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let basis = RnsBasis::new(&CHAIN);
+    let target = RnsBasis::new(&[KYBER_Q, GOLDILOCKS_Q]);
+    let modulus = product(&CHAIN);
+    let values = random_values(ring_degree, modulus / 2);
+    let poly: RnsPolynomial = basis.polynomial(&values);
+
+    // Test that each extended coefficient is x + u Q mod p for some u in [0, k).
+    let extended = poly.extend_basis(&target);
+    assert_eq!(extended.level(), 2);
+    for (limb, context) in extended.limbs.iter().zip(target.contexts.iter()) {
+        let p = context.modulus as i128;
+        for (&coeff, &value) in limb.coeffs.iter().zip(values.iter()) {
+            let lifted = value.rem_euclid(modulus);
+            assert!((0..3).any(|u| (lifted + u * modulus).rem_euclid(p) == coeff.value() as i128));
+        }
+    }
+}
+
+#[test]
+fn test_rns_drop_limb() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let basis = RnsBasis::new(&CHAIN);
+    let values = random_values(ring_degree, product(&CHAIN[..2]) / 2);
+    let poly: RnsPolynomial = basis.polynomial(&values);
+
+    // Values below q_0 q_1 / 2 are unchanged by dropping the last limb.
+    let dropped = poly.drop_limb();
+    assert_eq!(dropped.level(), 2);
+    assert_eq!(dropped.reconstruct_centered(), values);
+}
+
+#[test]
+fn test_rns_rescale() {
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let basis = RnsBasis::new(&CHAIN);
+    let values = random_values(ring_degree, product(&CHAIN) / 2);
+    let poly: RnsPolynomial = basis.polynomial(&values);
+
+    // Test that rescaling divides by the last modulus, rounding to the nearest integer.
+    let rescaled = poly.rescale();
+    assert_eq!(rescaled.level(), 2);
+    let last = FALCON_Q as i128;
+    for (&quotient, &value) in rescaled.reconstruct_centered().iter().zip(values.iter()) {
+        assert!((value - quotient * last).abs() <= last / 2);
+    }
+}
+
+#[test]
+fn test_rns_constant_limbs() {
+    let basis = RnsBasis::new(&CHAIN);
+    let values = [1, 0, -1, 2];
+    let bound: RnsPolynomial = basis.polynomial(&values);
+    let mut constant: RnsPolynomial = basis.polynomial(&values);
+    let one = ContextModInteger::one();
+    // Limbs built from Zero and One hold context-free constants rather than integers mod q_i.
+    for i in [0, 2] {
+        constant.limbs[i] = Polynomial::from_coeffs(vec![one, ContextModInteger::zero(), -one, one + one]);
+    }
+    let mut modulus = [0; 2];
+    modulus.copy_from_slice(&product_limbs(&CHAIN));
+    let context = BigModulusContext::new(modulus);
+    let target = RnsBasis::new(&[KYBER_Q, GOLDILOCKS_Q]);
+
+    // Test that the constants are read mod the modulus of their limb.
+    assert_eq!(constant.reconstruct_centered(), values);
+    assert_eq!(constant.reconstruct(&context).coeffs, bound.reconstruct(&context).coeffs);
+    assert_eq!(constant.rescale().reconstruct_centered(), bound.rescale().reconstruct_centered());
+    for (limb1, limb2) in constant.extend_basis(&target).limbs.iter().zip(bound.extend_basis(&target).limbs.iter()) {
+        assert_eq!(limb1.coeffs, limb2.coeffs);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Number of limbs should be equal. 3 != 2")]
+fn test_rns_add_different_levels_fail() {
+    let basis = RnsBasis::new(&CHAIN);
+    let poly: RnsPolynomial = basis.polynomial(&[1, 2, 3]);
+
+    let _ = poly.add(&poly.drop_limb());
+}
+
+#[test]
+#[should_panic(expected = "RNS polynomial should have at least two limbs to drop one. 1")]
+fn test_rns_rescale_single_limb_fail() {
+    let basis = RnsBasis::new(&[FALCON_Q]);
+    let poly: RnsPolynomial = basis.polynomial(&[1, 2, 3]);
+
+    let _ = poly.rescale();
+}
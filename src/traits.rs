@@ -1,11 +1,81 @@
-pub trait Zero<T> {
-    fn zero() -> T;
+use std::ops::{Add, Sub, Mul, AddAssign};
+use std::fmt::Debug;
+
+pub trait Zero {
+    fn zero() -> Self;
 }
 
-pub trait One<T> {
-    fn one() -> T;
+pub trait One {
+    fn one() -> Self;
 }
 
-pub trait Abs<T> {
+pub trait Abs {
     fn abs(self) -> f64;
-}
\ No newline at end of file
+}
+
+pub trait Inverse {
+    fn inverse(self) -> Self;
+}
+
+pub trait Norm {
+    /// Return the magnitude of a coefficient, using the centered representative for modular types.
+    fn norm(self) -> f64;
+
+    /// Return the magnitudes whose norms are the norms of a polynomial. These are the coefficient
+    /// magnitudes, except for types which use the canonical embedding instead.
+    fn embedding_norms(coeffs: &[Self], _negacyclic: bool) -> Vec<f64> where Self: Sized + Clone {
+        coeffs.iter().map(|coeff| coeff.clone().norm()).collect()
+    }
+}
+
+/// Trait for types whose product can be taken by reference, so that generic algorithms need not
+/// clone both factors of every product.
+///
+/// It is implemented for every type with `&T * &T`. Requiring it of `Ring`, rather than writing
+/// `for<'a> &'a T: Mul<&'a T, Output = T>` at each use, lets every `T: Ring` bound imply it.
+pub trait MulRef: Sized {
+    /// Return the product of two elements, without consuming them.
+    fn mul_ref(&self, other: &Self) -> Self;
+}
+
+impl<T> MulRef for T where for<'a> &'a T: Mul<&'a T, Output = T> {
+    fn mul_ref(&self, other: &T) -> T {
+        self * other
+    }
+}
+
+/// Trait for the coefficient types of polynomials, vectors and matrices: a ring with identity,
+/// whose elements can be cloned, compared and printed, and multiplied by reference.
+///
+/// Only `Clone` is required, so that big integers and polynomials can be coefficients, such as
+/// `Polynomial<Polynomial<T>>` for bivariate polynomials. The scalar types are all `Copy`, which
+/// makes each clone a plain copy. `Vector` and `Matrix` are not rings, so they cannot be
+/// coefficients.
+///
+/// Negation is written as subtraction from zero, so that unsigned integers, whose subtraction may
+/// overflow, can be coefficients too.
+pub trait Ring: Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + AddAssign + Zero + One + Clone
+    + PartialEq + Debug + MulRef {}
+
+/// Trait for rings whose multiplication is commutative.
+pub trait CommutativeRing: Ring {}
+
+/// Trait for commutative rings where every nonzero element has a multiplicative inverse.
+pub trait Field: CommutativeRing + Inverse {}
+
+/// Trait for the rings Z/q, whose elements are represented by residues in [0, q).
+pub trait ModularRing: CommutativeRing {
+    /// Return the modulus q.
+    fn modulus() -> u128;
+
+    /// Return the characteristic of the ring, which is q for Z/q.
+    fn characteristic() -> u128 {
+        Self::modulus()
+    }
+
+    /// Return the residue of the element in [0, q).
+    fn residue(self) -> u128;
+
+    /// Return the element with the given residue, which should be in [0, q).
+    fn from_residue(residue: u128) -> Self;
+}
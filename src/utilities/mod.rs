@@ -1,20 +1,21 @@
-use rand::{distributions::Standard, prelude::Distribution, Rng};
-use std::ops::{Add, AddAssign, Sub, Mul};
+use rand::{distributions::{Standard, uniform::SampleUniform}, prelude::Distribution, Rng};
+use std::ops::Sub;
 use std::fmt::Debug;
-use std::cmp::PartialEq;
 use crate::polynomial::Polynomial;
 use crate::vector::{Vector, Matrix};
-use crate::ring::{Complex, ModInteger32, ModInteger64};
-use crate::traits::{Zero, One, Abs};
+use crate::ring::{Complex, ModInteger32, ModInteger64, ModInteger, ModulusContext, ContextModInteger, Torus32,
+                  Torus64};
+use crate::bigint::{BigModulusContext, BigModInteger};
+use crate::traits::{Abs, Ring};
 
-pub fn check_vecs_almost_equal<T>(vec1: &Vec<T>, vec2: &Vec<T>, percent_error: f64) -> bool 
-        where T: Copy + Sub<Output = T> + Debug + Abs<T>{
+pub fn check_vecs_almost_equal<T>(vec1: &[T], vec2: &[T], percent_error: f64) -> bool 
+        where T: Clone + Sub<Output = T> + Debug + Abs{
     if vec1.len() != vec2.len() {
         return false;
     }
 
     for i in 0..vec1.len() {
-        if T::abs(vec1[i] - vec2[i]) / T::abs(vec1[i]) > percent_error {
+        if T::abs(vec1[i].clone() - vec2[i].clone()) / T::abs(vec1[i].clone()) > percent_error {
             println!("vec1[{:?}] = {:?} and vec2[{:?}] = {:?}", i, vec1[i], i, vec2[i]);
             return false;
         }
@@ -24,19 +25,17 @@ pub fn check_vecs_almost_equal<T>(vec1: &Vec<T>, vec2: &Vec<T>, percent_error: f
 }
 
 pub fn generate_random_float_polynomial<T>(size: usize) -> Polynomial<T> 
-        where Standard: Distribution<T>, T: Mul<Output = T> + Add + Sub + Zero<T> +
-        One<T> + Sub<Output = T> + Copy + Add<Output = T> + Abs<T> + Debug + PartialEq + AddAssign {
+        where Standard: Distribution<T>, T: Ring + Abs {
     let mut rng = rand::thread_rng();
     let mut rand_vec: Vec<T> = Vec::new();
     for _ in 0..size {
         rand_vec.push(rng.gen::<T>());
     }
-    Polynomial{ring_degree: size, coeffs: rand_vec}
+    Polynomial::from_coeffs(rand_vec)
 }
 
 pub fn generate_random_complex_polynomial<T>(size: usize) -> Polynomial<Complex<T>> 
-        where Standard: Distribution<T>, T: Mul<Output = T> + Add + Sub + Zero<T> +
-        One<T> + Sub<Output = T> + Copy + Add<Output = T> + Abs<T> + Debug + PartialEq + AddAssign {
+        where Standard: Distribution<T>, T: Ring + Abs {
     let mut rng = rand::thread_rng();
     let mut rand_vec: Vec<Complex<T>> = Vec::new();
     for _ in 0..size {
@@ -44,30 +43,88 @@ pub fn generate_random_complex_polynomial<T>(size: usize) -> Polynomial<Complex<
         let imag = rng.gen::<T>();
         rand_vec.push(Complex{real, imag});
     }
-    Polynomial{ring_degree: size, coeffs: rand_vec}
+    Polynomial::from_coeffs(rand_vec)
 }
 
 pub fn generate_random_modint32_polynomial(size: usize) -> Polynomial<ModInteger32> {
     let mut rng = rand::thread_rng();
     let mut rand_vec: Vec<ModInteger32> = Vec::new();
     for _ in 0..size {
-        rand_vec.push(ModInteger32{value: rng.gen_range(0..(2^32))});
+        rand_vec.push(ModInteger32{value: rng.gen_range(0..(1 << 32))});
     }
-    Polynomial{ring_degree: size, coeffs: rand_vec}
+    Polynomial::from_coeffs(rand_vec)
 }
 
 pub fn generate_random_modint64_polynomial(size: usize) -> Polynomial<ModInteger64> {
     let mut rng = rand::thread_rng();
     let mut rand_vec: Vec<ModInteger64> = Vec::new();
     for _ in 0..size {
-        rand_vec.push(ModInteger64{value: rng.gen_range(0..(2^64))});
+        rand_vec.push(ModInteger64{value: rng.gen_range(0..(1 << 64))});
     }
-    Polynomial{ring_degree: size, coeffs: rand_vec}
+    Polynomial::from_coeffs(rand_vec)
+}
+
+pub fn generate_random_modint_polynomial<const Q: u64>(size: usize) -> Polynomial<ModInteger<Q>> {
+    let mut rng = rand::thread_rng();
+    let mut rand_vec: Vec<ModInteger<Q>> = Vec::new();
+    for _ in 0..size {
+        rand_vec.push(ModInteger::new(rng.gen_range(0..Q)));
+    }
+    Polynomial::from_coeffs(rand_vec)
+}
+
+pub fn generate_random_context_modint_polynomial(context: &ModulusContext, size: usize)
+        -> Polynomial<ContextModInteger<'_>> {
+    let mut rng = rand::thread_rng();
+    let mut rand_vec: Vec<ContextModInteger> = Vec::new();
+    for _ in 0..size {
+        rand_vec.push(context.integer(rng.gen_range(0..context.modulus)));
+    }
+    Polynomial::from_coeffs(rand_vec)
+}
+
+pub fn generate_random_big_modint_polynomial<const L: usize>(context: &BigModulusContext<L>, size: usize)
+        -> Polynomial<BigModInteger<'_, L>> {
+    let mut rng = rand::thread_rng();
+    let mut rand_vec: Vec<BigModInteger<L>> = Vec::new();
+    for _ in 0..size {
+        let mut limbs = [0; L];
+        rng.fill(&mut limbs[..]);
+        rand_vec.push(context.integer_from_limbs(limbs));
+    }
+    Polynomial::from_coeffs(rand_vec)
+}
+
+pub fn generate_random_torus32_polynomial(size: usize) -> Polynomial<Torus32> {
+    let mut rng = rand::thread_rng();
+    let mut rand_vec: Vec<Torus32> = Vec::new();
+    for _ in 0..size {
+        rand_vec.push(rng.gen::<Torus32>());
+    }
+    Polynomial::from_coeffs(rand_vec)
+}
+
+pub fn generate_random_torus64_polynomial(size: usize) -> Polynomial<Torus64> {
+    let mut rng = rand::thread_rng();
+    let mut rand_vec: Vec<Torus64> = Vec::new();
+    for _ in 0..size {
+        rand_vec.push(rng.gen::<Torus64>());
+    }
+    Polynomial::from_coeffs(rand_vec)
+}
+
+pub fn generate_random_integer_polynomial<T>(size: usize, low: T, high: T) -> Polynomial<T>
+        where T: Ring + SampleUniform + PartialOrd {
+    let mut rng = rand::thread_rng();
+    let mut rand_vec: Vec<T> = Vec::new();
+    for _ in 0..size {
+        rand_vec.push(rng.gen_range(low.clone()..=high.clone()));
+    }
+    Polynomial::from_coeffs(rand_vec)
 }
 
 pub fn generate_random_float_polynomial_vector<T>(size: usize, ring_degree: usize) -> Vector<T> 
-        where Standard: Distribution<T>, T: Mul<Output = T> + Add + Sub + Zero<T> +
-        One<T> + Sub<Output = T> + Copy + Add<Output = T> + Abs<T> + Debug + PartialEq + AddAssign {
+        where Standard: Distribution<T>, T: Ring + Abs {
     let mut rand_poly_vec: Vec<Polynomial<T>> = Vec::new();
     for _ in 0..size {
         let rand_poly: Polynomial<T> = generate_random_float_polynomial(ring_degree);
@@ -78,8 +135,7 @@ pub fn generate_random_float_polynomial_vector<T>(size: usize, ring_degree: usiz
 
 pub fn generate_random_complex_polynomial_vector<T>(
     size: usize, ring_degree: usize) -> Vector<Complex<T>> 
-        where Standard: Distribution<T>, T: Mul<Output = T> + Add + Sub + Zero<T> +
-        One<T> + Sub<Output = T> + Copy + Add<Output = T> + Abs<T> + Debug + PartialEq + AddAssign {
+        where Standard: Distribution<T>, T: Ring + Abs {
     let mut rand_poly_vec: Vec<Polynomial<Complex<T>>> = Vec::new();
     for _ in 0..size {
         let rand_poly: Polynomial<Complex<T>> = generate_random_complex_polynomial(ring_degree);
@@ -108,10 +164,60 @@ pub fn generate_random_modint64_polynomial_vector(
     Vector{ring_degree, length: size, polys: rand_poly_vec}
 }
 
+pub fn generate_random_modint_polynomial_vector<const Q: u64>(
+    size: usize, ring_degree: usize) -> Vector<ModInteger<Q>> {
+    let mut rand_poly_vec: Vec<Polynomial<ModInteger<Q>>> = Vec::new();
+    for _ in 0..size {
+        let rand_poly: Polynomial<ModInteger<Q>> = generate_random_modint_polynomial(ring_degree);
+        rand_poly_vec.push(rand_poly);
+    }
+    Vector{ring_degree, length: size, polys: rand_poly_vec}
+}
+
+pub fn generate_random_context_modint_polynomial_vector(
+    context: &ModulusContext, size: usize, ring_degree: usize) -> Vector<ContextModInteger<'_>> {
+    let mut rand_poly_vec: Vec<Polynomial<ContextModInteger>> = Vec::new();
+    for _ in 0..size {
+        let rand_poly: Polynomial<ContextModInteger> = generate_random_context_modint_polynomial(context, ring_degree);
+        rand_poly_vec.push(rand_poly);
+    }
+    Vector{ring_degree, length: size, polys: rand_poly_vec}
+}
+
+pub fn generate_random_torus32_polynomial_vector(
+    size: usize, ring_degree: usize) -> Vector<Torus32> {
+    let mut rand_poly_vec: Vec<Polynomial<Torus32>> = Vec::new();
+    for _ in 0..size {
+        let rand_poly: Polynomial<Torus32> = generate_random_torus32_polynomial(ring_degree);
+        rand_poly_vec.push(rand_poly);
+    }
+    Vector{ring_degree, length: size, polys: rand_poly_vec}
+}
+
+pub fn generate_random_torus64_polynomial_vector(
+    size: usize, ring_degree: usize) -> Vector<Torus64> {
+    let mut rand_poly_vec: Vec<Polynomial<Torus64>> = Vec::new();
+    for _ in 0..size {
+        let rand_poly: Polynomial<Torus64> = generate_random_torus64_polynomial(ring_degree);
+        rand_poly_vec.push(rand_poly);
+    }
+    Vector{ring_degree, length: size, polys: rand_poly_vec}
+}
+
+pub fn generate_random_integer_polynomial_vector<T>(
+    size: usize, ring_degree: usize, low: T, high: T) -> Vector<T>
+        where T: Ring + SampleUniform + PartialOrd {
+    let mut rand_poly_vec: Vec<Polynomial<T>> = Vec::new();
+    for _ in 0..size {
+        let rand_poly: Polynomial<T> = generate_random_integer_polynomial(ring_degree, low.clone(), high.clone());
+        rand_poly_vec.push(rand_poly);
+    }
+    Vector{ring_degree, length: size, polys: rand_poly_vec}
+}
+
 pub fn generate_random_float_polynomial_matrix<T>(
     num_rows: usize, num_cols: usize, ring_degree: usize) -> Matrix<T> 
-        where Standard: Distribution<T>, T: Mul<Output = T> + Add + Sub + Zero<T> +
-        One<T> + Sub<Output = T> + Copy + Add<Output = T> + Abs<T> + Debug + PartialEq + AddAssign {
+        where Standard: Distribution<T>, T: Ring + Abs {
     let mut rand_poly_matrix: Vec<Vector<T>> = Vec::new();
     for _ in 0..num_cols {
         let rand_poly_vec: Vector<T> = generate_random_float_polynomial_vector(num_rows, ring_degree);
@@ -122,8 +228,7 @@ pub fn generate_random_float_polynomial_matrix<T>(
 
 pub fn generate_random_complex_polynomial_matrix<T>(
     num_rows: usize, num_cols: usize, ring_degree: usize) -> Matrix<Complex<T>> 
-        where Standard: Distribution<T>, T: Mul<Output = T> + Add + Sub + Zero<T> +
-        One<T> + Sub<Output = T> + Copy + Add<Output = T> + Abs<T> + Debug + PartialEq + AddAssign {
+        where Standard: Distribution<T>, T: Ring + Abs {
     let mut rand_poly_matrix: Vec<Vector<Complex<T>>> = Vec::new();
     for _ in 0..num_cols {
         let rand_poly_vec: Vector<Complex<T>> = generate_random_complex_polynomial_vector(num_rows, ring_degree);
@@ -151,3 +256,54 @@ pub fn generate_random_modint64_polynomial_matrix(
     }
     Matrix{ring_degree, num_rows, num_cols, cols: rand_poly_matrix}
 }
+
+pub fn generate_random_modint_polynomial_matrix<const Q: u64>(
+    num_rows: usize, num_cols: usize, ring_degree: usize) -> Matrix<ModInteger<Q>> {
+    let mut rand_poly_matrix: Vec<Vector<ModInteger<Q>>> = Vec::new();
+    for _ in 0..num_cols {
+        let rand_poly_vec: Vector<ModInteger<Q>> = generate_random_modint_polynomial_vector(num_rows, ring_degree);
+        rand_poly_matrix.push(rand_poly_vec);
+    }
+    Matrix{ring_degree, num_rows, num_cols, cols: rand_poly_matrix}
+}
+
+pub fn generate_random_context_modint_polynomial_matrix(
+    context: &ModulusContext, num_rows: usize, num_cols: usize, ring_degree: usize) -> Matrix<ContextModInteger<'_>> {
+    let mut rand_poly_matrix: Vec<Vector<ContextModInteger>> = Vec::new();
+    for _ in 0..num_cols {
+        let rand_poly_vec: Vector<ContextModInteger> = generate_random_context_modint_polynomial_vector(context, num_rows, ring_degree);
+        rand_poly_matrix.push(rand_poly_vec);
+    }
+    Matrix{ring_degree, num_rows, num_cols, cols: rand_poly_matrix}
+}
+
+pub fn generate_random_torus32_polynomial_matrix(
+    num_rows: usize, num_cols: usize, ring_degree: usize) -> Matrix<Torus32> {
+    let mut rand_poly_matrix: Vec<Vector<Torus32>> = Vec::new();
+    for _ in 0..num_cols {
+        let rand_poly_vec: Vector<Torus32> = generate_random_torus32_polynomial_vector(num_rows, ring_degree);
+        rand_poly_matrix.push(rand_poly_vec);
+    }
+    Matrix{ring_degree, num_rows, num_cols, cols: rand_poly_matrix}
+}
+
+pub fn generate_random_torus64_polynomial_matrix(
+    num_rows: usize, num_cols: usize, ring_degree: usize) -> Matrix<Torus64> {
+    let mut rand_poly_matrix: Vec<Vector<Torus64>> = Vec::new();
+    for _ in 0..num_cols {
+        let rand_poly_vec: Vector<Torus64> = generate_random_torus64_polynomial_vector(num_rows, ring_degree);
+        rand_poly_matrix.push(rand_poly_vec);
+    }
+    Matrix{ring_degree, num_rows, num_cols, cols: rand_poly_matrix}
+}
+
+pub fn generate_random_integer_polynomial_matrix<T>(
+    num_rows: usize, num_cols: usize, ring_degree: usize, low: T, high: T) -> Matrix<T>
+        where T: Ring + SampleUniform + PartialOrd {
+    let mut rand_poly_matrix: Vec<Vector<T>> = Vec::new();
+    for _ in 0..num_cols {
+        let rand_poly_vec: Vector<T> = generate_random_integer_polynomial_vector(num_rows, ring_degree, low.clone(), high.clone());
+        rand_poly_matrix.push(rand_poly_vec);
+    }
+    Matrix{ring_degree, num_rows, num_cols, cols: rand_poly_matrix}
+}
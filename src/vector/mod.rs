@@ -1,21 +1,65 @@
 //! vector module
-use std::ops::{Add, Mul, AddAssign};
-use crate::traits::Zero;
-use crate::polynomial::Polynomial;
+use std::ops;
+use std::convert::TryFrom;
+use crate::traits::{Norm, Ring, ModularRing};
+use crate::polynomial::{Polynomial, Rounding};
+use crate::ring::{Complex, ModInteger32, ModInteger64};
+use crate::quotient::{Quotient, Cyclic};
+use crate::error::RingMathError;
 
-/// Structure representing a vector of polynomials in a generic ring T[x]/(X^N - 1)
+/// Structure representing a vector of polynomials in a generic ring T[x]/(X^N ± 1)
 ///
 /// # Attributes
 /// * `ring_degree` - the degree N of the polynomial ring
 /// * `length` - the length of the vector
 /// * `polys` - the values of the vector
-pub struct Vector<T: Add<Output = T> + Mul<Output = T> + Copy + Zero<T> + AddAssign> {
+pub struct Vector<T: Ring, Q = Cyclic> {
     pub ring_degree: usize,
     pub length: usize,
-    pub polys: Vec<Polynomial<T>>
+    pub polys: Vec<Polynomial<T, Q>>
 }
 
-impl<T: Add<Output = T> + Mul<Output = T> + Copy + Zero<T> + AddAssign> Vector<T> {
+impl<T: Ring, Q: Quotient<T>> Vector<T, Q> {
+
+    /// Check the number of polynomials.
+    ///
+    /// # Output
+    /// * `Err(RingMathError::PolyCountInvalid)` if the number of polys does not match the length
+    /// ```
+    pub fn try_check_poly_count(&self) -> Result<(), RingMathError> {
+        if self.length != self.polys.len() {
+            return Err(RingMathError::PolyCountInvalid(self.length, self.polys.len()));
+        }
+        Ok(())
+    }
+
+    /// Check that another vector is well formed and has the same length.
+    ///
+    /// # Output
+    /// * `Err(RingMathError)` if either vector has the wrong number of polynomials, or the lengths
+    ///   differ
+    /// ```
+    fn try_check_same_length(&self, other: &Vector<T, Q>) -> Result<(), RingMathError> {
+        self.try_check_poly_count()?;
+        if self.length != other.length {
+            return Err(RingMathError::LengthMismatch(self.length, other.length));
+        }
+        other.try_check_poly_count()
+    }
+
+    /// Check that another vector has the same length and that every pair of entries belongs to
+    /// the same ring, so that an in-place operation cannot fail halfway.
+    ///
+    /// # Output
+    /// * `Err(RingMathError)` if the lengths differ or any pair of entries cannot be combined
+    /// ```
+    fn try_check_same_entries(&self, other: &Vector<T, Q>) -> Result<(), RingMathError> {
+        self.try_check_same_length(other)?;
+        for (poly, other_poly) in self.polys.iter().zip(other.polys.iter()) {
+            poly.try_check_same_ring(other_poly)?;
+        }
+        Ok(())
+    }
 
     /// Add a vector to another vector in the ring.
     ///
@@ -25,16 +69,108 @@ impl<T: Add<Output = T> + Mul<Output = T> + Copy + Zero<T> + AddAssign> Vector<T
     /// # Output
     /// * a new instantiation of a vector, which is the sum of the two vectors
     /// ```
-    pub fn add(&self, other: &Vector<T>) -> Vector<T> {
-        if self.length != other.length {
-            panic!("Vector lengths should be equal. {} != {}", self.length, other.length);
+    pub fn add(&self, other: &Vector<T, Q>) -> Vector<T, Q> {
+        self.try_add(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Add a vector to another vector in the ring, without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other vector to add
+    ///
+    /// # Output
+    /// * a new instantiation of a vector, which is the sum of the two vectors, or the
+    ///   `RingMathError` describing why the vectors cannot be added
+    /// ```
+    pub fn try_add(&self, other: &Vector<T, Q>) -> Result<Vector<T, Q>, RingMathError> {
+        self.try_check_same_length(other)?;
+
+        let mut sum_polys: Vec<Polynomial<T, Q>> = Vec::new();
+        for i in 0..self.length {
+            sum_polys.push(self.polys[i].try_add(&other.polys[i])?);
         }
+        Ok(Vector{ring_degree: self.ring_degree, length: self.length, polys: sum_polys})
+    }
+
+    /// Add another vector to a vector in place.
+    ///
+    /// # Arguments
+    /// * `other` - other vector to add
+    /// ```
+    pub fn add_assign(&mut self, other: &Vector<T, Q>) {
+        self.try_add_assign(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Add another vector to a vector in place, without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other vector to add
+    ///
+    /// # Output
+    /// * `Err(RingMathError)` describing why the vectors cannot be added, in which case the
+    ///   vector is left unchanged
+    /// ```
+    pub fn try_add_assign(&mut self, other: &Vector<T, Q>) -> Result<(), RingMathError> {
+        self.try_check_same_entries(other)?;
 
-        let mut sum_polys: Vec<Polynomial<T>> = Vec::new();
         for i in 0..self.length {
-            sum_polys.push(self.polys[i].add(&other.polys[i]));
+            self.polys[i].try_add_assign(&other.polys[i])?;
+        }
+        Ok(())
+    }
+
+    /// Multiply every entry of a vector by a polynomial.
+    ///
+    /// # Arguments
+    /// * `poly` - polynomial to multiply by
+    ///
+    /// # Output
+    /// * a new instantiation of a vector, which is the product of the vector with the polynomial
+    /// ```
+    pub fn multiply_by_polynomial(&self, poly: &Polynomial<T, Q>) -> Vector<T, Q> {
+        self.try_multiply_by_polynomial(poly).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Multiply every entry of a vector by a polynomial, without panicking.
+    ///
+    /// # Arguments
+    /// * `poly` - polynomial to multiply by
+    ///
+    /// # Output
+    /// * a new instantiation of a vector, which is the product of the vector with the polynomial,
+    ///   or the `RingMathError` describing why the product cannot be computed
+    /// ```
+    pub fn try_multiply_by_polynomial(&self, poly: &Polynomial<T, Q>) -> Result<Vector<T, Q>, RingMathError> {
+        self.try_check_poly_count()?;
+
+        let mut prod_polys: Vec<Polynomial<T, Q>> = Vec::new();
+        for entry in self.polys.iter() {
+            prod_polys.push(entry.try_multiply(poly)?);
         }
-        Vector{ring_degree: self.ring_degree, length: self.length, polys: sum_polys}
+        Ok(Vector{ring_degree: self.ring_degree, length: self.length, polys: prod_polys})
+    }
+
+    /// Multiply every entry of a vector by a polynomial in place.
+    ///
+    /// # Arguments
+    /// * `poly` - polynomial to multiply by
+    /// ```
+    pub fn multiply_by_polynomial_assign(&mut self, poly: &Polynomial<T, Q>) {
+        self.try_multiply_by_polynomial_assign(poly).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Multiply every entry of a vector by a polynomial in place, without panicking.
+    ///
+    /// # Arguments
+    /// * `poly` - polynomial to multiply by
+    ///
+    /// # Output
+    /// * `Err(RingMathError)` describing why the product cannot be computed, in which case the
+    ///   vector is left unchanged
+    /// ```
+    pub fn try_multiply_by_polynomial_assign(&mut self, poly: &Polynomial<T, Q>) -> Result<(), RingMathError> {
+        *self = self.try_multiply_by_polynomial(poly)?;
+        Ok(())
     }
 
     /// Compute the dot product of two vectors.
@@ -45,35 +181,46 @@ impl<T: Add<Output = T> + Mul<Output = T> + Copy + Zero<T> + AddAssign> Vector<T
     /// # Output
     /// * a new instantiation of a Polynomial, which is the dot product of the two Vectors
     /// ```
-    pub fn dot_product(&self, other: &Vector<T>) -> Polynomial<T> {
-        if self.length != other.length {
-            panic!("Vector lengths should be equal. {} != {}", self.length, other.length);
-        }
+    pub fn dot_product(&self, other: &Vector<T, Q>) -> Polynomial<T, Q> {
+        self.try_dot_product(other).unwrap_or_else(|err| panic!("{}", err))
+    }
 
-        let mut dot_prod: Polynomial<T> = Polynomial::new(self.ring_degree);
+    /// Compute the dot product of two vectors, without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other vector
+    ///
+    /// # Output
+    /// * a new instantiation of a Polynomial, which is the dot product of the two Vectors, or the
+    ///   `RingMathError` describing why the dot product cannot be computed
+    /// ```
+    pub fn try_dot_product(&self, other: &Vector<T, Q>) -> Result<Polynomial<T, Q>, RingMathError> {
+        self.try_check_same_length(other)?;
+
+        let mut dot_prod: Polynomial<T, Q> = Polynomial::new(self.ring_degree);
         for i in 0..self.length {
-            dot_prod = dot_prod.add(&self.polys[i].multiply(&other.polys[i]));
+            dot_prod = dot_prod.try_add(&self.polys[i].try_multiply(&other.polys[i])?)?;
         }
-        dot_prod
+        Ok(dot_prod)
     }
 
 }
 
-/// Structure representing a matrix of polynomials in a generic ring T[x]/(X^N - 1)
+/// Structure representing a matrix of polynomials in a generic ring T[x]/(X^N ± 1)
 ///
 /// # Attributes
 /// * `ring_degree` - the degree N of the polynomial ring
 /// * `num_rows` - the number of rows in the matrix
 /// * `num_cols` - the number of columns in the matrix
 /// * `polys` - the values of the vector
-pub struct Matrix<T: Add<Output = T> + Mul<Output = T> + Copy + Zero<T> + AddAssign> {
+pub struct Matrix<T: Ring, Q = Cyclic> {
     pub ring_degree: usize,
     pub num_rows: usize,
     pub num_cols: usize,
-    pub cols: Vec<Vector<T>>
+    pub cols: Vec<Vector<T, Q>>
 }
 
-impl<T: Add<Output = T> + Mul<Output = T> + Copy + Zero<T> + AddAssign> Matrix<T> {
+impl<T: Ring, Q: Quotient<T>> Matrix<T, Q> {
 
     /// Add a matrix to another matrix in the ring.
     ///
@@ -83,19 +230,97 @@ impl<T: Add<Output = T> + Mul<Output = T> + Copy + Zero<T> + AddAssign> Matrix<T
     /// # Output
     /// * a new instantiation of a matrix, which is the sum of the two matrices
     /// ```
-    pub fn add(&self, other: &Matrix<T>) -> Matrix<T> {
+    pub fn add(&self, other: &Matrix<T, Q>) -> Matrix<T, Q> {
+        self.try_add(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Add a matrix to another matrix in the ring, without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other matrix to add
+    ///
+    /// # Output
+    /// * a new instantiation of a matrix, which is the sum of the two matrices, or the
+    ///   `RingMathError` describing why the matrices cannot be added
+    /// ```
+    pub fn try_add(&self, other: &Matrix<T, Q>) -> Result<Matrix<T, Q>, RingMathError> {
+        self.try_check_same_shape(other)?;
+
+        let mut sum_vecs: Vec<Vector<T, Q>> = Vec::new();
+        for i in 0..self.num_cols {
+            sum_vecs.push(self.cols[i].try_add(&other.cols[i])?);
+        }
+        Ok(Matrix{ring_degree: self.ring_degree, num_rows: self.num_rows, num_cols: self.num_cols, cols: sum_vecs})
+    }
+
+    /// Check the number of column vectors.
+    ///
+    /// # Output
+    /// * `Err(RingMathError::ColumnCountInvalid)` if the number of cols does not match num_cols
+    /// ```
+    pub fn try_check_column_count(&self) -> Result<(), RingMathError> {
+        if self.num_cols != self.cols.len() {
+            return Err(RingMathError::ColumnCountInvalid(self.num_cols, self.cols.len()));
+        }
+        Ok(())
+    }
+
+    /// Check that another matrix is well formed and has the same number of rows and columns.
+    ///
+    /// # Output
+    /// * `Err(RingMathError)` if either matrix has the wrong number of column vectors, or the
+    ///   number of rows or columns differ
+    /// ```
+    fn try_check_same_shape(&self, other: &Matrix<T, Q>) -> Result<(), RingMathError> {
+        self.try_check_column_count()?;
         if self.num_rows != other.num_rows {
-            panic!("Row lengths should be equal. {} != {}", self.num_rows, other.num_rows);
+            return Err(RingMathError::RowLengthMismatch(self.num_rows, other.num_rows));
         }
         if self.num_cols != other.num_cols {
-            panic!("Column lengths should be equal. {} != {}", self.num_cols, other.num_cols);
+            return Err(RingMathError::ColumnLengthMismatch(self.num_cols, other.num_cols));
+        }
+        other.try_check_column_count()
+    }
+
+    /// Check that another matrix has the same shape and that every pair of entries belongs to
+    /// the same ring, so that an in-place operation cannot fail halfway.
+    ///
+    /// # Output
+    /// * `Err(RingMathError)` if the shapes differ or any pair of entries cannot be combined
+    /// ```
+    fn try_check_same_entries(&self, other: &Matrix<T, Q>) -> Result<(), RingMathError> {
+        self.try_check_same_shape(other)?;
+        for (col, other_col) in self.cols.iter().zip(other.cols.iter()) {
+            col.try_check_same_entries(other_col)?;
         }
+        Ok(())
+    }
+
+    /// Add another matrix to a matrix in place.
+    ///
+    /// # Arguments
+    /// * `other` - other matrix to add
+    /// ```
+    pub fn add_assign(&mut self, other: &Matrix<T, Q>) {
+        self.try_add_assign(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Add another matrix to a matrix in place, without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other matrix to add
+    ///
+    /// # Output
+    /// * `Err(RingMathError)` describing why the matrices cannot be added, in which case the
+    ///   matrix is left unchanged
+    /// ```
+    pub fn try_add_assign(&mut self, other: &Matrix<T, Q>) -> Result<(), RingMathError> {
+        self.try_check_same_entries(other)?;
 
-        let mut sum_vecs: Vec<Vector<T>> = Vec::new();
         for i in 0..self.num_cols {
-            sum_vecs.push(self.cols[i].add(&other.cols[i]));
+            self.cols[i].try_add_assign(&other.cols[i])?;
         }
-        Matrix{ring_degree: self.ring_degree, num_rows: self.num_rows, num_cols: self.num_cols, cols: sum_vecs}
+        Ok(())
     }
 
     /// Compute the matrix product with a vector on the left.
@@ -106,21 +331,429 @@ impl<T: Add<Output = T> + Mul<Output = T> + Copy + Zero<T> + AddAssign> Matrix<T
     /// # Output
     /// * a new instantiation of a Vector, which is the product
     /// ```
-    pub fn multiply_by_left_vector(&self, other: &Vector<T>) -> Vector<T> {
+    pub fn multiply_by_left_vector(&self, other: &Vector<T, Q>) -> Vector<T, Q> {
+        self.try_multiply_by_left_vector(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Compute the matrix product with a vector on the left, without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other vector
+    ///
+    /// # Output
+    /// * a new instantiation of a Vector, which is the product, or the `RingMathError` describing
+    ///   why the product cannot be computed
+    /// ```
+    pub fn try_multiply_by_left_vector(&self, other: &Vector<T, Q>) -> Result<Vector<T, Q>, RingMathError> {
+        self.try_check_column_count()?;
         if self.num_rows != other.length {
-            panic!("Matrix row length should equal size of vector. {} != {}", self.num_rows, other.length);
+            return Err(RingMathError::MatrixVectorMismatch(self.num_rows, other.length));
+        }
+
+        let mut prod_vec: Vec<Polynomial<T, Q>> = Vec::new();
+        for i in 0..self.num_cols {
+            prod_vec.push(other.try_dot_product(&self.cols[i])?);
+        }
+        Ok(Vector {ring_degree: self.ring_degree,
+                   length: self.num_cols,
+                   polys: prod_vec})
+    }
+
+}
+
+impl<T: Ring, Q: Quotient<T>> Vector<T, Q> {
+
+    /// Subtract another vector from a vector in the ring.
+    ///
+    /// # Arguments
+    /// * `other` - other vector to subtract
+    ///
+    /// # Output
+    /// * a new instantiation of a vector, which is the difference of the two vectors
+    /// ```
+    pub fn sub(&self, other: &Vector<T, Q>) -> Vector<T, Q> {
+        self.try_sub(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Subtract another vector from a vector in the ring, without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other vector to subtract
+    ///
+    /// # Output
+    /// * a new instantiation of a vector, which is the difference of the two vectors, or the
+    ///   `RingMathError` describing why the vectors cannot be subtracted
+    /// ```
+    pub fn try_sub(&self, other: &Vector<T, Q>) -> Result<Vector<T, Q>, RingMathError> {
+        self.try_check_same_length(other)?;
+
+        let mut diff_polys: Vec<Polynomial<T, Q>> = Vec::new();
+        for i in 0..self.length {
+            diff_polys.push(self.polys[i].try_sub(&other.polys[i])?);
+        }
+        Ok(Vector{ring_degree: self.ring_degree, length: self.length, polys: diff_polys})
+    }
+
+    /// Subtract another vector from a vector in place.
+    ///
+    /// # Arguments
+    /// * `other` - other vector to subtract
+    /// ```
+    pub fn sub_assign(&mut self, other: &Vector<T, Q>) {
+        self.try_sub_assign(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Subtract another vector from a vector in place, without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other vector to subtract
+    ///
+    /// # Output
+    /// * `Err(RingMathError)` describing why the vectors cannot be subtracted, in which case the
+    ///   vector is left unchanged
+    /// ```
+    pub fn try_sub_assign(&mut self, other: &Vector<T, Q>) -> Result<(), RingMathError> {
+        self.try_check_same_entries(other)?;
+
+        for i in 0..self.length {
+            self.polys[i].try_sub_assign(&other.polys[i])?;
         }
+        Ok(())
+    }
 
-        let mut prod_vec: Vec<Polynomial<T>> = Vec::new();
+    /// Negate a vector.
+    ///
+    /// # Output
+    /// * a new instantiation of a vector, which is the additive inverse of the vector
+    /// ```
+    pub fn neg(&self) -> Vector<T, Q> {
+        let neg_polys: Vec<Polynomial<T, Q>> = self.polys.iter().map(|poly| -poly).collect();
+        Vector{ring_degree: self.ring_degree, length: self.length, polys: neg_polys}
+    }
+}
+
+impl<T: Ring, Q: Quotient<T>> Matrix<T, Q> {
+
+    /// Subtract another matrix from a matrix in the ring.
+    ///
+    /// # Arguments
+    /// * `other` - other matrix to subtract
+    ///
+    /// # Output
+    /// * a new instantiation of a matrix, which is the difference of the two matrices
+    /// ```
+    pub fn sub(&self, other: &Matrix<T, Q>) -> Matrix<T, Q> {
+        self.try_sub(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Subtract another matrix from a matrix in the ring, without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other matrix to subtract
+    ///
+    /// # Output
+    /// * a new instantiation of a matrix, which is the difference of the two matrices, or the
+    ///   `RingMathError` describing why the matrices cannot be subtracted
+    /// ```
+    pub fn try_sub(&self, other: &Matrix<T, Q>) -> Result<Matrix<T, Q>, RingMathError> {
+        self.try_check_same_shape(other)?;
+
+        let mut diff_vecs: Vec<Vector<T, Q>> = Vec::new();
         for i in 0..self.num_cols {
-            prod_vec.push(other.dot_product(&self.cols[i]));
+            diff_vecs.push(self.cols[i].try_sub(&other.cols[i])?);
         }
-        Vector {ring_degree: self.ring_degree,
-                length: self.num_cols,
-                polys: prod_vec}
+        Ok(Matrix{ring_degree: self.ring_degree, num_rows: self.num_rows, num_cols: self.num_cols, cols: diff_vecs})
+    }
+
+    /// Subtract another matrix from a matrix in place.
+    ///
+    /// # Arguments
+    /// * `other` - other matrix to subtract
+    /// ```
+    pub fn sub_assign(&mut self, other: &Matrix<T, Q>) {
+        self.try_sub_assign(other).unwrap_or_else(|err| panic!("{}", err))
     }
 
+    /// Subtract another matrix from a matrix in place, without panicking.
+    ///
+    /// # Arguments
+    /// * `other` - other matrix to subtract
+    ///
+    /// # Output
+    /// * `Err(RingMathError)` describing why the matrices cannot be subtracted, in which case the
+    ///   matrix is left unchanged
+    /// ```
+    pub fn try_sub_assign(&mut self, other: &Matrix<T, Q>) -> Result<(), RingMathError> {
+        self.try_check_same_entries(other)?;
+
+        for i in 0..self.num_cols {
+            self.cols[i].try_sub_assign(&other.cols[i])?;
+        }
+        Ok(())
+    }
+
+    /// Negate a matrix.
+    ///
+    /// # Output
+    /// * a new instantiation of a matrix, which is the additive inverse of the matrix
+    /// ```
+    pub fn neg(&self) -> Matrix<T, Q> {
+        let neg_vecs: Vec<Vector<T, Q>> = self.cols.iter().map(|col| -col).collect();
+        Matrix{ring_degree: self.ring_degree, num_rows: self.num_rows, num_cols: self.num_cols, cols: neg_vecs}
+    }
+}
+
+impl<T: ModularRing, Q: Quotient<T>> Vector<T, Q> {
+
+    /// Switch each polynomial of a vector from Z/q to Z/q', see `Polynomial::switch_modulus`.
+    ///
+    /// # Arguments
+    /// * `rounding` - how to round the scaled coefficients
+    ///
+    /// # Output
+    /// * a new instantiation of a vector over Z/q'
+    /// ```
+    pub fn switch_modulus<U: ModularRing>(&self, rounding: Rounding) -> Vector<U, Q> where Q: Quotient<U> {
+        let polys: Vec<Polynomial<U, Q>> = self.polys.iter().map(|poly| poly.switch_modulus(rounding)).collect();
+        Vector{ring_degree: self.ring_degree, length: self.length, polys}
+    }
+
+    /// Map each polynomial of a vector from Z/q to the real torus, see `Polynomial::switch_to_real`.
+    ///
+    /// # Output
+    /// * a new instantiation of a vector with coefficients in [0, 1)
+    /// ```
+    pub fn switch_to_real(&self) -> Vector<f64, Q> where Q: Quotient<f64> {
+        let polys: Vec<Polynomial<f64, Q>> = self.polys.iter().map(|poly| poly.switch_to_real()).collect();
+        Vector{ring_degree: self.ring_degree, length: self.length, polys}
+    }
+}
+
+impl<T: ModularRing, Q: Quotient<T>> Matrix<T, Q> {
+
+    /// Switch each polynomial of a matrix from Z/q to Z/q', see `Polynomial::switch_modulus`.
+    ///
+    /// # Arguments
+    /// * `rounding` - how to round the scaled coefficients
+    ///
+    /// # Output
+    /// * a new instantiation of a matrix over Z/q'
+    /// ```
+    pub fn switch_modulus<U: ModularRing>(&self, rounding: Rounding) -> Matrix<U, Q> where Q: Quotient<U> {
+        let cols: Vec<Vector<U, Q>> = self.cols.iter().map(|col| col.switch_modulus(rounding)).collect();
+        Matrix{ring_degree: self.ring_degree, num_rows: self.num_rows, num_cols: self.num_cols, cols}
+    }
+
+    /// Map each polynomial of a matrix from Z/q to the real torus, see `Polynomial::switch_to_real`.
+    ///
+    /// # Output
+    /// * a new instantiation of a matrix with coefficients in [0, 1)
+    /// ```
+    pub fn switch_to_real(&self) -> Matrix<f64, Q> where Q: Quotient<f64> {
+        let cols: Vec<Vector<f64, Q>> = self.cols.iter().map(|col| col.switch_to_real()).collect();
+        Matrix{ring_degree: self.ring_degree, num_rows: self.num_rows, num_cols: self.num_cols, cols}
+    }
 }
 
+impl<T: Ring + Norm, Q: Quotient<T>> Vector<T, Q> {
+
+    /// Return the magnitudes of all the polynomials of a vector, see `Polynomial::norm_infinity`.
+    fn embedding_norms(&self) -> Vec<f64> {
+        self.polys.iter().flat_map(|poly| poly.embedding_norms()).collect()
+    }
+
+    /// Return the infinity norm of a vector, over the coefficients of all its polynomials.
+    ///
+    /// # Output
+    /// * the largest magnitude, with coefficients mod q taken in (-q/2, q/2]
+    /// ```
+    pub fn norm_infinity(&self) -> f64 {
+        self.embedding_norms().into_iter().fold(0.0, f64::max)
+    }
+
+    /// Return the L1 norm of a vector, over the coefficients of all its polynomials.
+    ///
+    /// # Output
+    /// * the sum of the magnitudes, with coefficients mod q taken in (-q/2, q/2]
+    /// ```
+    pub fn norm_l1(&self) -> f64 {
+        self.embedding_norms().into_iter().sum()
+    }
+
+    /// Return the L2 norm of a vector, over the coefficients of all its polynomials.
+    ///
+    /// # Output
+    /// * the square root of the sum of the squared magnitudes, with coefficients mod q taken in
+    ///   (-q/2, q/2]
+    /// ```
+    pub fn norm_l2(&self) -> f64 {
+        self.embedding_norms().into_iter().map(|norm| norm * norm).sum::<f64>().sqrt()
+    }
+}
+
+impl<T: Ring + Norm, Q: Quotient<T>> Matrix<T, Q> {
+
+    /// Return the magnitudes of all the polynomials of a matrix, see `Polynomial::norm_infinity`.
+    fn embedding_norms(&self) -> Vec<f64> {
+        self.cols.iter().flat_map(|col| col.embedding_norms()).collect()
+    }
+
+    /// Return the infinity norm of a matrix, over the coefficients of all its polynomials.
+    ///
+    /// # Output
+    /// * the largest magnitude, with coefficients mod q taken in (-q/2, q/2]
+    /// ```
+    pub fn norm_infinity(&self) -> f64 {
+        self.embedding_norms().into_iter().fold(0.0, f64::max)
+    }
+
+    /// Return the L1 norm of a matrix, over the coefficients of all its polynomials.
+    ///
+    /// # Output
+    /// * the sum of the magnitudes, with coefficients mod q taken in (-q/2, q/2]
+    /// ```
+    pub fn norm_l1(&self) -> f64 {
+        self.embedding_norms().into_iter().sum()
+    }
+
+    /// Return the L2 norm of a matrix, over the coefficients of all its polynomials.
+    ///
+    /// # Output
+    /// * the square root of the sum of the squared magnitudes, with coefficients mod q taken in
+    ///   (-q/2, q/2]
+    /// ```
+    pub fn norm_l2(&self) -> f64 {
+        self.embedding_norms().into_iter().map(|norm| norm * norm).sum::<f64>().sqrt()
+    }
+}
+
+impl<T: Ring, Q: Quotient<T>> ops::Add<&Vector<T, Q>> for &Vector<T, Q> {
+    type Output = Vector<T, Q>;
+
+    fn add(self, other: &Vector<T, Q>) -> Vector<T, Q> {
+        Vector::add(self, other)
+    }
+}
+
+forward_binop!(impl[T, Q] Add, add for Vector<T, Q>, Vector<T, Q> => Vector<T, Q>
+               where T: Ring, Q: Quotient<T>);
+
+impl<T: Ring, Q: Quotient<T>> ops::Sub<&Vector<T, Q>> for &Vector<T, Q> {
+    type Output = Vector<T, Q>;
+
+    fn sub(self, other: &Vector<T, Q>) -> Vector<T, Q> {
+        Vector::sub(self, other)
+    }
+}
+
+forward_binop!(impl[T, Q] Sub, sub for Vector<T, Q>, Vector<T, Q> => Vector<T, Q>
+               where T: Ring,
+               Q: Quotient<T>);
+
+impl<T: Ring, Q: Quotient<T>> ops::Neg for &Vector<T, Q> {
+    type Output = Vector<T, Q>;
+
+    fn neg(self) -> Vector<T, Q> {
+        Vector::neg(self)
+    }
+}
+
+forward_neg!(impl[T, Q] for Vector<T, Q>
+             where T: Ring,
+             Q: Quotient<T>);
+
+impl<T: Ring, Q: Quotient<T>> ops::Mul<&Polynomial<T, Q>> for &Vector<T, Q> {
+    type Output = Vector<T, Q>;
+
+    fn mul(self, other: &Polynomial<T, Q>) -> Vector<T, Q> {
+        self.multiply_by_polynomial(other)
+    }
+}
+
+forward_binop!(impl[T, Q] Mul, mul for Vector<T, Q>, Polynomial<T, Q> => Vector<T, Q>
+               where T: Ring, Q: Quotient<T>);
+
+impl<T: Ring, Q: Quotient<T>> ops::Mul<&Vector<T, Q>> for &Polynomial<T, Q> {
+    type Output = Vector<T, Q>;
+
+    fn mul(self, other: &Vector<T, Q>) -> Vector<T, Q> {
+        other.multiply_by_polynomial(self)
+    }
+}
+
+forward_binop!(impl[T, Q] Mul, mul for Polynomial<T, Q>, Vector<T, Q> => Vector<T, Q>
+               where T: Ring, Q: Quotient<T>);
+
+impl<T: Ring, Q: Quotient<T>> ops::Mul<&Matrix<T, Q>> for &Vector<T, Q> {
+    type Output = Vector<T, Q>;
+
+    fn mul(self, other: &Matrix<T, Q>) -> Vector<T, Q> {
+        other.multiply_by_left_vector(self)
+    }
+}
+
+forward_binop!(impl[T, Q] Mul, mul for Vector<T, Q>, Matrix<T, Q> => Vector<T, Q>
+               where T: Ring, Q: Quotient<T>);
+
+impl<T: Ring, Q: Quotient<T>> ops::Add<&Matrix<T, Q>> for &Matrix<T, Q> {
+    type Output = Matrix<T, Q>;
+
+    fn add(self, other: &Matrix<T, Q>) -> Matrix<T, Q> {
+        Matrix::add(self, other)
+    }
+}
+
+forward_binop!(impl[T, Q] Add, add for Matrix<T, Q>, Matrix<T, Q> => Matrix<T, Q>
+               where T: Ring, Q: Quotient<T>);
+
+impl<T: Ring, Q: Quotient<T>> ops::Sub<&Matrix<T, Q>> for &Matrix<T, Q> {
+    type Output = Matrix<T, Q>;
+
+    fn sub(self, other: &Matrix<T, Q>) -> Matrix<T, Q> {
+        Matrix::sub(self, other)
+    }
+}
+
+forward_binop!(impl[T, Q] Sub, sub for Matrix<T, Q>, Matrix<T, Q> => Matrix<T, Q>
+               where T: Ring,
+               Q: Quotient<T>);
+
+impl<T: Ring, Q: Quotient<T>> ops::Neg for &Matrix<T, Q> {
+    type Output = Matrix<T, Q>;
+
+    fn neg(self) -> Matrix<T, Q> {
+        Matrix::neg(self)
+    }
+}
+
+forward_neg!(impl[T, Q] for Matrix<T, Q>
+             where T: Ring,
+             Q: Quotient<T>);
+
+convert_vector!(From<f32> for f64);
+convert_vector!(From<f32> for Complex<f32>);
+convert_vector!(From<f64> for Complex<f64>);
+convert_vector!(From<Complex<f32>> for Complex<f64>);
+convert_vector!(From<f32> for ModInteger32);
+convert_vector!(From<f64> for ModInteger32);
+convert_vector!(From<f32> for ModInteger64);
+convert_vector!(From<f64> for ModInteger64);
+convert_vector!(From<ModInteger32> for f32);
+convert_vector!(From<ModInteger32> for f64);
+convert_vector!(From<ModInteger32> for Complex<f32>);
+convert_vector!(From<ModInteger32> for Complex<f64>);
+convert_vector!(From<ModInteger64> for f32);
+convert_vector!(From<ModInteger64> for f64);
+convert_vector!(From<ModInteger64> for Complex<f32>);
+convert_vector!(From<ModInteger64> for Complex<f64>);
+convert_vector!(From<ModInteger32> for ModInteger64);
+convert_vector!(From<ModInteger64> for ModInteger32);
+convert_vector!(TryFrom<Complex<f32>> for f32);
+convert_vector!(TryFrom<Complex<f64>> for f64);
+convert_vector!(TryFrom<Complex<f32>> for ModInteger32);
+convert_vector!(TryFrom<Complex<f64>> for ModInteger32);
+convert_vector!(TryFrom<Complex<f32>> for ModInteger64);
+convert_vector!(TryFrom<Complex<f64>> for ModInteger64);
+
 #[cfg(test)]
 mod tests;
\ No newline at end of file
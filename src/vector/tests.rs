@@ -5,21 +5,24 @@ use crate::utilities::{check_vecs_almost_equal, generate_random_float_polynomial
                        generate_random_complex_polynomial_vector, generate_random_modint32_polynomial_vector,
                        generate_random_modint64_polynomial_vector, generate_random_float_polynomial_matrix,
                        generate_random_complex_polynomial_matrix, generate_random_modint32_polynomial_matrix,
-                       generate_random_modint64_polynomial_matrix};
-use crate::ring::{Complex, ModInteger32, ModInteger64};
-use crate::traits::{Abs, Zero, One};
-use std::ops::{Sub, Div};
-use std::cmp::{PartialOrd, PartialEq};
-use std::fmt::Debug;
+                       generate_random_modint64_polynomial_matrix, generate_random_modint_polynomial_vector,
+                       generate_random_modint_polynomial_matrix, generate_random_context_modint_polynomial_vector,
+                       generate_random_context_modint_polynomial_matrix, generate_random_torus32_polynomial_vector,
+                       generate_random_torus32_polynomial_matrix, generate_random_torus64_polynomial_vector,
+                       generate_random_torus64_polynomial_matrix, generate_random_modint32_polynomial};
+use crate::ring::{Complex, ModInteger32, ModInteger64, ModInteger, ModulusContext, Torus32, Torus64};
+use crate::quotient::Negacyclic;
+use crate::error::RingMathError;
+use crate::traits::Abs;
+use std::ops::Div;
+use std::cmp::PartialOrd;
 
 const MAX_TEST_DEGREE: usize = 16;
 const F32_MULTIPLY_ERROR: f64 = 0.01;
 const F64_ERROR: f64 = 0.0000000001;
 
 fn test_distributive_matrix_float<T>(error: f64)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
 
     // Generate random polynomials.
     let mut rng = rand::thread_rng();
@@ -56,9 +59,7 @@ fn test_distributive_matrix_float_f64() {
 }
 
 fn test_distributive_matrix_complex<T>(error: f64)
-        where Standard: Distribution<T>, T: Add<Output = T> + Mul<Output = T> + Copy +
-        Zero<T> + AddAssign + Sub + Sub<Output = T> + PartialOrd + Debug +
-        Div + Abs<T> + Div<Output = T> + One<T> + PartialEq {
+        where Standard: Distribution<T>, T: Ring + PartialOrd + Div + Abs + Div<Output = T> {
 
     // Generate random polynomials.
     let mut rng = rand::thread_rng();
@@ -145,3 +146,692 @@ fn test_distributive_matrix_modint64() {
         assert_eq!(total1.polys[i].coeffs, total2.polys[i].coeffs);
     }
 }
+
+
+const FALCON_Q: u64 = 12289;
+
+#[test]
+fn test_distributive_matrix_modint() {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_rows = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_cols = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let vector: Vector<ModInteger<FALCON_Q>> = generate_random_modint_polynomial_vector(num_rows, ring_degree);
+    let mat1: Matrix<ModInteger<FALCON_Q>> = generate_random_modint_polynomial_matrix(num_rows, num_cols, ring_degree);
+    let mat2: Matrix<ModInteger<FALCON_Q>> = generate_random_modint_polynomial_matrix(num_rows, num_cols, ring_degree);
+
+    // Test that v * (m1 + m2) = v * m1 + v * m2
+    let sum1 = mat1.add(&mat2);
+    let total1 = sum1.multiply_by_left_vector(&vector);
+
+    let prod1 = mat1.multiply_by_left_vector(&vector);
+    let prod2 = mat2.multiply_by_left_vector(&vector);
+    let total2 = prod1.add(&prod2);
+
+    assert_eq!(total1.ring_degree, total2.ring_degree);
+    assert_eq!(total1.length, total2.length);
+    for i in 0..total1.length {
+        assert_eq!(total1.polys[i].coeffs, total2.polys[i].coeffs);
+    }
+}
+
+#[test]
+fn test_distributive_matrix_context_modint() {
+    // Generate random polynomials.
+    let context = ModulusContext::new(FALCON_Q);
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_rows = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_cols = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let vector = generate_random_context_modint_polynomial_vector(&context, num_rows, ring_degree);
+    let mat1 = generate_random_context_modint_polynomial_matrix(&context, num_rows, num_cols, ring_degree);
+    let mat2 = generate_random_context_modint_polynomial_matrix(&context, num_rows, num_cols, ring_degree);
+
+    // Test that v * (m1 + m2) = v * m1 + v * m2
+    let sum1 = mat1.add(&mat2);
+    let total1 = sum1.multiply_by_left_vector(&vector);
+
+    let prod1 = mat1.multiply_by_left_vector(&vector);
+    let prod2 = mat2.multiply_by_left_vector(&vector);
+    let total2 = prod1.add(&prod2);
+
+    assert_eq!(total1.ring_degree, total2.ring_degree);
+    assert_eq!(total1.length, total2.length);
+    for i in 0..total1.length {
+        assert_eq!(total1.polys[i].coeffs, total2.polys[i].coeffs);
+    }
+}
+
+#[test]
+fn test_distributive_matrix_torus32() {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_rows = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_cols = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let vector: Vector<Torus32> = generate_random_torus32_polynomial_vector(num_rows, ring_degree);
+    let mat1: Matrix<Torus32> = generate_random_torus32_polynomial_matrix(num_rows, num_cols, ring_degree);
+    let mat2: Matrix<Torus32> = generate_random_torus32_polynomial_matrix(num_rows, num_cols, ring_degree);
+
+    // Test that v * (m1 + m2) = v * m1 + v * m2
+    let sum1 = mat1.add(&mat2);
+    let total1 = sum1.multiply_by_left_vector(&vector);
+
+    let prod1 = mat1.multiply_by_left_vector(&vector);
+    let prod2 = mat2.multiply_by_left_vector(&vector);
+    let total2 = prod1.add(&prod2);
+
+    assert_eq!(total1.ring_degree, total2.ring_degree);
+    assert_eq!(total1.length, total2.length);
+    for i in 0..total1.length {
+        assert_eq!(total1.polys[i].coeffs, total2.polys[i].coeffs);
+    }
+}
+
+#[test]
+fn test_distributive_matrix_torus64() {
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_rows = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_cols = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let vector: Vector<Torus64> = generate_random_torus64_polynomial_vector(num_rows, ring_degree);
+    let mat1: Matrix<Torus64> = generate_random_torus64_polynomial_matrix(num_rows, num_cols, ring_degree);
+    let mat2: Matrix<Torus64> = generate_random_torus64_polynomial_matrix(num_rows, num_cols, ring_degree);
+
+    // Test that v * (m1 + m2) = v * m1 + v * m2
+    let sum1 = mat1.add(&mat2);
+    let total1 = sum1.multiply_by_left_vector(&vector);
+
+    let prod1 = mat1.multiply_by_left_vector(&vector);
+    let prod2 = mat2.multiply_by_left_vector(&vector);
+    let total2 = prod1.add(&prod2);
+
+    assert_eq!(total1.ring_degree, total2.ring_degree);
+    assert_eq!(total1.length, total2.length);
+    for i in 0..total1.length {
+        assert_eq!(total1.polys[i].coeffs, total2.polys[i].coeffs);
+    }
+}
+
+// The negacyclic tests use coefficients in {0, 1}. Wrapped terms are subtracted, so random float
+// coefficients can cancel to almost 0 and blow up the relative error. Small integer coefficients
+// keep every product exact.
+fn generate_random_binary_coeffs<T>(size: usize) -> Vec<T>
+        where T: Ring {
+    let mut rng = rand::thread_rng();
+    let mut rand_vec: Vec<T> = Vec::new();
+    for _ in 0..size {
+        rand_vec.push(if rng.gen::<bool>() { T::one() } else { T::zero() });
+    }
+    rand_vec
+}
+
+fn generate_random_binary_complex_coeffs<T>(size: usize) -> Vec<Complex<T>>
+        where T: Ring + Abs {
+    let reals: Vec<T> = generate_random_binary_coeffs(size);
+    let imags: Vec<T> = generate_random_binary_coeffs(size);
+    reals.into_iter().zip(imags).map(|(real, imag)| Complex{real, imag}).collect()
+}
+
+fn generate_random_negacyclic_vector<T>(random_coeffs: fn(usize) -> Vec<T>, size: usize,
+                                        ring_degree: usize) -> Vector<T, Negacyclic>
+        where T: Ring {
+    let mut rand_poly_vec: Vec<Polynomial<T, Negacyclic>> = Vec::new();
+    for _ in 0..size {
+        rand_poly_vec.push(Polynomial::from_coeffs(random_coeffs(ring_degree)));
+    }
+    Vector{ring_degree, length: size, polys: rand_poly_vec}
+}
+
+fn generate_random_negacyclic_matrix<T>(random_coeffs: fn(usize) -> Vec<T>, num_rows: usize,
+                                        num_cols: usize, ring_degree: usize) -> Matrix<T, Negacyclic>
+        where T: Ring {
+    let mut rand_poly_matrix: Vec<Vector<T, Negacyclic>> = Vec::new();
+    for _ in 0..num_cols {
+        rand_poly_matrix.push(generate_random_negacyclic_vector(random_coeffs, num_rows, ring_degree));
+    }
+    Matrix{ring_degree, num_rows, num_cols, cols: rand_poly_matrix}
+}
+
+#[test]
+fn test_dot_product_negacyclic_known_answer() {
+    let vec1: Vector<f64, Negacyclic> = Vector {
+        ring_degree: 2,
+        length: 2,
+        polys: vec![Polynomial::from_coeffs(vec![1.0, 2.0]), Polynomial::from_coeffs(vec![3.0, 4.0])],
+    };
+    let vec2: Vector<f64, Negacyclic> = Vector {
+        ring_degree: 2,
+        length: 2,
+        polys: vec![Polynomial::from_coeffs(vec![5.0, 6.0]), Polynomial::from_coeffs(vec![7.0, 8.0])],
+    };
+
+    // (1 + 2x)(5 + 6x) + (3 + 4x)(7 + 8x) = (5 - 12 + 21 - 32) + (6 + 10 + 24 + 28)x mod x^2 + 1
+    let dot_prod = vec1.dot_product(&vec2);
+
+    assert_eq!(dot_prod.ring_degree, 2);
+    assert_eq!(dot_prod.coeffs, vec![-18.0, 68.0]);
+}
+
+fn test_distributive_matrix_negacyclic<T>(random_coeffs: fn(usize) -> Vec<T>, error: f64)
+        where T: Ring + Abs {
+
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_rows = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_cols = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let vector = generate_random_negacyclic_vector(random_coeffs, num_rows, ring_degree);
+    let mat1 = generate_random_negacyclic_matrix(random_coeffs, num_rows, num_cols, ring_degree);
+    let mat2 = generate_random_negacyclic_matrix(random_coeffs, num_rows, num_cols, ring_degree);
+
+    // Test that v * (m1 + m2) = v * m1 + v * m2
+    let sum1 = mat1.add(&mat2);
+    let total1 = sum1.multiply_by_left_vector(&vector);
+
+    let prod1 = mat1.multiply_by_left_vector(&vector);
+    let prod2 = mat2.multiply_by_left_vector(&vector);
+    let total2 = prod1.add(&prod2);
+
+    assert_eq!(total1.ring_degree, total2.ring_degree);
+    assert_eq!(total1.length, total2.length);
+    for i in 0..total1.length {
+        assert!(check_vecs_almost_equal(&total1.polys[i].coeffs, &total2.polys[i].coeffs, error));
+    }
+}
+
+#[test]
+fn test_distributive_matrix_negacyclic_f32() {
+    test_distributive_matrix_negacyclic::<f32>(generate_random_binary_coeffs, F32_MULTIPLY_ERROR);
+}
+
+#[test]
+fn test_distributive_matrix_negacyclic_f64() {
+    test_distributive_matrix_negacyclic::<f64>(generate_random_binary_coeffs, F64_ERROR);
+}
+
+#[test]
+fn test_distributive_matrix_negacyclic_complex_f32() {
+    test_distributive_matrix_negacyclic::<Complex<f32>>(generate_random_binary_complex_coeffs,
+                                                        F32_MULTIPLY_ERROR);
+}
+
+#[test]
+fn test_distributive_matrix_negacyclic_complex_f64() {
+    test_distributive_matrix_negacyclic::<Complex<f64>>(generate_random_binary_complex_coeffs,
+                                                        F64_ERROR);
+}
+
+fn test_distributive_matrix_sub<T>(generate_random_vector: fn(usize, usize) -> Vector<T>,
+                                   generate_random_matrix: fn(usize, usize, usize) -> Matrix<T>)
+        where T: Ring {
+
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_rows = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_cols = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let vector = generate_random_vector(num_rows, ring_degree);
+    let mat1 = generate_random_matrix(num_rows, num_cols, ring_degree);
+    let mat2 = generate_random_matrix(num_rows, num_cols, ring_degree);
+
+    // Test that v * (m1 - m2) = v * m1 - v * m2
+    let total1 = mat1.sub(&mat2).multiply_by_left_vector(&vector);
+    let total2 = mat1.multiply_by_left_vector(&vector).sub(&mat2.multiply_by_left_vector(&vector));
+
+    assert_eq!(total1.ring_degree, total2.ring_degree);
+    assert_eq!(total1.length, total2.length);
+    for i in 0..total1.length {
+        assert_eq!(total1.polys[i].coeffs, total2.polys[i].coeffs);
+    }
+
+    // Test that m1 - m2 = m1 + (-m2)
+    let diff1 = mat1.sub(&mat2);
+    let diff2 = mat1.add(&mat2.neg());
+    for j in 0..num_cols {
+        for i in 0..num_rows {
+            assert_eq!(diff1.cols[j].polys[i].coeffs, diff2.cols[j].polys[i].coeffs);
+        }
+    }
+}
+
+#[test]
+fn test_distributive_matrix_sub_modint32() {
+    test_distributive_matrix_sub(generate_random_modint32_polynomial_vector,
+                                 generate_random_modint32_polynomial_matrix);
+}
+
+#[test]
+fn test_distributive_matrix_sub_modint64() {
+    test_distributive_matrix_sub(generate_random_modint64_polynomial_vector,
+                                 generate_random_modint64_polynomial_matrix);
+}
+
+#[test]
+fn test_distributive_matrix_sub_modint() {
+    test_distributive_matrix_sub(generate_random_modint_polynomial_vector::<FALCON_Q>,
+                                 generate_random_modint_polynomial_matrix::<FALCON_Q>);
+}
+
+#[test]
+fn test_distributive_matrix_sub_torus() {
+    test_distributive_matrix_sub(generate_random_torus32_polynomial_vector,
+                                 generate_random_torus32_polynomial_matrix);
+    test_distributive_matrix_sub(generate_random_torus64_polynomial_vector,
+                                 generate_random_torus64_polynomial_matrix);
+}
+
+fn test_assign_matches<T>(generate_random_vector: fn(usize, usize) -> Vector<T>,
+                          generate_random_matrix: fn(usize, usize, usize) -> Matrix<T>)
+        where T: Ring {
+
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_rows = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_cols = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let vec1 = generate_random_vector(num_rows, ring_degree);
+    let vec2 = generate_random_vector(num_rows, ring_degree);
+    let mat1 = generate_random_matrix(num_rows, num_cols, ring_degree);
+    let mat2 = generate_random_matrix(num_rows, num_cols, ring_degree);
+    let poly = vec2.polys[0].multiply_by_x();
+
+    // Test that the in-place operations match the ones returning a new vector or matrix.
+    let mut vec_sum = vec1.add(&vec2);
+    vec_sum.sub_assign(&vec2);
+    let mut vec_diff = vec1.sub(&vec2);
+    vec_diff.add_assign(&vec2);
+    let mut vec_prod = vec1.add(&vec2);
+    vec_prod.multiply_by_polynomial_assign(&poly);
+    let expected_prod = vec1.add(&vec2).multiply_by_polynomial(&poly);
+    for i in 0..num_rows {
+        assert_eq!(vec_sum.polys[i].coeffs, vec1.polys[i].coeffs);
+        assert_eq!(vec_diff.polys[i].coeffs, vec1.polys[i].coeffs);
+        assert_eq!(vec_prod.polys[i].coeffs, expected_prod.polys[i].coeffs);
+    }
+
+    let mut mat_sum = mat1.add(&mat2);
+    mat_sum.sub_assign(&mat2);
+    let mut mat_diff = mat1.sub(&mat2);
+    mat_diff.add_assign(&mat2);
+    for j in 0..num_cols {
+        for i in 0..num_rows {
+            assert_eq!(mat_sum.cols[j].polys[i].coeffs, mat1.cols[j].polys[i].coeffs);
+            assert_eq!(mat_diff.cols[j].polys[i].coeffs, mat1.cols[j].polys[i].coeffs);
+        }
+    }
+}
+
+#[test]
+fn test_assign_matches_modint32() {
+    test_assign_matches(generate_random_modint32_polynomial_vector, generate_random_modint32_polynomial_matrix);
+}
+
+#[test]
+fn test_assign_matches_torus64() {
+    test_assign_matches(generate_random_torus64_polynomial_vector, generate_random_torus64_polynomial_matrix);
+}
+
+#[test]
+fn test_operators_known_answer() {
+    let vec1: Vector<f64, Negacyclic> = Vector {
+        ring_degree: 2,
+        length: 2,
+        polys: vec![Polynomial::from_coeffs(vec![1.0, 2.0]), Polynomial::from_coeffs(vec![3.0, 4.0])],
+    };
+    let mat: Matrix<f64, Negacyclic> = Matrix {
+        ring_degree: 2,
+        num_rows: 2,
+        num_cols: 1,
+        cols: vec![Vector {
+            ring_degree: 2,
+            length: 2,
+            polys: vec![Polynomial::from_coeffs(vec![5.0, 6.0]), Polynomial::from_coeffs(vec![7.0, 8.0])],
+        }],
+    };
+    let poly: Polynomial<f64, Negacyclic> = Polynomial::from_coeffs(vec![0.0, 1.0]);
+
+    // x * (1 + 2x) = -2 + x and x * (3 + 4x) = -4 + 3x mod x^2 + 1
+    let prod = &poly * &vec1;
+    assert_eq!(prod.polys[0].coeffs, vec![-2.0, 1.0]);
+    assert_eq!(prod.polys[1].coeffs, vec![-4.0, 3.0]);
+    assert_eq!((&vec1 * &poly).polys[1].coeffs, vec![-4.0, 3.0]);
+
+    let prod = &vec1 * &mat;
+    assert_eq!(prod.length, 1);
+    assert_eq!(prod.polys[0].coeffs, vec![-18.0, 68.0]);
+
+    let diff = &vec1 - &(-&vec1);
+    assert_eq!(diff.polys[1].coeffs, vec![6.0, 8.0]);
+
+    let sum = &mat + &(-&mat);
+    assert_eq!(sum.cols[0].polys[0].coeffs, vec![0.0, 0.0]);
+}
+
+#[test]
+#[should_panic(expected = "Matrix row length should equal size of vector. 3 != 2")]
+fn test_operator_vector_matrix_different_sizes_fail() {
+    let vector: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(2, 4);
+    let mat: Matrix<ModInteger32> = generate_random_modint32_polynomial_matrix(3, 2, 4);
+
+    // Should panic because the matrix has more rows than the vector has entries.
+    let _ = vector * mat;
+}
+
+fn test_operators_match_methods<T>(generate_random_vector: fn(usize, usize) -> Vector<T>,
+                                   generate_random_matrix: fn(usize, usize, usize) -> Matrix<T>)
+        where T: Ring {
+
+    // Generate random polynomials.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_rows = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_cols = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let vec1 = generate_random_vector(num_rows, ring_degree);
+    let vec2 = generate_random_vector(num_rows, ring_degree);
+    let mat1 = generate_random_matrix(num_rows, num_cols, ring_degree);
+    let mat2 = generate_random_matrix(num_rows, num_cols, ring_degree);
+    let poly = vec2.polys[0].multiply_by_x();
+
+    // Test that (p * v1 + v2) * m1 - v2 * m2 matches the named methods.
+    let total1 = (&poly * &vec1 + &vec2) * &mat1 - &vec2 * &mat2;
+    let total2 = mat1.multiply_by_left_vector(&Vector::add(&vec1.multiply_by_polynomial(&poly), &vec2))
+        .sub(&mat2.multiply_by_left_vector(&vec2));
+
+    assert_eq!(total1.length, total2.length);
+    for i in 0..total1.length {
+        assert_eq!(total1.polys[i].coeffs, total2.polys[i].coeffs);
+    }
+}
+
+#[test]
+fn test_operators_match_methods_modint64() {
+    test_operators_match_methods(generate_random_modint64_polynomial_vector,
+                                 generate_random_modint64_polynomial_matrix);
+}
+
+#[test]
+fn test_operators_match_methods_torus32() {
+    test_operators_match_methods(generate_random_torus32_polynomial_vector,
+                                 generate_random_torus32_polynomial_matrix);
+}
+
+#[test]
+fn test_try_operations_different_sizes() {
+    let vec1: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(2, 4);
+    let vec2: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(3, 4);
+    let mat1: Matrix<ModInteger32> = generate_random_modint32_polynomial_matrix(3, 2, 4);
+    let mat2: Matrix<ModInteger32> = generate_random_modint32_polynomial_matrix(2, 2, 4);
+    let mat3: Matrix<ModInteger32> = generate_random_modint32_polynomial_matrix(3, 5, 4);
+
+    assert_eq!(vec1.try_add(&vec2).err(), Some(RingMathError::LengthMismatch(2, 3)));
+    assert_eq!(vec1.try_sub(&vec2).err(), Some(RingMathError::LengthMismatch(2, 3)));
+    assert_eq!(vec2.try_dot_product(&vec1).err(), Some(RingMathError::LengthMismatch(3, 2)));
+    assert_eq!(mat1.try_add(&mat2).err(), Some(RingMathError::RowLengthMismatch(3, 2)));
+    assert_eq!(mat1.try_sub(&mat3).err(), Some(RingMathError::ColumnLengthMismatch(2, 5)));
+    assert_eq!(mat1.try_multiply_by_left_vector(&vec1).err(), Some(RingMathError::MatrixVectorMismatch(3, 2)));
+    assert!(mat1.try_multiply_by_left_vector(&vec2).is_ok());
+}
+
+#[test]
+fn test_try_operations_different_ring_degrees() {
+    let vec1: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(2, 4);
+    let vec2: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(2, 8);
+    let mat: Matrix<ModInteger32> = generate_random_modint32_polynomial_matrix(2, 3, 8);
+
+    assert_eq!(vec1.try_add(&vec2).err(), Some(RingMathError::RingDegreeMismatch(4, 8)));
+    assert_eq!(vec1.try_dot_product(&vec2).err(), Some(RingMathError::RingDegreeMismatch(4, 8)));
+    assert_eq!(mat.try_multiply_by_left_vector(&vec1).err(), Some(RingMathError::RingDegreeMismatch(4, 8)));
+}
+
+#[test]
+fn test_try_operations_invalid_counts() {
+    let mut vec1: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(3, 4);
+    let mut vec2: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(3, 4);
+    let mut mat1: Matrix<ModInteger32> = generate_random_modint32_polynomial_matrix(3, 2, 4);
+    let mut mat2: Matrix<ModInteger32> = generate_random_modint32_polynomial_matrix(3, 2, 4);
+    vec2.polys.pop();
+    mat2.cols.pop();
+
+    assert_eq!(vec1.try_check_poly_count(), Ok(()));
+    assert_eq!(vec2.try_check_poly_count(), Err(RingMathError::PolyCountInvalid(3, 2)));
+    // The count of the other operand is checked too, rather than indexing out of bounds.
+    assert_eq!(vec1.try_add(&vec2).err(), Some(RingMathError::PolyCountInvalid(3, 2)));
+    assert_eq!(vec1.try_sub(&vec2).err(), Some(RingMathError::PolyCountInvalid(3, 2)));
+    assert_eq!(vec1.try_dot_product(&vec2).err(), Some(RingMathError::PolyCountInvalid(3, 2)));
+    assert_eq!(vec1.try_add_assign(&vec2), Err(RingMathError::PolyCountInvalid(3, 2)));
+    assert_eq!(vec1.try_sub_assign(&vec2), Err(RingMathError::PolyCountInvalid(3, 2)));
+    assert_eq!(mat1.try_check_column_count(), Ok(()));
+    assert_eq!(mat2.try_check_column_count(), Err(RingMathError::ColumnCountInvalid(2, 1)));
+    assert_eq!(mat1.try_add(&mat2).err(), Some(RingMathError::ColumnCountInvalid(2, 1)));
+    assert_eq!(mat1.try_sub(&mat2).err(), Some(RingMathError::ColumnCountInvalid(2, 1)));
+    assert_eq!(mat1.try_add_assign(&mat2), Err(RingMathError::ColumnCountInvalid(2, 1)));
+    assert_eq!(mat1.try_sub_assign(&mat2), Err(RingMathError::ColumnCountInvalid(2, 1)));
+    assert_eq!(mat2.try_multiply_by_left_vector(&vec1).err(), Some(RingMathError::ColumnCountInvalid(2, 1)));
+}
+
+#[test]
+fn test_try_assign_operations_leave_target_unchanged() {
+    let mut vec1: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(2, 2);
+    let mut vec2: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(2, 2);
+    let mut mat1: Matrix<ModInteger32> = generate_random_modint32_polynomial_matrix(2, 2, 2);
+    let mut mat2: Matrix<ModInteger32> = generate_random_modint32_polynomial_matrix(2, 2, 2);
+    // Only the last entry has a different ring degree, after entries that could be combined.
+    vec2.polys[1] = generate_random_modint32_polynomial(3);
+    mat2.cols[1].polys[1] = generate_random_modint32_polynomial(3);
+    let vec_coeffs: Vec<Vec<ModInteger32>> = vec1.polys.iter().map(|poly| poly.coeffs.clone()).collect();
+    let mat_coeffs: Vec<Vec<ModInteger32>> = mat1.cols.iter()
+        .flat_map(|col| col.polys.iter().map(|poly| poly.coeffs.clone())).collect();
+
+    assert_eq!(vec1.try_add_assign(&vec2), Err(RingMathError::RingDegreeMismatch(2, 3)));
+    assert_eq!(vec1.try_sub_assign(&vec2), Err(RingMathError::RingDegreeMismatch(2, 3)));
+    assert_eq!(mat1.try_add_assign(&mat2), Err(RingMathError::RingDegreeMismatch(2, 3)));
+    assert_eq!(mat1.try_sub_assign(&mat2), Err(RingMathError::RingDegreeMismatch(2, 3)));
+    assert_eq!(vec1.polys.iter().map(|poly| poly.coeffs.clone()).collect::<Vec<_>>(), vec_coeffs);
+    assert_eq!(mat1.cols.iter().flat_map(|col| col.polys.iter().map(|poly| poly.coeffs.clone())).collect::<Vec<_>>(),
+               mat_coeffs);
+}
+
+#[test]
+fn test_try_multiply_by_polynomial() {
+    let mut vec1: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(3, 4);
+    let mut vec2: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(3, 4);
+    let poly1: Polynomial<ModInteger32> = generate_random_modint32_polynomial(4);
+    let poly2: Polynomial<ModInteger32> = generate_random_modint32_polynomial(8);
+    vec2.polys.pop();
+
+    let prod = vec1.try_multiply_by_polynomial(&poly1).unwrap();
+    for i in 0..3 {
+        assert_eq!(prod.polys[i].coeffs, vec1.polys[i].multiply(&poly1).coeffs);
+    }
+    assert_eq!(vec1.try_multiply_by_polynomial(&poly2).err(), Some(RingMathError::RingDegreeMismatch(4, 8)));
+    assert_eq!(vec2.try_multiply_by_polynomial(&poly1).err(), Some(RingMathError::PolyCountInvalid(3, 2)));
+
+    assert_eq!(vec1.try_multiply_by_polynomial_assign(&poly2), Err(RingMathError::RingDegreeMismatch(4, 8)));
+    assert_eq!(vec2.try_multiply_by_polynomial_assign(&poly1), Err(RingMathError::PolyCountInvalid(3, 2)));
+    assert_eq!(vec1.try_multiply_by_polynomial_assign(&poly1), Ok(()));
+    for i in 0..3 {
+        assert_eq!(vec1.polys[i].coeffs, prod.polys[i].coeffs);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Vector length should be equal to number of polynomials. 3 != 2")]
+fn test_multiply_by_polynomial_invalid_count_fail() {
+    let mut vec: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(3, 4);
+    let poly: Polynomial<ModInteger32> = generate_random_modint32_polynomial(4);
+    vec.polys.pop();
+
+    // Should panic with the count error rather than index out of bounds.
+    let _ = &vec * &poly;
+}
+
+#[test]
+fn test_neg_invalid_counts() {
+    let mut vec: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(3, 4);
+    let mut mat: Matrix<ModInteger32> = generate_random_modint32_polynomial_matrix(3, 2, 4);
+    vec.polys.pop();
+    mat.cols.pop();
+
+    // Negation follows the entries that are present, rather than indexing out of bounds.
+    let neg_vec = -&vec;
+    let neg_mat = -&mat;
+    assert_eq!(neg_vec.try_check_poly_count(), Err(RingMathError::PolyCountInvalid(3, 2)));
+    assert_eq!(neg_mat.try_check_column_count(), Err(RingMathError::ColumnCountInvalid(2, 1)));
+    for i in 0..2 {
+        assert_eq!((&neg_vec.polys[i] + &vec.polys[i]).coeffs, Polynomial::<ModInteger32>::new(4).coeffs);
+    }
+}
+
+#[test]
+fn test_try_assign_operations_match_panicking_operations() {
+    let vec1: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(3, 4);
+    let vec2: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(3, 4);
+    let vec3: Vector<ModInteger32> = generate_random_modint32_polynomial_vector(2, 4);
+    let mat1: Matrix<ModInteger32> = generate_random_modint32_polynomial_matrix(3, 2, 4);
+    let mat2: Matrix<ModInteger32> = generate_random_modint32_polynomial_matrix(3, 2, 4);
+    let mat3: Matrix<ModInteger32> = generate_random_modint32_polynomial_matrix(2, 2, 4);
+
+    let mut vec_sum = vec1.add(&vec2);
+    assert_eq!(vec_sum.try_sub_assign(&vec2), Ok(()));
+    let mut vec_diff = vec1.sub(&vec2);
+    assert_eq!(vec_diff.try_add_assign(&vec2), Ok(()));
+    for i in 0..3 {
+        assert_eq!(vec_sum.polys[i].coeffs, vec1.polys[i].coeffs);
+        assert_eq!(vec_diff.polys[i].coeffs, vec1.polys[i].coeffs);
+    }
+    assert_eq!(vec_sum.try_add_assign(&vec3), Err(RingMathError::LengthMismatch(3, 2)));
+
+    let mut mat_sum = mat1.add(&mat2);
+    assert_eq!(mat_sum.try_sub_assign(&mat2), Ok(()));
+    let mut mat_diff = mat1.sub(&mat2);
+    assert_eq!(mat_diff.try_add_assign(&mat2), Ok(()));
+    for j in 0..2 {
+        for i in 0..3 {
+            assert_eq!(mat_sum.cols[j].polys[i].coeffs, mat1.cols[j].polys[i].coeffs);
+            assert_eq!(mat_diff.cols[j].polys[i].coeffs, mat1.cols[j].polys[i].coeffs);
+        }
+    }
+    assert_eq!(mat_sum.try_sub_assign(&mat3), Err(RingMathError::RowLengthMismatch(3, 2)));
+}
+
+#[test]
+fn test_switch_modulus_vector_matches_polynomials() {
+    // Generate a random vector.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let length = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let vector: Vector<ModInteger64> = generate_random_modint64_polynomial_vector(length, ring_degree);
+
+    let switched: Vector<ModInteger<FALCON_Q>> = vector.switch_modulus(Rounding::Nearest);
+    let real = vector.switch_to_real();
+
+    assert_eq!(switched.length, length);
+    for i in 0..length {
+        assert_eq!(switched.polys[i].coeffs, vector.polys[i].switch_modulus::<ModInteger<FALCON_Q>>(Rounding::Nearest).coeffs);
+        assert_eq!(real.polys[i].coeffs, vector.polys[i].switch_to_real().coeffs);
+    }
+}
+
+#[test]
+fn test_switch_modulus_matrix_matches_polynomials() {
+    // Generate a random matrix.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_rows = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_cols = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let matrix: Matrix<ModInteger<FALCON_Q>> = generate_random_modint_polynomial_matrix(num_rows, num_cols, ring_degree);
+
+    let switched: Matrix<Torus32> = matrix.switch_modulus(Rounding::Floor);
+    let real = matrix.switch_to_real();
+
+    assert_eq!((switched.num_rows, switched.num_cols), (num_rows, num_cols));
+    for j in 0..num_cols {
+        for i in 0..num_rows {
+            assert_eq!(switched.cols[j].polys[i].coeffs,
+                       matrix.cols[j].polys[i].switch_modulus::<Torus32>(Rounding::Floor).coeffs);
+            assert_eq!(real.cols[j].polys[i].coeffs, matrix.cols[j].polys[i].switch_to_real().coeffs);
+        }
+    }
+}
+
+#[test]
+fn test_norms_vector_matches_polynomials() {
+    // Generate a random vector.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let length = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let vector: Vector<ModInteger<FALCON_Q>> = generate_random_modint_polynomial_vector(length, ring_degree);
+
+    // Test that the norms are taken over the coefficients of all the polynomials.
+    let norm_l2: f64 = vector.polys.iter().map(|poly| poly.norm_l2() * poly.norm_l2()).sum();
+
+    assert_eq!(vector.norm_infinity(), vector.polys.iter().map(|poly| poly.norm_infinity()).fold(0.0, f64::max));
+    assert_eq!(vector.norm_l1(), vector.polys.iter().map(|poly| poly.norm_l1()).sum::<f64>());
+    assert!(check_vecs_almost_equal(&[vector.norm_l2()], &[norm_l2.sqrt()], 0.0000000001));
+}
+
+#[test]
+fn test_norms_matrix_matches_vectors() {
+    // Generate a random matrix.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_rows = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_cols = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let matrix: Matrix<Torus64> = generate_random_torus64_polynomial_matrix(num_rows, num_cols, ring_degree);
+
+    let norm_l2: f64 = matrix.cols.iter().map(|col| col.norm_l2() * col.norm_l2()).sum();
+
+    assert_eq!(matrix.norm_infinity(), matrix.cols.iter().map(|col| col.norm_infinity()).fold(0.0, f64::max));
+    assert!(matrix.norm_infinity() <= 0.5);
+    assert!(check_vecs_almost_equal(&[matrix.norm_l1()], &[matrix.cols.iter().map(|col| col.norm_l1()).sum::<f64>()],
+                                    0.0000000001));
+    assert!(check_vecs_almost_equal(&[matrix.norm_l2()], &[norm_l2.sqrt()], 0.0000000001));
+}
+
+#[test]
+fn test_convert_vector_matches_polynomials() {
+    // Generate a random vector.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let length = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let vector: Vector<ModInteger64> = generate_random_modint64_polynomial_vector(length, ring_degree);
+    let expected: Vec<Vec<f64>> = vector.polys.iter()
+        .map(|poly| poly.coeffs.iter().map(|&coeff| f64::from(coeff)).collect()).collect();
+
+    let real: Vector<f64> = Vector::from(vector);
+
+    assert_eq!((real.ring_degree, real.length), (ring_degree, length));
+    for (poly, coeffs) in real.polys.iter().zip(expected.iter()) {
+        assert_eq!(&poly.coeffs, coeffs);
+    }
+}
+
+#[test]
+fn test_try_convert_matrix() {
+    // Generate a random matrix.
+    let mut rng = rand::thread_rng();
+    let ring_degree = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_rows = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let num_cols = rng.gen_range(1..=MAX_TEST_DEGREE);
+    let matrix: Matrix<ModInteger32> = generate_random_modint32_polynomial_matrix(num_rows, num_cols, ring_degree);
+    let expected: Vec<Vec<Vec<ModInteger32>>> = matrix.cols.iter()
+        .map(|col| col.polys.iter().map(|poly| poly.coeffs.clone()).collect()).collect();
+
+    // Test that the matrix round trips through complex numbers, and that an imaginary entry fails.
+    let mut complex: Matrix<Complex<f64>> = Matrix::from(matrix);
+    let round_trip = Matrix::<ModInteger32>::try_from(complex).unwrap();
+
+    assert_eq!((round_trip.num_rows, round_trip.num_cols), (num_rows, num_cols));
+    for (col, col_coeffs) in round_trip.cols.iter().zip(expected.iter()) {
+        for (poly, coeffs) in col.polys.iter().zip(col_coeffs.iter()) {
+            assert_eq!(&poly.coeffs, coeffs);
+        }
+    }
+
+    complex = Matrix::from(round_trip);
+    complex.cols[num_cols - 1].polys[num_rows - 1].coeffs[0].imag = 0.5;
+    assert_eq!(Matrix::<ModInteger32>::try_from(complex).err(), Some(RingMathError::NonzeroImaginaryPart));
+}